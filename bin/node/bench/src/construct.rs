@@ -154,7 +154,7 @@ impl core::Benchmark for ConstructionBenchmark {
 		);
 		let inherent_data_providers = sp_inherents::InherentDataProviders::new();
 		inherent_data_providers
-			.register_provider(sp_timestamp::InherentDataProvider)
+			.register_provider(sp_timestamp::InherentDataProvider::default())
 			.expect("Failed to register timestamp data provider");
 
 		let start = std::time::Instant::now();
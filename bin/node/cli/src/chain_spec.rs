@@ -338,6 +338,7 @@ pub fn testnet_genesis(
 		}),
 		pallet_vesting: Some(Default::default()),
 		pallet_gilt: Some(Default::default()),
+		prml_attestation: Some(Default::default()),
 	}
 }
 
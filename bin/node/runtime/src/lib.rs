@@ -183,7 +183,7 @@ impl frame_system::Config for Runtime {
 	type PalletInfo = PalletInfo;
 	type AccountData = ();
 	type OnNewAccount = ();
-	type OnKilledAccount = ();
+	type OnKilledAccount = GenericAsset;
 	type SystemWeightInfo = frame_system::weights::SubstrateWeight<Runtime>;
 	type SS58Prefix = SS58Prefix;
 }
@@ -571,6 +571,8 @@ parameter_types! {
 	pub const CouncilMotionDuration: BlockNumber = 5 * DAYS;
 	pub const CouncilMaxProposals: u32 = 100;
 	pub const CouncilMaxMembers: u32 = 100;
+	pub const CouncilProposalDeposit: Balance = 0;
+	pub const CouncilMaxExpiredPerBlock: u32 = 10;
 }
 
 type CouncilCollective = pallet_collective::Instance1;
@@ -582,6 +584,9 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type Currency = SpendingAssetCurrency<Self>;
+	type ProposalDeposit = CouncilProposalDeposit;
+	type MaxExpiredPerBlock = CouncilMaxExpiredPerBlock;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -624,6 +629,8 @@ parameter_types! {
 	pub const TechnicalMotionDuration: BlockNumber = 5 * DAYS;
 	pub const TechnicalMaxProposals: u32 = 100;
 	pub const TechnicalMaxMembers: u32 = 100;
+	pub const TechnicalProposalDeposit: Balance = 0;
+	pub const TechnicalMaxExpiredPerBlock: u32 = 10;
 }
 
 type TechnicalCollective = pallet_collective::Instance2;
@@ -635,6 +642,9 @@ impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type MaxProposals = TechnicalMaxProposals;
 	type MaxMembers = TechnicalMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type Currency = SpendingAssetCurrency<Self>;
+	type ProposalDeposit = TechnicalProposalDeposit;
+	type MaxExpiredPerBlock = TechnicalMaxExpiredPerBlock;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -957,16 +967,39 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxTopicLen: u32 = 64;
+	pub const MaxClaimsPerBatch: u32 = 100;
+	pub const MaxIssuersPerHolder: u32 = 100;
+	pub const MaxTopicsPerIssuerHolder: u32 = 32;
+}
+
 impl prml_attestation::Config for Runtime {
 	type Event = Event;
+	type MaxTopicLen = MaxTopicLen;
+	type MaxClaimsPerBatch = MaxClaimsPerBatch;
+	type MaxIssuersPerHolder = MaxIssuersPerHolder;
+	type MaxTopicsPerIssuerHolder = MaxTopicsPerIssuerHolder;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxSymbolLength: u32 = 32;
+	pub const MaxMintBatch: u32 = 500;
+	pub const MaxMemoLength: u32 = 128;
+}
+
 impl prml_generic_asset::Config for Runtime {
 	type AssetId = AssetId;
 	type Balance = Balance;
 	type Event = Event;
 	type OnDustImbalance = TransferImbalanceToTreasury;
+	type MaxSymbolLength = MaxSymbolLength;
+	type MaxMintBatch = MaxMintBatch;
+	type MaxMemoLength = MaxMemoLength;
+	type MaxLocks = MaxLocks;
+	type OnNewAsset = ();
+	type BlockedRecipients = prml_generic_asset::NoBlockedRecipients;
 	type WeightInfo = ();
 }
 
@@ -1090,7 +1123,7 @@ construct_runtime!(
 		Mmr: pallet_mmr::{Module, Storage},
 		Lottery: pallet_lottery::{Module, Call, Storage, Event<T>},
 		Gilt: pallet_gilt::{Module, Call, Storage, Event<T>, Config},
-		Attestation: prml_attestation::{Module, Call, Storage, Event<T>},
+		Attestation: prml_attestation::{Module, Call, Storage, Event<T>, Config},
 	}
 );
 
@@ -122,5 +122,6 @@ pub fn config_endowed(
 		}),
 		pallet_vesting: Some(Default::default()),
 		pallet_gilt: Some(Default::default()),
+		prml_attestation: Some(Default::default()),
 	}
 }
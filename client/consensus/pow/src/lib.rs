@@ -487,7 +487,7 @@ pub fn register_pow_inherent_data_provider(
 ) -> Result<(), sp_consensus::Error> {
 	if !inherent_data_providers.has_provider(&sp_timestamp::INHERENT_IDENTIFIER) {
 		inherent_data_providers
-			.register_provider(sp_timestamp::InherentDataProvider)
+			.register_provider(sp_timestamp::InherentDataProvider::default())
 			.map_err(Into::into)
 			.map_err(sp_consensus::Error::InherentData)
 	} else {
@@ -48,7 +48,8 @@ fn assert_last_event<T: Config<I>, I: Instance>(generic_event: <T as Config<I>>:
 
 benchmarks_instance! {
 	set_members {
-		let m in 1 .. T::MaxMembers::get();
+		// At least 2 old members, so that their total weight meets the threshold of the proposals below.
+		let m in 2 .. T::MaxMembers::get();
 		let n in 1 .. T::MaxMembers::get();
 		let p in 1 .. T::MaxProposals::get();
 
@@ -73,6 +74,7 @@ benchmarks_instance! {
 		let threshold = m.max(2);
 		// Length of the proposals should be irrelevant to `set_members`.
 		let length = 100;
+		T::Currency::make_free_balance_be(&last_old_member, BalanceOf::<T, I>::max_value());
 		for i in 0 .. p {
 			// Proposals should be different so that different proposal hashes are generated
 			let proposal: T::Proposal = SystemCall::<T>::remark(vec![i as u8; length]).into();
@@ -188,6 +190,7 @@ benchmarks_instance! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 		members.push(caller.clone());
 		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
 
@@ -227,6 +230,7 @@ benchmarks_instance! {
 		// Construct `members`.
 		let mut members = vec![];
 		let proposer: T::AccountId = account("proposer", 0, SEED);
+		T::Currency::make_free_balance_be(&proposer, BalanceOf::<T, I>::max_value());
 		members.push(proposer.clone());
 		for i in 1 .. m - 1 {
 			let member = account("member", i, SEED);
@@ -303,6 +307,7 @@ benchmarks_instance! {
 		// Construct `members`.
 		let mut members = vec![];
 		let proposer: T::AccountId = account("proposer", 0, SEED);
+		T::Currency::make_free_balance_be(&proposer, BalanceOf::<T, I>::max_value());
 		members.push(proposer.clone());
 		for i in 1 .. m - 1 {
 			let member = account("member", i, SEED);
@@ -387,6 +392,7 @@ benchmarks_instance! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 		members.push(caller.clone());
 		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
 
@@ -468,6 +474,7 @@ benchmarks_instance! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 		members.push(caller.clone());
 		Collective::<T, _>::set_members(
 			SystemOrigin::Root.into(),
@@ -540,6 +547,7 @@ benchmarks_instance! {
 			members.push(member);
 		}
 		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 		members.push(caller.clone());
 		Collective::<T, _>::set_members(
 			SystemOrigin::Root.into(),
@@ -603,6 +611,7 @@ benchmarks_instance! {
 			members.push(member);
 		}
 		let caller: T::AccountId = account("caller", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 		members.push(caller.clone());
 		Collective::<T, _>::set_members(
 			SystemOrigin::Root.into(),
@@ -38,6 +38,15 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! Members may also explicitly abstain from a motion. Abstentions count as participation, so they
+//! are never replaced by the default vote, but they never count towards the threshold.
+//!
+//! Votes are weighted by `MemberWeight`, which Root may set with `set_member_weight`. Every vote
+//! has a weight of 1 unless set, so by default thresholds count members.
+//!
+//! A `ProposalDeposit` may be reserved from the proposer of a motion, it is refunded once the
+//! motion is closed or disapproved. A zero deposit disables this.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -45,7 +54,7 @@
 use sp_std::{prelude::*, result};
 use sp_core::u32_trait::Value as U32;
 use sp_io::storage;
-use sp_runtime::{RuntimeDebug, traits::Hash};
+use sp_runtime::{RuntimeDebug, traits::{Hash, Zero}};
 
 use frame_support::{
 	codec::{Decode, Encode},
@@ -55,7 +64,7 @@ use frame_support::{
 		PostDispatchInfo,
 	},
 	ensure,
-	traits::{ChangeMembers, EnsureOrigin, Get, InitializeMembers},
+	traits::{ChangeMembers, Currency, EnsureOrigin, Get, InitializeMembers, ReservableCurrency},
 	weights::{DispatchClass, GetDispatchInfo, Weight, Pays},
 };
 use frame_system::{self as system, ensure_signed, ensure_root};
@@ -66,6 +75,9 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+type BalanceOf<T, I> =
+	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 /// Simple index type for proposal counting.
 pub type ProposalIndex = u32;
 
@@ -80,9 +92,9 @@ pub trait DefaultVote {
 	/// Get the default voting strategy, given:
 	///
 	/// - Whether the prime member voted Aye.
-	/// - Raw number of yes votes.
-	/// - Raw number of no votes.
-	/// - Total number of member count.
+	/// - Weight of yes votes.
+	/// - Weight of no votes.
+	/// - Total weight of all members.
 	fn default_vote(
 		prime_vote: Option<bool>,
 		yes_votes: MemberCount,
@@ -116,7 +128,7 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 		_no_votes: MemberCount,
 		len: MemberCount,
 	) -> bool {
-		let more_than_majority = yes_votes * 2 > len;
+		let more_than_majority = yes_votes.saturating_mul(2) > len;
 		more_than_majority || prime_vote.unwrap_or(false)
 	}
 }
@@ -150,6 +162,17 @@ pub trait Config<I: Instance=DefaultInstance>: frame_system::Config {
 	/// Default vote strategy of this collective.
 	type DefaultVote: DefaultVote;
 
+	/// The currency used to hold proposal deposits.
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// The amount reserved from a member when their motion is put up for voting, refunded once it
+	/// is closed or disapproved. Zero disables proposal deposits.
+	type ProposalDeposit: Get<BalanceOf<Self, I>>;
+
+	/// The maximum number of expired motions disapproved automatically at the start of each
+	/// block. Zero leaves expired motions until they are explicitly closed.
+	type MaxExpiredPerBlock: Get<u32>;
+
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
 }
@@ -179,10 +202,37 @@ pub struct Votes<AccountId, BlockNumber> {
 	ayes: Vec<AccountId>,
 	/// The current set of voters that rejected it.
 	nays: Vec<AccountId>,
+	/// The current set of voters that explicitly abstained.
+	abstentions: Vec<AccountId>,
 	/// The hard end time of this vote.
 	end: BlockNumber,
 }
 
+/// Info for keeping track of a motion being voted on, as stored before abstentions were tracked.
+#[derive(Encode, Decode)]
+struct VotesV1<AccountId, BlockNumber> {
+	index: ProposalIndex,
+	threshold: MemberCount,
+	ayes: Vec<AccountId>,
+	nays: Vec<AccountId>,
+	end: BlockNumber,
+}
+
+// A value placed in storage that represents the current version of the Collective storage.
+// This value is used by the `on_runtime_upgrade` logic to determine whether we run
+// storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+enum Releases {
+	V1,
+	V2,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1
+	}
+}
+
 decl_storage! {
 	trait Store for Module<T: Config<I>, I: Instance=DefaultInstance> as Collective {
 		/// The hashes of the active proposals.
@@ -199,6 +249,16 @@ decl_storage! {
 		pub Members get(fn members): Vec<T::AccountId>;
 		/// The prime member that helps determine the default vote behavior in case of absentations.
 		pub Prime get(fn prime): Option<T::AccountId>;
+		/// The weight of an account's vote when a motion is tallied. This is kept when a member leaves.
+		pub MemberWeight get(fn member_weight):
+			map hasher(blake2_128_concat) T::AccountId => MemberCount = 1;
+		/// The proposer and deposit held for a given proposal hash, if a deposit was taken.
+		pub DepositOf get(fn deposit_of):
+			map hasher(identity) T::Hash => Option<(T::AccountId, BalanceOf<T, I>)>;
+		/// Storage version of the pallet.
+		///
+		/// New networks start with last version.
+		StorageVersion build(|_| Releases::V2): Releases;
 	}
 	add_extra_genesis {
 		config(phantom): sp_std::marker::PhantomData<I>;
@@ -211,15 +271,20 @@ decl_event! {
 	pub enum Event<T, I=DefaultInstance> where
 		<T as frame_system::Config>::Hash,
 		<T as frame_system::Config>::AccountId,
+		Balance = BalanceOf<T, I>,
 	{
 		/// A motion (given hash) has been proposed (by given account) with a threshold (given
 		/// `MemberCount`).
 		/// \[account, proposal_index, proposal_hash, threshold\]
 		Proposed(AccountId, ProposalIndex, Hash, MemberCount),
 		/// A motion (given hash) has been voted on by given account, leaving
-		/// a tally (yes votes and no votes given respectively as `MemberCount`).
+		/// a tally (summed `MemberWeight` of the yes votes and no votes respectively).
 		/// \[account, proposal_hash, voted, yes, no\]
 		Voted(AccountId, Hash, bool, MemberCount, MemberCount),
+		/// A member abstained from voting on a motion (given hash), leaving the given number of
+		/// abstentions.
+		/// \[account, proposal_hash, abstentions\]
+		Abstained(AccountId, Hash, MemberCount),
 		/// A motion was approved by the required threshold.
 		/// \[proposal_hash\]
 		Approved(Hash),
@@ -235,6 +300,12 @@ decl_event! {
 		/// A proposal was closed because its threshold was reached or after its duration was up.
 		/// \[proposal_hash, yes, no\]
 		Closed(Hash, MemberCount, MemberCount),
+		/// The deposit held for a motion was returned to its proposer.
+		/// \[proposer, amount\]
+		DepositRefunded(AccountId, Balance),
+		/// The weight of an account's vote was set.
+		/// \[account, weight\]
+		MemberWeightSet(AccountId, MemberCount),
 	}
 }
 
@@ -260,6 +331,10 @@ decl_error! {
 		WrongProposalWeight,
 		/// The given length bound for the proposal was too low.
 		WrongProposalLength,
+		/// Proposer's balance is too low to reserve the proposal deposit.
+		InsufficientProposersBalance,
+		/// The threshold is above the summed `MemberWeight` of all members, so could never be met.
+		ThresholdTooHigh,
 	}
 }
 
@@ -281,6 +356,14 @@ decl_module! {
 
 		fn deposit_event() = default;
 
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			Self::disapprove_expired(n)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_v1_to_v2()
+		}
+
 		/// Set the collective's membership.
 		///
 		/// - `new_members`: The new member list. Be nice to the chain and provide it sorted.
@@ -395,7 +478,10 @@ decl_module! {
 		/// Requires the sender to be member.
 		///
 		/// `threshold` determines whether `proposal` is executed directly (`threshold < 2`)
-		/// or put up for voting.
+		/// or put up for voting. It is a summed `MemberWeight`, so may not exceed that of all members.
+		///
+		/// A proposal put up for voting reserves `ProposalDeposit` from the sender until it is
+		/// closed or disapproved.
 		///
 		/// # <weight>
 		/// ## Weight
@@ -446,9 +532,9 @@ decl_module! {
 			let proposal_hash = T::Hashing::hash_of(&proposal);
 			ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
 
+			let total_weight = Self::vote_weight(&members);
 			if threshold < 2 {
-				let seats = Self::members().len() as MemberCount;
-				let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
+				let result = proposal.dispatch(RawOrigin::Members(1, total_weight).into());
 				Self::deposit_event(
 					RawEvent::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
 				);
@@ -460,6 +546,10 @@ decl_module! {
 					).saturating_add(w) // P1
 				}).into())
 			} else {
+				ensure!(threshold <= total_weight, Error::<T, I>::ThresholdTooHigh);
+				let deposit = T::ProposalDeposit::get();
+				ensure!(T::Currency::can_reserve(&who, deposit), Error::<T, I>::InsufficientProposersBalance);
+
 				let active_proposals =
 					<Proposals<T, I>>::try_mutate(|proposals| -> Result<usize, DispatchError> {
 						proposals.push(proposal_hash);
@@ -469,11 +559,22 @@ decl_module! {
 						);
 						Ok(proposals.len())
 					})?;
+				if !deposit.is_zero() {
+					T::Currency::reserve(&who, deposit)?;
+					<DepositOf<T, I>>::insert(proposal_hash, (who.clone(), deposit));
+				}
 				let index = Self::proposal_count();
 				<ProposalCount<I>>::mutate(|i| *i += 1);
 				<ProposalOf<T, I>>::insert(proposal_hash, *proposal);
 				let end = system::Module::<T>::block_number() + T::MotionDuration::get();
-				let votes = Votes { index, threshold, ayes: vec![who.clone()], nays: vec![], end };
+				let votes = Votes {
+					index,
+					threshold,
+					ayes: vec![who.clone()],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				};
 				<Voting<T, I>>::insert(proposal_hash, votes);
 
 				Self::deposit_event(RawEvent::Proposed(who, index, proposal_hash, threshold));
@@ -497,11 +598,13 @@ decl_module! {
 		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
 		/// - DB:
 		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - up to `M` storage reads `MemberWeight` (codec `O(1)`) to tally the votes
 		///   - 1 storage mutation `Voting` (codec `O(M)`)
 		/// - 1 event
 		/// # </weight>
 		#[weight = (
-			T::WeightInfo::vote(T::MaxMembers::get()),
+			T::WeightInfo::vote(T::MaxMembers::get())
+				.saturating_add(T::DbWeight::get().reads(T::MaxMembers::get() as Weight)),
 			DispatchClass::Operational
 		)]
 		fn vote(origin,
@@ -518,10 +621,15 @@ decl_module! {
 
 			let position_yes = voting.ayes.iter().position(|a| a == &who);
 			let position_no = voting.nays.iter().position(|a| a == &who);
+			let position_abstain = voting.abstentions.iter().position(|a| a == &who);
 
 			// Detects first vote of the member in the motion
-			let is_account_voting_first_time = position_yes.is_none() && position_no.is_none();
+			let is_account_voting_first_time =
+				position_yes.is_none() && position_no.is_none() && position_abstain.is_none();
 
+			if let Some(pos) = position_abstain {
+				voting.abstentions.swap_remove(pos);
+			}
 			if approve {
 				if position_yes.is_none() {
 					voting.ayes.push(who.clone());
@@ -542,25 +650,83 @@ decl_module! {
 				}
 			}
 
-			let yes_votes = voting.ayes.len() as MemberCount;
-			let no_votes = voting.nays.len() as MemberCount;
+			let yes_votes = Self::vote_weight(&voting.ayes);
+			let no_votes = Self::vote_weight(&voting.nays);
 			Self::deposit_event(RawEvent::Voted(who, proposal, approve, yes_votes, no_votes));
 
 			Voting::<T, I>::insert(&proposal, voting);
 
+			let weight = T::WeightInfo::vote(members.len() as u32)
+				.saturating_add(T::DbWeight::get().reads(members.len() as Weight));
 			if is_account_voting_first_time {
 				Ok((
-					Some(T::WeightInfo::vote(members.len() as u32)),
+					Some(weight),
 					Pays::No,
 				).into())
 			} else {
 				Ok((
-					Some(T::WeightInfo::vote(members.len() as u32)),
+					Some(weight),
 					Pays::Yes,
 				).into())
 			}
 		}
 
+		/// Abstain from voting on the given proposal, replacing any aye or nay vote of the sender.
+		///
+		/// Requires the sender to be a member.
+		///
+		/// An abstention counts as participation, so it is not replaced by the default vote when
+		/// the proposal is closed, but it never counts towards the threshold.
+		///
+		/// Transaction fees will be waived if the member is voting on any particular proposal
+		/// for the first time and the call is successful. Subsequent vote changes will charge a fee.
+		/// # <weight>
+		/// ## Weight
+		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
+		/// - DB:
+		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - 1 storage mutation `Voting` (codec `O(M)`)
+		/// - 1 event
+		/// # </weight>
+		#[weight = (
+			T::WeightInfo::vote(T::MaxMembers::get()),
+			DispatchClass::Operational
+		)]
+		fn abstain(origin,
+			proposal: T::Hash,
+			#[compact] index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let members = Self::members();
+			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+
+			let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
+			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+			ensure!(!voting.abstentions.contains(&who), Error::<T, I>::DuplicateVote);
+
+			let position_yes = voting.ayes.iter().position(|a| a == &who);
+			let position_no = voting.nays.iter().position(|a| a == &who);
+
+			// Detects first vote of the member in the motion
+			let is_account_voting_first_time = position_yes.is_none() && position_no.is_none();
+
+			if let Some(pos) = position_yes {
+				voting.ayes.swap_remove(pos);
+			}
+			if let Some(pos) = position_no {
+				voting.nays.swap_remove(pos);
+			}
+			voting.abstentions.push(who.clone());
+
+			let abstentions = voting.abstentions.len() as MemberCount;
+			Self::deposit_event(RawEvent::Abstained(who, proposal, abstentions));
+
+			Voting::<T, I>::insert(&proposal, voting);
+
+			let pays = if is_account_voting_first_time { Pays::No } else { Pays::Yes };
+			Ok((Some(T::WeightInfo::vote(members.len() as u32)), pays).into())
+		}
+
 		/// Close a vote that is either approved, disapproved or whose voting period has ended.
 		///
 		/// May be called by any signed account in order to finish voting and close the proposal.
@@ -569,7 +735,8 @@ decl_module! {
 		/// has enough votes to be approved or disapproved.
 		///
 		/// If called after the end of the voting period abstentions are counted as rejections
-		/// unless there is a prime member set and the prime member cast an approval.
+		/// unless there is a prime member set and the prime member cast an approval. Members who
+		/// explicitly abstained are not counted either way.
 		///
 		/// If the close operation completes successfully with disapproval, the transaction fee will
 		/// be waived. Otherwise execution of the approved operation will be charged to the caller.
@@ -587,6 +754,7 @@ decl_module! {
 		///   - `P2` is proposal-count (code-bounded)
 		/// - DB:
 		///  - 2 storage reads (`Members`: codec `O(M)`, `Prime`: codec `O(1)`)
+		///  - `M` storage reads (`MemberWeight`: codec `O(1)`) to tally the votes, voters being members
 		///  - 3 mutations (`Voting`: codec `O(M)`, `ProposalOf`: codec `O(B)`, `Proposals`: codec `O(P2)`)
 		///  - any mutations done while executing `proposal` (`P1`)
 		/// - up to 3 events
@@ -601,6 +769,7 @@ decl_module! {
 					.max(T::WeightInfo::close_early_disapproved(m, p2))
 					.max(T::WeightInfo::close_approved(b, m, p2))
 					.max(T::WeightInfo::close_disapproved(m, p2))
+					.saturating_add(T::DbWeight::get().reads(m as Weight))
 					.saturating_add(p1)
 			},
 			DispatchClass::Operational
@@ -616,11 +785,14 @@ decl_module! {
 			let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-			let mut no_votes = voting.nays.len() as MemberCount;
-			let mut yes_votes = voting.ayes.len() as MemberCount;
-			let seats = Self::members().len() as MemberCount;
+			let members = Self::members();
+			let (yes_votes, no_votes, explicit_abstentions) = Self::tally(&voting);
+			let seats = members.len() as MemberCount;
+			let total_weight = Self::vote_weight(&members);
+			let tally_weight = T::DbWeight::get().reads(seats as Weight);
 			let approved = yes_votes >= voting.threshold;
-			let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
+			let disapproved =
+				total_weight.saturating_sub(no_votes).saturating_sub(explicit_abstentions) < voting.threshold;
 			// Allow (dis-)approving the proposal as soon as there are enough votes.
 			if approved {
 				let (proposal, len) = Self::validate_and_get_proposal(
@@ -630,9 +802,10 @@ decl_module! {
 				)?;
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let (proposal_weight, proposal_count) =
-					Self::do_approve_proposal(seats, voting, proposal_hash, proposal);
+					Self::do_approve_proposal(total_weight, voting, proposal_hash, proposal);
 				return Ok((
 					Some(T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
+					.saturating_add(tally_weight)
 					.saturating_add(proposal_weight)),
 					Pays::Yes,
 				).into());
@@ -641,7 +814,7 @@ decl_module! {
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 				return Ok((
-					Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
+					Some(T::WeightInfo::close_early_disapproved(seats, proposal_count).saturating_add(tally_weight)),
 					Pays::No,
 				).into());
 			}
@@ -649,16 +822,8 @@ decl_module! {
 			// Only allow actual closing of the proposal after the voting period has ended.
 			ensure!(system::Module::<T>::block_number() >= voting.end, Error::<T, I>::TooEarly);
 
-			let prime_vote = Self::prime().map(|who| voting.ayes.iter().any(|a| a == &who));
-
-			// default voting strategy.
-			let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
-
-			let abstentions = seats - (yes_votes + no_votes);
-			match default {
-				true => yes_votes += abstentions,
-				false => no_votes += abstentions,
-			}
+			let (yes_votes, no_votes) =
+				Self::final_tally(&voting, (yes_votes, no_votes, explicit_abstentions), total_weight);
 			let approved = yes_votes >= voting.threshold;
 
 			if approved {
@@ -669,9 +834,10 @@ decl_module! {
 				)?;
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let (proposal_weight, proposal_count) =
-					Self::do_approve_proposal(seats, voting, proposal_hash, proposal);
+					Self::do_approve_proposal(total_weight, voting, proposal_hash, proposal);
 				return Ok((
 					Some(T::WeightInfo::close_approved(len as u32, seats, proposal_count)
+					.saturating_add(tally_weight)
 					.saturating_add(proposal_weight)),
 					Pays::Yes,
 				).into());
@@ -679,7 +845,7 @@ decl_module! {
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 				return Ok((
-					Some(T::WeightInfo::close_disapproved(seats, proposal_count)),
+					Some(T::WeightInfo::close_disapproved(seats, proposal_count).saturating_add(tally_weight)),
 					Pays::No,
 				).into());
 			}
@@ -704,6 +870,23 @@ decl_module! {
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 			Ok(Some(T::WeightInfo::disapprove_proposal(proposal_count)).into())
 		}
+
+		/// Set the weight of an account's vote, every account's vote has a weight of 1 unless set.
+		///
+		/// Motions are approved once the summed weight of their ayes reaches their threshold.
+		///
+		/// Must be called by the Root origin.
+		///
+		/// # <weight>
+		/// - 1 storage write (`MemberWeight`)
+		/// - 1 event
+		/// # </weight>
+		#[weight = T::DbWeight::get().writes(1)]
+		fn set_member_weight(origin, who: T::AccountId, weight: MemberCount) {
+			ensure_root(origin)?;
+			MemberWeight::<T, I>::insert(&who, weight);
+			Self::deposit_event(RawEvent::MemberWeightSet(who, weight));
+		}
 	}
 }
 
@@ -749,8 +932,11 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 	/// Two removals, one mutation.
 	/// Computation and i/o `O(P)` where:
 	/// - `P` is number of active proposals
+	///
+	/// The proposal is dispatched from `RawOrigin::Members(threshold, total_weight)`, where
+	/// `total_weight` is the summed `MemberWeight` of all members.
 	fn do_approve_proposal(
-		seats: MemberCount,
+		total_weight: MemberCount,
 		voting: Votes<T::AccountId, T::BlockNumber>,
 		proposal_hash: T::Hash,
 		proposal: <T as Config<I>>::Proposal,
@@ -758,7 +944,7 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		Self::deposit_event(RawEvent::Approved(proposal_hash));
 
 		let dispatch_weight = proposal.get_dispatch_info().weight;
-		let origin = RawOrigin::Members(voting.threshold, seats).into();
+		let origin = RawOrigin::Members(voting.threshold, total_weight).into();
 		let result = proposal.dispatch(origin);
 		Self::deposit_event(
 			RawEvent::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
@@ -770,17 +956,144 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		(proposal_weight, proposal_count)
 	}
 
+	/// The summed `MemberWeight` of the given accounts.
+	fn vote_weight(who: &[T::AccountId]) -> MemberCount {
+		who.iter().fold(0, |total: MemberCount, member| total.saturating_add(Self::member_weight(member)))
+	}
+
+	/// The summed `MemberWeight` of the ayes, nays and explicit abstentions of a motion.
+	fn tally(voting: &Votes<T::AccountId, T::BlockNumber>) -> (MemberCount, MemberCount, MemberCount) {
+		(
+			Self::vote_weight(&voting.ayes),
+			Self::vote_weight(&voting.nays),
+			Self::vote_weight(&voting.abstentions),
+		)
+	}
+
+	/// The weighted ayes and nays of a motion once its voting period has ended, with the members
+	/// who did not vote given the default vote. `tally` is the motion's `tally` and `total_weight`
+	/// is the summed weight of all members.
+	fn final_tally(
+		voting: &Votes<T::AccountId, T::BlockNumber>,
+		tally: (MemberCount, MemberCount, MemberCount),
+		total_weight: MemberCount,
+	) -> (MemberCount, MemberCount) {
+		let (mut yes_votes, mut no_votes, explicit_abstentions) = tally;
+
+		let prime_vote = Self::prime().map(|who| voting.ayes.iter().any(|a| a == &who));
+
+		// default voting strategy.
+		let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, total_weight);
+
+		let abstentions = total_weight
+			.saturating_sub(yes_votes)
+			.saturating_sub(no_votes)
+			.saturating_sub(explicit_abstentions);
+		match default {
+			true => yes_votes = yes_votes.saturating_add(abstentions),
+			false => no_votes = no_votes.saturating_add(abstentions),
+		}
+		(yes_votes, no_votes)
+	}
+
+	/// Disapprove at most `MaxExpiredPerBlock` motions whose voting period ended by block `now`
+	/// and that did not reach their threshold. Motions that would pass are left for an explicit
+	/// `close` since they must be dispatched with a weight bound. They are tallied and skipped
+	/// without counting towards the limit, so they cannot hold back the motions behind them.
+	///
+	/// Motions all share the same `MotionDuration` and are kept in the order they were proposed,
+	/// so nothing has expired unless the oldest motion has, and the scan stops at the first motion
+	/// that is still open.
+	///
+	/// Weight: 1 read (`Proposals`), and a `Voting` read for the oldest motion if any. Only once
+	/// it has expired, 1 read (`Members`) and a `MemberWeight` read per member, then for each
+	/// scanned motion a `Voting` read, a `Prime` read and a `MemberWeight` read per voter, bounded
+	/// by members-count, plus the weight of disapproving it. At most `MaxProposals` motions are
+	/// scanned.
+	fn disapprove_expired(now: T::BlockNumber) -> Weight {
+		let db = T::DbWeight::get();
+		let max_expired = T::MaxExpiredPerBlock::get();
+		if max_expired == 0 {
+			return 0
+		}
+
+		let proposals = Self::proposals();
+		let oldest_expired = match proposals.first() {
+			Some(oldest) => Self::voting(oldest).map_or(true, |voting| voting.end <= now),
+			None => return db.reads(1),
+		};
+		if !oldest_expired {
+			return db.reads(2)
+		}
+
+		let members = Self::members();
+		let seats = members.len() as MemberCount;
+		let total_weight = Self::vote_weight(&members);
+		let mut weight = db.reads(3 + seats as Weight);
+		let mut disapproved = 0;
+		for proposal_hash in proposals {
+			if disapproved >= max_expired {
+				break
+			}
+			weight = weight.saturating_add(db.reads(1));
+			let voting = match Self::voting(&proposal_hash) {
+				Some(voting) => voting,
+				None => continue,
+			};
+			if voting.end > now {
+				break
+			}
+			weight = weight.saturating_add(db.reads(1 + seats as Weight));
+			let (yes_votes, _) = Self::final_tally(&voting, Self::tally(&voting), total_weight);
+			if yes_votes < voting.threshold {
+				disapproved += 1;
+				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+				weight = weight.saturating_add(T::WeightInfo::close_disapproved(seats, proposal_count));
+			}
+		}
+		weight
+	}
+
 	fn do_disapprove_proposal(proposal_hash: T::Hash) -> u32 {
 		// disapproved
 		Self::deposit_event(RawEvent::Disapproved(proposal_hash));
 		Self::remove_proposal(proposal_hash)
 	}
 
-	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals.
+	/// Migrate `Voting` from V1 to V2, adding empty abstentions to every ongoing motion.
+	///
+	/// Returns the weight consumed, this is a no-op unless storage is at V1.
+	pub fn migrate_v1_to_v2() -> Weight {
+		if StorageVersion::<I>::get() != Releases::V1 {
+			return 0
+		}
+		StorageVersion::<I>::put(Releases::V2);
+
+		let mut translated: Weight = 0;
+		Voting::<T, I>::translate::<VotesV1<T::AccountId, T::BlockNumber>, _>(|_, votes| {
+			translated += 1;
+			Some(Votes {
+				index: votes.index,
+				threshold: votes.threshold,
+				ayes: votes.ayes,
+				nays: votes.nays,
+				abstentions: vec![],
+				end: votes.end,
+			})
+		});
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals and
+	// refunding any deposit held for it.
 	fn remove_proposal(proposal_hash: T::Hash) -> u32 {
 		// remove proposal and vote
 		ProposalOf::<T, I>::remove(&proposal_hash);
 		Voting::<T, I>::remove(&proposal_hash);
+		if let Some((proposer, deposit)) = DepositOf::<T, I>::take(&proposal_hash) {
+			T::Currency::unreserve(&proposer, deposit);
+			Self::deposit_event(RawEvent::DepositRefunded(proposer, deposit));
+		}
 		let num_proposals = Proposals::<T, I>::mutate(|proposals| {
 			proposals.retain(|h| h != &proposal_hash);
 			proposals.len() + 1 // calculate weight based on original length
@@ -832,6 +1145,9 @@ impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 					votes.nays = votes.nays.into_iter()
 						.filter(|i| outgoing.binary_search(i).is_err())
 						.collect();
+					votes.abstentions = votes.abstentions.into_iter()
+						.filter(|i| outgoing.binary_search(i).is_err())
+						.collect();
 					*v = Some(votes);
 				}
 			);
@@ -963,7 +1279,7 @@ impl<
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::{Hashable, assert_ok, assert_noop, parameter_types};
+	use frame_support::{Hashable, assert_ok, assert_noop, parameter_types, traits::OnInitialize};
 	use frame_system::{self as system, EventRecord, Phase};
 	use hex_literal::hex;
 	use sp_core::H256;
@@ -971,13 +1287,29 @@ mod tests {
 		traits::{BlakeTwo256, IdentityLookup}, testing::Header,
 		BuildStorage,
 	};
+	use std::cell::RefCell;
 	use crate as collective;
 
+	thread_local! {
+		static PROPOSAL_DEPOSIT: RefCell<u64> = RefCell::new(0);
+	}
+	pub struct ProposalDeposit;
+	impl Get<u64> for ProposalDeposit {
+		fn get() -> u64 {
+			PROPOSAL_DEPOSIT.with(|v| *v.borrow())
+		}
+	}
+	fn set_proposal_deposit(deposit: u64) {
+		PROPOSAL_DEPOSIT.with(|v| *v.borrow_mut() = deposit);
+	}
+
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub const MotionDuration: u64 = 3;
 		pub const MaxProposals: u32 = 100;
 		pub const MaxMembers: u32 = 100;
+		pub const MaxExpiredPerBlock: u32 = 2;
+		pub const ExistentialDeposit: u64 = 1;
 		pub BlockWeights: frame_system::limits::BlockWeights =
 			frame_system::limits::BlockWeights::simple_max(1024);
 	}
@@ -999,12 +1331,21 @@ mod tests {
 		type BlockHashCount = BlockHashCount;
 		type Version = ();
 		type PalletInfo = PalletInfo;
-		type AccountData = ();
+		type AccountData = pallet_balances::AccountData<u64>;
 		type OnNewAccount = ();
 		type OnKilledAccount = ();
 		type SystemWeightInfo = ();
 		type SS58Prefix = ();
 	}
+	impl pallet_balances::Config for Test {
+		type MaxLocks = ();
+		type Balance = u64;
+		type Event = Event;
+		type DustRemoval = ();
+		type ExistentialDeposit = ExistentialDeposit;
+		type AccountStore = System;
+		type WeightInfo = ();
+	}
 	impl Config<Instance1> for Test {
 		type Origin = Origin;
 		type Proposal = Call;
@@ -1013,6 +1354,9 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type Currency = Balances;
+		type ProposalDeposit = ProposalDeposit;
+		type MaxExpiredPerBlock = MaxExpiredPerBlock;
 		type WeightInfo = ();
 	}
 	impl Config<Instance2> for Test {
@@ -1023,6 +1367,9 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+		type Currency = Balances;
+		type ProposalDeposit = ProposalDeposit;
+		type MaxExpiredPerBlock = MaxExpiredPerBlock;
 		type WeightInfo = ();
 	}
 	impl Config for Test {
@@ -1033,6 +1380,9 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type Currency = Balances;
+		type ProposalDeposit = ProposalDeposit;
+		type MaxExpiredPerBlock = MaxExpiredPerBlock;
 		type WeightInfo = ();
 	}
 
@@ -1046,6 +1396,7 @@ mod tests {
 			UncheckedExtrinsic = UncheckedExtrinsic
 		{
 			System: system::{Module, Call, Event<T>},
+			Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
 			Collective: collective::<Instance1>::{Module, Call, Event<T>, Origin<T>, Config<T>},
 			CollectiveMajority: collective::<Instance2>::{Module, Call, Event<T>, Origin<T>, Config<T>},
 			DefaultCollective: collective::{Module, Call, Event<T>, Origin<T>, Config<T>},
@@ -1063,8 +1414,12 @@ mod tests {
 				phantom: Default::default(),
 			}),
 			collective: None,
+			pallet_balances: Some(pallet_balances::GenesisConfig::<Test> {
+				balances: vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)],
+			}),
 		}.build_storage().unwrap().into();
 		ext.execute_with(|| System::set_block_number(1));
+		set_proposal_deposit(0);
 		ext
 	}
 
@@ -1110,6 +1465,77 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn weighted_aye_passes_motion_that_fails_by_headcount() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			assert_noop!(Collective::set_member_weight(Origin::signed(1), 1, 3), DispatchError::BadOrigin);
+			assert_ok!(Collective::set_member_weight(Origin::root(), 1, 3));
+			assert_eq!(Collective::member_weight(1), 3);
+			assert_eq!(Collective::member_weight(2), 1);
+
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, false));
+			assert_ok!(Collective::vote(Origin::signed(3), hash.clone(), 0, false));
+			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance1(RawEvent::MemberWeightSet(1, 3))),
+				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 3))),
+				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), false, 3, 1))),
+				record(Event::collective_Instance1(RawEvent::Voted(3, hash.clone(), false, 3, 2))),
+				record(Event::collective_Instance1(RawEvent::Closed(hash.clone(), 3, 2))),
+				record(Event::collective_Instance1(RawEvent::Approved(hash.clone()))),
+				record(Event::collective_Instance1(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin))))
+			]);
+		});
+	}
+
+	#[test]
+	fn propose_rejects_threshold_above_total_member_weight() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+
+			// Members 1, 2 and 3 have a total weight of 3
+			assert_noop!(
+				Collective::propose(Origin::signed(1), 4, Box::new(proposal.clone()), proposal_len),
+				Error::<Test, Instance1>::ThresholdTooHigh
+			);
+			assert_ok!(Collective::set_member_weight(Origin::root(), 3, 2));
+			assert_ok!(Collective::propose(Origin::signed(1), 4, Box::new(proposal.clone()), proposal_len));
+		});
+	}
+
+	#[test]
+	fn default_vote_counts_weight_of_members_who_did_not_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			// 3 does not vote, their weight goes to the prime's aye
+			assert_ok!(Collective::set_member_weight(Origin::root(), 3, 2));
+			Prime::<Test, Instance1>::set(Some(1));
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, false));
+
+			System::set_block_number(4);
+			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let closed = Event::collective_Instance1(RawEvent::Closed(hash.clone(), 3, 1));
+			assert!(System::events().iter().any(|record| record.event == closed));
+			let approved = Event::collective_Instance1(RawEvent::Approved(hash.clone()));
+			assert!(System::events().iter().any(|record| record.event == approved));
+		});
+	}
+
 	#[test]
 	fn proposal_weight_limit_works_on_approve() {
 		new_test_ext().execute_with(|| {
@@ -1237,12 +1663,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], abstentions: vec![], end })
 			);
 			Collective::change_members_sorted(&[4], &[1], &[2, 3, 4]);
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], abstentions: vec![], end })
 			);
 
 			let proposal = make_proposal(69);
@@ -1252,12 +1678,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(3), hash.clone(), 1, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], abstentions: vec![], end })
 			);
 			Collective::change_members_sorted(&[], &[3], &[2, 4]);
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], abstentions: vec![], end })
 			);
 		});
 	}
@@ -1273,12 +1699,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], abstentions: vec![], end })
 			);
 			assert_ok!(Collective::set_members(Origin::root(), vec![2, 3, 4], None, MaxMembers::get()));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], abstentions: vec![], end })
 			);
 
 			let proposal = make_proposal(69);
@@ -1288,12 +1714,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(3), hash.clone(), 1, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], abstentions: vec![], end })
 			);
 			assert_ok!(Collective::set_members(Origin::root(), vec![2, 4], None, MaxMembers::get()));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], abstentions: vec![], end })
 			);
 		});
 	}
@@ -1310,7 +1736,7 @@ mod tests {
 			assert_eq!(Collective::proposal_of(&hash), Some(proposal));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![1], nays: vec![], abstentions: vec![], end })
 			);
 
 			assert_eq!(System::events(), vec![
@@ -1425,7 +1851,7 @@ mod tests {
 			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![], abstentions: vec![], end })
 			);
 			assert_noop!(
 				Collective::vote(Origin::signed(1), hash.clone(), 0, true),
@@ -1434,7 +1860,7 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(1), hash.clone(), 0, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![1], end })
+				Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![1], abstentions: vec![], end })
 			);
 			assert_noop!(
 				Collective::vote(Origin::signed(1), hash.clone(), 0, false),
@@ -1484,7 +1910,7 @@ mod tests {
 			);
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![], abstentions: vec![], end })
 			);
 
 			// For the motion, acc 2's first vote, expecting Ok with Pays::No.
@@ -1732,4 +2158,297 @@ mod tests {
 			]);
 		})
 	}
+
+	#[test]
+	fn propose_reserves_deposit_and_close_refunds_it() {
+		new_test_ext().execute_with(|| {
+			set_proposal_deposit(5);
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_eq!(Balances::reserved_balance(1), 5);
+			assert_eq!(Balances::free_balance(1), 95);
+			assert_eq!(Collective::deposit_of(&hash), Some((1, 5)));
+
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, false));
+			assert_ok!(Collective::close(Origin::signed(2), hash.clone(), 0, proposal_weight, proposal_len));
+
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::free_balance(1), 100);
+			assert_eq!(Collective::deposit_of(&hash), None);
+			assert_eq!(
+				System::events().last().map(|record| record.event.clone()),
+				Some(Event::collective_Instance1(RawEvent::DepositRefunded(1, 5))),
+			);
+		});
+	}
+
+	#[test]
+	fn deposit_is_refunded_when_approved() {
+		new_test_ext().execute_with(|| {
+			set_proposal_deposit(5);
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
+			assert_eq!(Balances::reserved_balance(1), 5);
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_ok!(Collective::close(Origin::signed(2), hash.clone(), 0, proposal_weight, proposal_len));
+
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::free_balance(1), 100);
+			assert_eq!(
+				System::events().last().map(|record| record.event.clone()),
+				Some(Event::collective_Instance1(RawEvent::DepositRefunded(1, 5))),
+			);
+		});
+	}
+
+	#[test]
+	fn deposit_is_refunded_when_root_disapproves() {
+		new_test_ext().execute_with(|| {
+			set_proposal_deposit(5);
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::disapprove_proposal(Origin::root(), hash.clone()));
+
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Collective::deposit_of(&hash), None);
+		});
+	}
+
+	#[test]
+	fn propose_fails_without_enough_balance_for_deposit() {
+		new_test_ext().execute_with(|| {
+			set_proposal_deposit(101);
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+
+			assert_noop!(
+				Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len),
+				Error::<Test, Instance1>::InsufficientProposersBalance,
+			);
+		});
+	}
+
+	#[test]
+	fn no_deposit_is_taken_when_disabled_or_executed_directly() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Collective::deposit_of(&hash), None);
+
+			set_proposal_deposit(5);
+			let proposal = make_proposal(69);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			assert_ok!(Collective::propose(Origin::signed(1), 1, Box::new(proposal.clone()), proposal_len));
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	#[test]
+	fn abstain_replaces_previous_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let end = 4;
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, false));
+
+			let abstain = Collective::abstain(Origin::signed(2), hash.clone(), 0).unwrap();
+			assert_eq!(abstain.pays_fee, Pays::Yes);
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes { index: 0, threshold: 3, ayes: vec![1], nays: vec![], abstentions: vec![2], end })
+			);
+			assert_noop!(
+				Collective::abstain(Origin::signed(2), hash.clone(), 0),
+				Error::<Test, Instance1>::DuplicateVote,
+			);
+
+			let abstain = Collective::abstain(Origin::signed(1), hash.clone(), 0).unwrap();
+			assert_eq!(abstain.pays_fee, Pays::Yes);
+			let abstain = Collective::abstain(Origin::signed(3), hash.clone(), 0).unwrap();
+			assert_eq!(abstain.pays_fee, Pays::No);
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes { index: 0, threshold: 3, ayes: vec![], nays: vec![], abstentions: vec![2, 1, 3], end })
+			);
+
+			assert_ok!(Collective::vote(Origin::signed(1), hash.clone(), 0, true));
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes { index: 0, threshold: 3, ayes: vec![1], nays: vec![], abstentions: vec![2, 3], end })
+			);
+			assert_eq!(System::events().last().map(|r| r.event.clone()),
+				Some(Event::collective_Instance1(RawEvent::Voted(1, hash.clone(), true, 1, 0))));
+
+			assert_noop!(
+				Collective::abstain(Origin::signed(4), hash.clone(), 0),
+				Error::<Test, Instance1>::NotMember,
+			);
+			assert_noop!(
+				Collective::abstain(Origin::signed(2), hash.clone(), 1),
+				Error::<Test, Instance1>::WrongIndex,
+			);
+		});
+	}
+
+	#[test]
+	fn abstentions_can_disapprove_early() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_ok!(Collective::abstain(Origin::signed(3), hash.clone(), 0));
+			assert_ok!(Collective::close(Origin::signed(2), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 3))),
+				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
+				record(Event::collective_Instance1(RawEvent::Abstained(3, hash.clone(), 1))),
+				record(Event::collective_Instance1(RawEvent::Closed(hash.clone(), 2, 0))),
+				record(Event::collective_Instance1(RawEvent::Disapproved(hash.clone()))),
+			]);
+		});
+	}
+
+	#[test]
+	fn abstentions_do_not_take_the_prime_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3, 4], Some(1), MaxMembers::get()));
+
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_ok!(Collective::abstain(Origin::signed(3), hash.clone(), 0));
+
+			System::set_block_number(4);
+			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 3))),
+				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
+				record(Event::collective_Instance1(RawEvent::Abstained(3, hash.clone(), 1))),
+				record(Event::collective_Instance1(RawEvent::Closed(hash.clone(), 3, 0))),
+				record(Event::collective_Instance1(RawEvent::Approved(hash.clone()))),
+				record(Event::collective_Instance1(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin))))
+			]);
+		});
+	}
+
+	#[test]
+	fn expired_proposals_are_disapproved_on_initialize() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
+
+			Collective::on_initialize(3);
+			assert_eq!(Collective::proposals(), vec![hash]);
+
+			System::set_block_number(4);
+			Collective::on_initialize(4);
+			assert_eq!(Collective::proposals(), vec![]);
+			assert_eq!(Collective::proposal_of(&hash), None);
+			assert_eq!(Collective::voting(&hash), None);
+			assert_eq!(System::events().last().map(|r| r.event.clone()),
+				Some(Event::collective_Instance1(RawEvent::Disapproved(hash))));
+		});
+	}
+
+	#[test]
+	fn expired_proposals_disapproved_on_initialize_are_bounded() {
+		new_test_ext().execute_with(|| {
+			// Passes once the prime's default vote is counted, so it is left for `close`.
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], Some(1), MaxMembers::get()));
+			let passing = make_proposal(1);
+			let passing_len: u32 = passing.using_encoded(|p| p.len() as u32);
+			let passing_hash = BlakeTwo256::hash_of(&passing);
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(passing.clone()), passing_len));
+
+			let hashes: Vec<H256> = (2..5).map(|value| {
+				let proposal = make_proposal(value);
+				let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+				assert_ok!(Collective::propose(Origin::signed(2), 3, Box::new(proposal.clone()), proposal_len));
+				BlakeTwo256::hash_of(&proposal)
+			}).collect();
+
+			// The passing motion is skipped, only disapprovals count towards the limit
+			Collective::on_initialize(4);
+			assert_eq!(Collective::proposals(), vec![passing_hash, hashes[2]]);
+
+			Collective::on_initialize(5);
+			assert_eq!(Collective::proposals(), vec![passing_hash]);
+
+			Collective::on_initialize(6);
+			assert_eq!(Collective::proposals(), vec![passing_hash]);
+		});
+	}
+
+	#[test]
+	fn expired_passing_proposals_do_not_hold_back_failing_ones() {
+		new_test_ext().execute_with(|| {
+			// Each passes once the prime's default vote is counted, so it is left for `close`.
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], Some(1), MaxMembers::get()));
+			let passing: Vec<H256> = (1..4).map(|value| {
+				let proposal = make_proposal(value);
+				let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+				assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
+				BlakeTwo256::hash_of(&proposal)
+			}).collect();
+			assert!(passing.len() > MaxExpiredPerBlock::get() as usize);
+
+			let failing = make_proposal(4);
+			let failing_len: u32 = failing.using_encoded(|p| p.len() as u32);
+			let failing_hash = BlakeTwo256::hash_of(&failing);
+			assert_ok!(Collective::propose(Origin::signed(2), 3, Box::new(failing.clone()), failing_len));
+
+			Collective::on_initialize(4);
+			assert_eq!(Collective::proposals(), passing);
+			assert_eq!(Collective::voting(&failing_hash), None);
+			assert_eq!(System::events().last().map(|r| r.event.clone()),
+				Some(Event::collective_Instance1(RawEvent::Disapproved(failing_hash))));
+		});
+	}
+
+	#[test]
+	fn migrate_v1_to_v2_adds_empty_abstentions() {
+		new_test_ext().execute_with(|| {
+			let hash = BlakeTwo256::hash_of(&make_proposal(42));
+			let old = VotesV1::<u64, u64> { index: 0, threshold: 2, ayes: vec![1], nays: vec![2], end: 4 };
+			frame_support::storage::unhashed::put(&Voting::<Test, Instance1>::hashed_key_for(&hash), &old);
+			StorageVersion::<Instance1>::kill();
+
+			assert!(Collective::migrate_v1_to_v2() > 0);
+			assert_eq!(StorageVersion::<Instance1>::get(), Releases::V2);
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![2], abstentions: vec![], end: 4 })
+			);
+			assert_eq!(Collective::migrate_v1_to_v2(), 0);
+		});
+	}
 }
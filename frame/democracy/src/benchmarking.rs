@@ -755,7 +755,7 @@ benchmarks! {
 	}: enact_proposal(RawOrigin::Root, proposal_hash, 0)
 	verify {
 		// Fails due to mismatched origin
-		assert_last_event::<T>(RawEvent::Executed(0, false).into());
+		assert_last_event::<T>(RawEvent::Executed(0, Err(DispatchError::BadOrigin)).into());
 	}
 
 	#[extra]
@@ -431,8 +431,9 @@ decl_event! {
 		NotPassed(ReferendumIndex),
 		/// A referendum has been cancelled. \[ref_index\]
 		Cancelled(ReferendumIndex),
-		/// A proposal has been enacted. \[ref_index, is_ok\]
-		Executed(ReferendumIndex, bool),
+		/// A proposal has been enacted; result will be `Ok` if it returned without error.
+		/// \[ref_index, result\]
+		Executed(ReferendumIndex, DispatchResult),
 		/// An account has delegated their vote to another account. \[who, target\]
 		Delegated(AccountId, AccountId),
 		/// An \[account\] has cancelled a previous delegation operation.
@@ -1544,8 +1545,8 @@ impl<T: Config> Module<T> {
 				let _ = T::Currency::unreserve(&provider, deposit);
 				Self::deposit_event(RawEvent::PreimageUsed(proposal_hash, provider, deposit));
 
-				let ok = proposal.dispatch(frame_system::RawOrigin::Root.into()).is_ok();
-				Self::deposit_event(RawEvent::Executed(index, ok));
+				let result = proposal.dispatch(frame_system::RawOrigin::Root.into());
+				Self::deposit_event(RawEvent::Executed(index, result.map(|_| ()).map_err(|e| e.error)));
 
 				Ok(())
 			} else {
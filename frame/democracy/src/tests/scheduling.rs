@@ -36,6 +36,24 @@ fn simple_passing_should_work() {
 	});
 }
 
+#[test]
+fn enacted_proposal_reports_its_dispatch_result() {
+	new_test_ext().execute_with(|| {
+		let r = Democracy::inject_referendum(
+			2,
+			set_balance_proposal_hash_and_note(2),
+			VoteThreshold::SuperMajorityApprove,
+			0
+		);
+		assert_ok!(Democracy::vote(Origin::signed(1), r, aye(1)));
+		next_block();
+		next_block();
+
+		let executed = Event::pallet_democracy(RawEvent::Executed(r, Ok(())));
+		assert!(System::events().iter().any(|record| record.event == executed));
+	});
+}
+
 #[test]
 fn simple_failing_should_work() {
 	new_test_ext().execute_with(|| {
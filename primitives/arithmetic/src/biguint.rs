@@ -429,6 +429,73 @@ impl BigUint {
 	}
 }
 
+/// Remainder of `a` divided by a single limb `b`.
+///
+/// Invariant: `b` cannot be zero.
+fn rem_unit(a: &BigUint, b: Single) -> Single {
+	let b = Double::from(b.max(1));
+	let mut r: Double = 0;
+	for d in (0..a.len()).rev() {
+		// PROOF: r < b <= B - 1, hence (B - 1) * B + (B - 1) still fits in double.
+		r = (r * B + Double::from(a.get(d))) % b;
+	}
+	// PROOF: r < b, conversion is safe.
+	r as Single
+}
+
+/// Computes the remainder of `a` divided by `b`, stripped of leading zero limbs.
+///
+/// Unlike [`BigUint::div`], `a` and `b` can be of any size. Returns `None` if `b` is zero.
+pub fn rem(a: &BigUint, b: &BigUint) -> Option<BigUint> {
+	if b.is_zero() {
+		return None
+	}
+	let mut a = a.clone();
+	let mut b = b.clone();
+	a.lstrip();
+	b.lstrip();
+
+	let mut r = if a < b {
+		a
+	} else if b.len() == 1 {
+		BigUint::from(rem_unit(&a, b.lsb()))
+	} else if a.len() > b.len() {
+		// both are stripped and `b` has at least two limbs, `div` always succeeds.
+		a.div(&b, true)?.1
+	} else {
+		// same number of limbs, hence the quotient fits into a single limb. Find the biggest `q`
+		// such that `b * q <= a`. `a >= b`, so `q >= 1`.
+		let (mut low, mut high): (Double, Double) = (1, B - 1);
+		while low < high {
+			let mid = (low + high + 1) / 2;
+			// PROOF: mid < B. conversion is safe.
+			if b.clone().mul(&BigUint::from(mid as Single)) <= a {
+				low = mid;
+			} else {
+				high = mid - 1;
+			}
+		}
+		a.sub(&b.mul(&BigUint::from(low as Single))).ok()?
+	};
+	r.lstrip();
+	Some(r)
+}
+
+/// Computes the greatest common divisor of `a` and `b`, stripped of leading zero limbs.
+///
+/// The gcd of any number and zero is the number itself.
+pub fn gcd(a: &BigUint, b: &BigUint) -> BigUint {
+	let mut a = a.clone();
+	let mut b = b.clone();
+	// `rem` is `None` once `b` reaches zero.
+	while let Some(r) = rem(&a, &b) {
+		a = b;
+		b = r;
+	}
+	a.lstrip();
+	a
+}
+
 impl sp_std::fmt::Debug for BigUint {
 	#[cfg(feature = "std")]
 	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
@@ -762,4 +829,61 @@ pub mod tests {
 		assert_eq!(c.clone().div_unit(2), BigUint { digits: vec![7, 14, 50] });
 		assert_eq!(c.clone().div_unit(7), BigUint { digits: vec![2, 4, 14] });
 	}
+
+	#[test]
+	fn rem_works() {
+		let check = |a: u128, b: u128| {
+			let r = rem(&BigUint::from(a), &BigUint::from(b)).unwrap();
+			assert_eq!(u128::try_from(r).unwrap(), a % b, "{} % {}", a, b);
+		};
+
+		// `a` smaller than `b`.
+		check(5, 1 << 70);
+		// single limb divisor.
+		check(u128::max_value(), 7);
+		check(1 << 100, 3);
+		check(10, 10);
+		// more limbs than the divisor.
+		check(u128::max_value(), (1 << 40) + 17);
+		check((1 << 120) + 1, (1 << 64) - 1);
+		// same number of limbs.
+		check(u128::max_value(), (1 << 96) + 1);
+		check((1 << 127) + 12345, (1 << 96) + 7);
+		check(u128::max_value(), u128::max_value() - 1);
+		check((1 << 64) + 5, (1 << 64) + 5);
+
+		// leading zero limbs are stripped.
+		let a = BigUint::from_limbs(&[0, 0, 1, 5]);
+		let b = BigUint::from_limbs(&[0, 3]);
+		assert_eq!(rem(&a, &b).unwrap().digits, vec![0]);
+		assert_eq!(rem(&b, &a).unwrap().digits, vec![3]);
+
+		assert!(rem(&BigUint::from(10u32), &BigUint::zero()).is_none());
+	}
+
+	#[test]
+	fn gcd_works() {
+		let gcd_of = |a: u128, b: u128| {
+			u128::try_from(gcd(&BigUint::from(a), &BigUint::from(b))).unwrap()
+		};
+		// Mersenne primes 2^61 - 1 and 2^89 - 1.
+		let p61 = (1u128 << 61) - 1;
+		let p89 = (1u128 << 89) - 1;
+
+		// coprime.
+		assert_eq!(gcd_of(p61, p89), 1);
+		assert_eq!(gcd_of(35, 64), 1);
+		assert_eq!(gcd_of(p89, 1 << 100), 1);
+
+		// common factor.
+		assert_eq!(gcd_of(p61 * 3, p61 * 5), p61);
+		assert_eq!(gcd_of(p61 * 12, p61 * 18), p61 * 6);
+		assert_eq!(gcd_of(1 << 100, 1 << 70), 1 << 70);
+		assert_eq!(gcd_of(p89, p89), p89);
+
+		// zero.
+		assert_eq!(gcd_of(p89, 0), p89);
+		assert_eq!(gcd_of(0, p89), p89);
+		assert_eq!(gcd_of(0, 0), 0);
+	}
 }
@@ -1049,6 +1049,11 @@ macro_rules! implement_fixed {
 				let a = $name::checked_from_rational(inner_min, 0.saturating_sub(accuracy));
 				assert_eq!(a, None);
 
+				// Ratio above max => None rather than saturating.
+				let a = $name::checked_from_rational(inner_max, accuracy - 1);
+				assert_eq!(a, None);
+				assert_eq!($name::saturating_from_rational(inner_max, accuracy - 1), $name::max_value());
+
 				if $name::SIGNED {
 					// Min - 1 => Underflow => None.
 					let a = $name::checked_from_rational(inner_max as u128 + 2, 0.saturating_sub(accuracy));
@@ -1118,6 +1123,13 @@ macro_rules! implement_fixed {
 				assert_eq!(c.checked_mul_int(2i128), Some(510));
 				assert_eq!(c.checked_mul_int(i128::max_value()), None);
 				assert_eq!(c.checked_mul_int(i128::min_value()), None);
+
+				// Near u128 max => None rather than saturating.
+				assert_eq!($name::one().checked_mul_int(u128::max_value()), Some(u128::max_value()));
+				assert_eq!(a.checked_mul_int(u128::max_value()), Some(u128::max_value() / 2));
+				assert_eq!(c.checked_mul_int(u128::max_value()), None);
+				assert_eq!(c.checked_mul_int(u128::max_value() / 255 + 1), None);
+				assert_eq!(c.saturating_mul_int(u128::max_value()), u128::max_value());
 			}
 
 			#[test]
@@ -490,6 +490,32 @@ mod threshold_compare_tests {
 		assert_eq!(102u32.tcmp(&b, e), 102u32.cmp(&b));
 	}
 
+	#[test]
+	fn epsilon_ord_works_with_per_things() {
+		let b = Perbill::from_parts(115);
+		let e = Perbill::from_parts(11);
+
+		// [115 - 11 (104), 115 + 11 (126)] parts are all equal
+		assert_eq!(Perbill::from_parts(104).tcmp(&b, e), Ordering::Equal);
+		assert_eq!(Perbill::from_parts(115).tcmp(&b, e), Ordering::Equal);
+		assert_eq!(Perbill::from_parts(120).tcmp(&b, e), Ordering::Equal);
+		assert_eq!(Perbill::from_parts(126).tcmp(&b, e), Ordering::Equal);
+
+		assert_eq!(Perbill::from_parts(127).tcmp(&b, e), Ordering::Greater);
+		assert_eq!(Perbill::from_parts(103).tcmp(&b, e), Ordering::Less);
+
+		// zero threshold is the same as normal ord.
+		assert_eq!(Perbill::from_parts(116).tcmp(&b, Perbill::zero()), Ordering::Greater);
+		assert_eq!(Perbill::from_parts(115).tcmp(&b, Perbill::zero()), Ordering::Equal);
+
+		// bounds saturate.
+		assert_eq!(Perbill::one().tcmp(&Perbill::one(), Perbill::from_percent(10)), Ordering::Equal);
+		assert_eq!(Perbill::zero().tcmp(&Perbill::zero(), Perbill::from_percent(10)), Ordering::Equal);
+		let (b, e) = (Percent::from_percent(45), Percent::from_percent(5));
+		assert_eq!(Percent::from_percent(50).tcmp(&b, e), Ordering::Equal);
+		assert_eq!(Percent::from_percent(51).tcmp(&b, e), Ordering::Greater);
+	}
+
 	#[test]
 	fn peru16_rational_does_not_overflow() {
 		// A historical example that will panic only for per_thing type that are created with
@@ -511,4 +537,12 @@ mod threshold_compare_tests {
 		assert_eq!(Saturating::saturating_pow(i32::min_value(), 2), i32::max_value());
 		assert_eq!(Saturating::saturating_pow(i32::max_value(), 2), i32::max_value());
 	}
+
+	#[test]
+	fn per_thing_saturating_pow_works() {
+		assert_eq!(Perbill::from_percent(50).saturating_pow(2), Perbill::from_percent(25));
+		assert_eq!(Perbill::from_percent(50).saturating_pow(1), Perbill::from_percent(50));
+		assert_eq!(Perbill::from_percent(50).saturating_pow(0), Perbill::one());
+		assert_eq!(Percent::from_percent(10).saturating_pow(2), Percent::from_percent(1));
+	}
 }
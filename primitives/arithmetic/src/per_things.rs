@@ -206,6 +206,10 @@ pub trait PerThing:
 	#[cfg(feature = "std")]
 	fn from_fraction(x: f64) -> Self;
 
+	/// Converts a float into `Self`, clamping it to `[0, 1]` and rounding to the nearest part.
+	#[cfg(feature = "std")]
+	fn from_float(x: f64) -> Self;
+
 	/// Approximate the fraction `p/q` into a per-thing fraction. This will never overflow.
 	///
 	/// The computation of this approximation is performed in the generic type `N`. Given
@@ -373,6 +377,12 @@ macro_rules! implement_per_thing {
 				Self::from_parts((x.max(0.).min(1.) * $max as f64) as Self::Inner)
 			}
 
+			/// NOTE: saturate to 0 or 1 if x is beyond `[0, 1]`
+			#[cfg(feature = "std")]
+			fn from_float(x: f64) -> Self {
+				Self::from_parts((x.max(0.).min(1.) * $max as f64).round() as Self::Inner)
+			}
+
 			fn from_rational_approximation<N>(p: N, q: N) -> Self
 			where
 				N: Clone + Ord + TryInto<Self::Inner> + TryInto<Self::Upper>
@@ -477,6 +487,12 @@ macro_rules! implement_per_thing {
 				<Self as PerThing>::from_fraction(x)
 			}
 
+			/// See [`PerThing::from_float`].
+			#[cfg(feature = "std")]
+			pub fn from_float(x: f64) -> Self {
+				<Self as PerThing>::from_float(x)
+			}
+
 			/// See [`PerThing::from_rational_approximation`].
 			pub fn from_rational_approximation<N>(p: N, q: N) -> Self
 				where N: Clone + Ord + TryInto<$type> +
@@ -633,6 +649,14 @@ macro_rules! implement_per_thing {
 			}
 		}
 
+		impl crate::ThresholdOrd<$name> for $name {
+			/// Compare the parts of `self` and `other`, treating them as equal if they are within
+			/// `threshold` parts of each other.
+			fn tcmp(&self, other: &Self, threshold: Self) -> sp_std::cmp::Ordering {
+				crate::ThresholdOrd::tcmp(&self.0, &other.0, threshold.0)
+			}
+		}
+
 		/// Non-overflow multiplication.
 		///
 		/// This is tailored to be used with a balance type.
@@ -744,6 +768,20 @@ macro_rules! implement_per_thing {
 				assert_eq!($name::from_fraction(-1.0), $name::from_parts(Zero::zero()));
 			}
 
+			#[test]
+			fn from_float_rounds_and_clamps() {
+				assert_eq!($name::from_float(0.0), $name::zero());
+				assert_eq!($name::from_float(1.0), $name::one());
+				// Unlike `from_fraction`, the nearest part is taken
+				assert_eq!($name::from_float(1.6 / $max as f64), $name::from_parts(2));
+				assert_eq!($name::from_float(1.4 / $max as f64), $name::from_parts(1));
+
+				assert_eq!($name::from_float(2.0), $name::one());
+				assert_eq!($name::from_float(-1.0), $name::zero());
+				assert_eq!($name::from_float(f64::INFINITY), $name::one());
+				assert_eq!($name::from_float(f64::NEG_INFINITY), $name::zero());
+			}
+
 			#[test]
 			fn percent_trait_impl_works() {
 				assert_eq!(<$name as PerThing>::from_percent(0), $name::from_parts(Zero::zero()));
@@ -1330,3 +1368,14 @@ implement_per_thing_with_perthousand!(
 	u128,
 	"_Parts per Quintillion_",
 );
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn perbill_from_float_matches_percent() {
+		assert_eq!(Perbill::from_float(0.25), Perbill::from_percent(25));
+		assert_eq!(Perbill::from_float(0.999_999_999_6), Perbill::one());
+	}
+}
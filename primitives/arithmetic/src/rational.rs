@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use sp_std::{cmp::Ordering, prelude::*};
-use crate::helpers_128bit;
+use crate::{helpers_128bit, FixedPointNumber, FixedU128};
 use num_traits::{Zero, One, Bounded};
 use crate::biguint::BigUint;
 
@@ -207,6 +207,16 @@ impl Rational128 {
 			.ok_or("overflow while subtracting numerators")?;
 		Ok(Self(n, self_scaled.1))
 	}
+
+	/// Convert `self` to a `FixedU128`. Equal to `n * FixedU128::DIV / d`.
+	///
+	/// Returns `None` if the denominator is zero or the result overflows.
+	pub fn to_fixed_u128(&self) -> Option<FixedU128> {
+		if self.1.is_zero() {
+			return None
+		}
+		helpers_128bit::multiply_by_rational(self.0, FixedU128::DIV, self.1).ok().map(FixedU128::from_inner)
+	}
 }
 
 impl Bounded for Rational128 {
@@ -486,6 +496,24 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn to_fixed_u128_works() {
+		assert_eq!(r(1, 2).to_fixed_u128(), FixedU128::checked_from_rational(1, 2));
+		assert_eq!(r(3, 7).to_fixed_u128(), FixedU128::checked_from_rational(3, 7));
+		assert_eq!(r(10, 3).to_fixed_u128(), FixedU128::checked_from_rational(10, 3));
+		assert_eq!(r(0, 5).to_fixed_u128(), Some(FixedU128::zero()));
+		assert_eq!(r(5, 5).to_fixed_u128(), Some(FixedU128::one()));
+		assert_eq!(r(1, 2).to_fixed_u128(), Some(FixedU128::saturating_from_rational(1, 2)));
+	}
+
+	#[test]
+	fn to_fixed_u128_fails_on_zero_denominator_or_overflow() {
+		assert_eq!(Rational128::from_unchecked(1, 0).to_fixed_u128(), None);
+		assert_eq!(r(MAX128, 1).to_fixed_u128(), None);
+		assert_eq!(r(MAX128 / FixedU128::DIV + 1, 1).to_fixed_u128(), None);
+		assert!(r(MAX128 / FixedU128::DIV, 1).to_fixed_u128().is_some());
+	}
+
 	#[test]
 	#[ignore]
 	fn multiply_by_rational_fuzzed_equation() {
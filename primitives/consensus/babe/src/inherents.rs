@@ -71,7 +71,7 @@ impl ProvideInherentData for InherentDataProvider {
 	fn on_register(&self, providers: &InherentDataProviders) -> Result<(), Error> {
 		if !providers.has_provider(&sp_timestamp::INHERENT_IDENTIFIER) {
 			// Add the timestamp inherent data provider, as we require it.
-			providers.register_provider(sp_timestamp::InherentDataProvider)
+			providers.register_provider(sp_timestamp::InherentDataProvider::default())
 		} else {
 			Ok(())
 		}
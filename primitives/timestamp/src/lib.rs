@@ -24,10 +24,19 @@ use codec::Encode;
 use codec::Decode;
 #[cfg(feature = "std")]
 use sp_inherents::ProvideInherentData;
+#[cfg(feature = "std")]
+use std::time::Duration;
 use sp_inherents::{InherentIdentifier, IsFatalError, InherentData};
 
 use sp_runtime::RuntimeString;
 
+mod median;
+#[cfg(feature = "std")]
+mod tracker;
+pub use median::{MedianTimePast, TimestampWindow, MEDIAN_TIME_PAST_WINDOW};
+#[cfg(feature = "std")]
+pub use tracker::BlockTimeTracker;
+
 /// The identifier for the `timestamp` inherent.
 pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"timstap0";
 /// The type of the inherent.
@@ -65,6 +74,22 @@ impl InherentError {
 	}
 }
 
+/// Check a `reported` timestamp is not too far ahead of the local time `now`.
+///
+/// Returns `InherentError::ValidAtTimestamp` when `reported` exceeds `now` by more than `max_drift`. This error is
+/// non-fatal, the block is only valid at a later time rather than invalid.
+pub fn check_inherent(
+	reported: InherentType,
+	now: InherentType,
+	max_drift: InherentType,
+) -> Result<(), InherentError> {
+	if reported > now.saturating_add(max_drift) {
+		Err(InherentError::ValidAtTimestamp(reported))
+	} else {
+		Ok(())
+	}
+}
+
 /// Auxiliary trait to extract timestamp inherent data.
 pub trait TimestampInherentData {
 	/// Get timestamp inherent data.
@@ -79,8 +104,29 @@ impl TimestampInherentData for InherentData {
 }
 
 /// Provide duration since unix epoch in millisecond for timestamp inherent.
+///
+/// The default provider reports the wall clock time, `new` and `from_fixed` allow reproducible timestamps in tests.
 #[cfg(feature = "std")]
-pub struct InherentDataProvider;
+#[derive(Default)]
+pub struct InherentDataProvider {
+	/// Added to the wall clock time
+	offset: Duration,
+	/// Reported instead of the wall clock time when set
+	fixed: Option<InherentType>,
+}
+
+#[cfg(feature = "std")]
+impl InherentDataProvider {
+	/// Create a provider reporting the wall clock time shifted forward by `offset`.
+	pub fn new(offset: Duration) -> Self {
+		Self { offset, fixed: None }
+	}
+
+	/// Create a provider which always reports the timestamp `ms`, in milliseconds since unix epoch.
+	pub fn from_fixed(ms: InherentType) -> Self {
+		Self { offset: Duration::default(), fixed: Some(ms) }
+	}
+}
 
 #[cfg(feature = "std")]
 impl ProvideInherentData for InherentDataProvider {
@@ -94,12 +140,16 @@ impl ProvideInherentData for InherentDataProvider {
 	) -> Result<(), sp_inherents::Error> {
 		use wasm_timer::SystemTime;
 
+		if let Some(fixed) = self.fixed {
+			return inherent_data.put_data(INHERENT_IDENTIFIER, &fixed)
+		}
+
 		let now = SystemTime::now();
 		now.duration_since(SystemTime::UNIX_EPOCH)
 			.map_err(|_| {
 				"Current time is before unix epoch".into()
 			}).and_then(|d| {
-				let duration: InherentType = d.as_millis() as u64;
+				let duration: InherentType = (d + self.offset).as_millis() as u64;
 				inherent_data.put_data(INHERENT_IDENTIFIER, &duration)
 			})
 	}
@@ -115,3 +165,69 @@ impl ProvideInherentData for InherentDataProvider {
 pub trait OnTimestampSet<Moment> {
 	fn on_timestamp_set(moment: Moment);
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use wasm_timer::SystemTime;
+
+	fn provide(provider: &InherentDataProvider) -> InherentType {
+		let mut inherent_data = InherentData::new();
+		provider.provide_inherent_data(&mut inherent_data).unwrap();
+		inherent_data.timestamp_inherent_data().unwrap()
+	}
+
+	fn unix_millis() -> InherentType {
+		SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis() as u64
+	}
+
+	#[test]
+	fn inherent_data_provider_provides_wall_clock_time() {
+		let provider = InherentDataProvider::default();
+
+		let before = unix_millis();
+		let first = provide(&provider);
+		let second = provide(&provider);
+		let after = unix_millis();
+
+		assert!(before <= first);
+		assert!(first <= second);
+		assert!(second <= after);
+	}
+
+	#[test]
+	fn inherent_data_provider_applies_offset() {
+		let provider = InherentDataProvider::new(Duration::from_secs(60));
+
+		let before = unix_millis();
+		let provided = provide(&provider);
+		let after = unix_millis();
+
+		assert!(before + 60_000 <= provided);
+		assert!(provided <= after + 60_000);
+	}
+
+	#[test]
+	fn inherent_data_provider_provides_fixed_time() {
+		let provider = InherentDataProvider::from_fixed(1_600_000_000_000);
+
+		assert_eq!(provide(&provider), 1_600_000_000_000);
+		assert_eq!(provide(&provider), 1_600_000_000_000);
+	}
+
+	#[test]
+	fn check_inherent_allows_drift_up_to_the_limit() {
+		assert!(check_inherent(1_000, 1_000, 30).is_ok());
+		assert!(check_inherent(900, 1_000, 30).is_ok());
+		assert!(check_inherent(1_030, 1_000, 30).is_ok());
+		assert!(check_inherent(InherentType::max_value(), InherentType::max_value() - 1, 30).is_ok());
+	}
+
+	#[test]
+	fn check_inherent_rejects_drift_past_the_limit() {
+		match check_inherent(1_031, 1_000, 30) {
+			Err(error @ InherentError::ValidAtTimestamp(1_031)) => assert!(!error.is_fatal_error()),
+			other => panic!("unexpected result: {:?}", other),
+		}
+	}
+}
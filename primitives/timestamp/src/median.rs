@@ -0,0 +1,154 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2019-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Median time past over recent block timestamps, a time source no single block author can move far.
+
+use crate::OnTimestampSet;
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// The number of recent block timestamps the median is taken over.
+pub const MEDIAN_TIME_PAST_WINDOW: usize = 11;
+
+/// Storage key of the `TimestampWindow` kept by `MedianTimePast`, hashed as a pallet storage value is, so it is
+/// outside the `:`-prefixed keys reserved for well known storage.
+fn window_key() -> [u8; 32] {
+	let mut key = [0u8; 32];
+	key[..16].copy_from_slice(&sp_io::hashing::twox_128(b"MedianTimePast"));
+	key[16..].copy_from_slice(&sp_io::hashing::twox_128(b"TimestampWindow"));
+	key
+}
+
+/// A ring buffer of the most recent timestamps.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct TimestampWindow {
+	/// The timestamps, in the order they were written to their slot
+	timestamps: Vec<u64>,
+	/// The slot the next timestamp is written to, once the window is full
+	next: u32,
+}
+
+impl TimestampWindow {
+	/// Record `moment`, overwriting the oldest timestamp once `capacity` are held.
+	pub fn note(&mut self, moment: u64, capacity: usize) {
+		if self.timestamps.len() < capacity {
+			self.timestamps.push(moment);
+		} else if capacity > 0 {
+			let slot = self.next as usize % capacity;
+			self.timestamps[slot] = moment;
+			self.next = ((slot + 1) % capacity) as u32;
+		}
+	}
+
+	/// The median of the held timestamps, the upper of the middle two for an even count. `None` when empty.
+	pub fn median(&self) -> Option<u64> {
+		let mut sorted = self.timestamps.clone();
+		sorted.sort_unstable();
+		sorted.get(sorted.len() / 2).copied()
+	}
+}
+
+/// Tracks the median of the last `MEDIAN_TIME_PAST_WINDOW` timestamps set.
+///
+/// The window is kept in runtime storage, so the tracker works natively and in Wasm alike.
+pub struct MedianTimePast;
+
+impl MedianTimePast {
+	/// The window of recent timestamps.
+	pub fn window() -> TimestampWindow {
+		sp_io::storage::get(&window_key())
+			.and_then(|encoded| TimestampWindow::decode(&mut &encoded[..]).ok())
+			.unwrap_or_default()
+	}
+
+	/// The median time past, zero until a timestamp has been set.
+	pub fn median() -> u64 {
+		Self::window().median().unwrap_or_default()
+	}
+}
+
+impl OnTimestampSet<u64> for MedianTimePast {
+	fn on_timestamp_set(moment: u64) {
+		let mut window = Self::window();
+		window.note(moment, MEDIAN_TIME_PAST_WINDOW);
+		sp_io::storage::set(&window_key(), &window.encode());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn window_overwrites_the_oldest_timestamp() {
+		let mut window = TimestampWindow::default();
+		for moment in &[1, 2, 3, 4, 5] {
+			window.note(*moment, 3);
+		}
+		assert_eq!(window.timestamps, vec![4, 5, 3]);
+		assert_eq!(window.median(), Some(4));
+	}
+
+	#[test]
+	fn median_of_an_empty_window_is_none() {
+		assert_eq!(TimestampWindow::default().median(), None);
+	}
+
+	#[test]
+	fn median_is_stable_with_an_out_of_order_timestamp() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_eq!(MedianTimePast::median(), 0);
+
+			MedianTimePast::on_timestamp_set(1_000);
+			MedianTimePast::on_timestamp_set(2_000);
+			MedianTimePast::on_timestamp_set(3_000);
+			assert_eq!(MedianTimePast::median(), 2_000);
+
+			// A far future timestamp, then one before its predecessors, barely move the median
+			MedianTimePast::on_timestamp_set(90_000);
+			assert_eq!(MedianTimePast::median(), 3_000);
+			MedianTimePast::on_timestamp_set(1_500);
+			assert_eq!(MedianTimePast::median(), 2_000);
+			MedianTimePast::on_timestamp_set(4_000);
+			assert_eq!(MedianTimePast::median(), 3_000);
+		});
+	}
+
+	#[test]
+	fn window_is_kept_under_a_hashed_key() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			MedianTimePast::on_timestamp_set(1_000);
+			assert!(sp_io::storage::get(b":timestamp:median_time_past:").is_none());
+			assert_eq!(
+				sp_io::storage::get(&window_key()).map(|encoded| TimestampWindow::decode(&mut &encoded[..]).ok()),
+				Some(Some(TimestampWindow { timestamps: vec![1_000], next: 0 }))
+			);
+		});
+	}
+
+	#[test]
+	fn median_only_covers_the_window() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			for moment in 1..=MEDIAN_TIME_PAST_WINDOW as u64 * 2 {
+				MedianTimePast::on_timestamp_set(moment * 1_000);
+			}
+			assert_eq!(MedianTimePast::window().timestamps.len(), MEDIAN_TIME_PAST_WINDOW);
+			assert_eq!(MedianTimePast::median(), 17_000);
+		});
+	}
+}
@@ -0,0 +1,79 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2019-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inter-block time tracking for node level monitoring.
+
+use crate::OnTimestampSet;
+use std::cell::Cell;
+
+thread_local! {
+	static LAST_TIMESTAMP: Cell<Option<u64>> = Cell::new(None);
+	static LAST_BLOCK_TIME: Cell<Option<u64>> = Cell::new(None);
+}
+
+/// Records the time between successive timestamps, in milliseconds.
+///
+/// State is kept per thread, so the tracker observes the timestamps set by blocks imported on the calling thread.
+pub struct BlockTimeTracker;
+
+impl BlockTimeTracker {
+	/// The time between the last two timestamps set, `None` until at least two have been seen.
+	pub fn last_block_time() -> Option<u64> {
+		LAST_BLOCK_TIME.with(|block_time| block_time.get())
+	}
+}
+
+impl OnTimestampSet<u64> for BlockTimeTracker {
+	fn on_timestamp_set(moment: u64) {
+		if let Some(last) = LAST_TIMESTAMP.with(|timestamp| timestamp.replace(Some(moment))) {
+			LAST_BLOCK_TIME.with(|block_time| block_time.set(Some(moment.saturating_sub(last))));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn tracks_time_between_timestamps() {
+		assert_eq!(BlockTimeTracker::last_block_time(), None);
+
+		BlockTimeTracker::on_timestamp_set(1_000);
+		assert_eq!(BlockTimeTracker::last_block_time(), None);
+
+		BlockTimeTracker::on_timestamp_set(7_000);
+		assert_eq!(BlockTimeTracker::last_block_time(), Some(6_000));
+
+		BlockTimeTracker::on_timestamp_set(10_000);
+		assert_eq!(BlockTimeTracker::last_block_time(), Some(3_000));
+	}
+
+	#[test]
+	fn earlier_timestamp_saturates_to_zero() {
+		BlockTimeTracker::on_timestamp_set(5_000);
+		BlockTimeTracker::on_timestamp_set(4_000);
+		assert_eq!(BlockTimeTracker::last_block_time(), Some(0));
+	}
+
+	#[test]
+	fn tracker_works_in_a_tuple() {
+		<(BlockTimeTracker, ()) as OnTimestampSet<u64>>::on_timestamp_set(2_000);
+		<(BlockTimeTracker, ()) as OnTimestampSet<u64>>::on_timestamp_set(4_500);
+		assert_eq!(BlockTimeTracker::last_block_time(), Some(2_500));
+	}
+}
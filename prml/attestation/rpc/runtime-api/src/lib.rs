@@ -0,0 +1,41 @@
+// Copyright 2020 Plug New Zealand Limited
+// This file is part of Plug.
+
+// Plug is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Plug is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Plug. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition required by Attestation RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding attestation claim lookups.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use sp_std::vec::Vec;
+
+/// Type used for claim topics, as in the attestation module.
+pub type Topic = Vec<u8>;
+/// Type used for claim values, as in the attestation module.
+pub type Value = sp_core::U256;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query attestation claims.
+	pub trait AttestationApi<AccountId: Decode + Encode> {
+		/// Get every issuer with a claim about the holder.
+		fn attestation_issuers(holder: AccountId) -> Vec<AccountId>;
+		/// Get the value of the claim on a topic about the holder from the issuer, zero if there is none.
+		fn attestation_value(holder: AccountId, issuer: AccountId, topic: Topic) -> Value;
+	}
+}
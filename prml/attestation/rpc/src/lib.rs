@@ -0,0 +1,184 @@
+// Copyright 2020 Plug New Zealand Limited
+// This file is part of Plug.
+
+// Plug is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Plug is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Plug. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the attestation module.
+
+pub use self::gen_client::Client as AttestationClient;
+use codec::{Decode, Encode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+pub use prml_attestation_rpc_runtime_api::{AttestationApi as AttestationRuntimeApi, Topic, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+#[rpc]
+pub trait AttestationApi<BlockHash, AccountId> {
+	/// Get every issuer with a claim about the holder.
+	#[rpc(name = "attestation_issuers")]
+	fn attestation_issuers(&self, holder: AccountId, at: Option<BlockHash>) -> Result<Vec<AccountId>>;
+
+	/// Get the value of the claim on a topic about the holder from the issuer, zero if there is none.
+	#[rpc(name = "attestation_value")]
+	fn attestation_value(
+		&self,
+		holder: AccountId,
+		issuer: AccountId,
+		topic: Topic,
+		at: Option<BlockHash>,
+	) -> Result<Value>;
+}
+
+/// A struct that implements the [`AttestationApi`].
+pub struct Attestation<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Attestation<C, P> {
+	/// Create new `Attestation` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Attestation {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl<C, Block, AccountId> AttestationApi<<Block as BlockT>::Hash, AccountId> for Attestation<C, (Block, AccountId)>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AttestationRuntimeApi<Block, AccountId>,
+	AccountId: Decode + Encode + Send + Sync + 'static,
+{
+	fn attestation_issuers(&self, holder: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<AccountId>> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.client.runtime_api().attestation_issuers(&at, holder).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError as i64),
+			message: "Unable to query attestation issuers.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn attestation_value(
+		&self,
+		holder: AccountId,
+		issuer: AccountId,
+		topic: Topic,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Value> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.client
+			.runtime_api()
+			.attestation_value(&at, holder, issuer, topic)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError as i64),
+				message: "Unable to query attestation value.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{Attestation, AttestationApi};
+	use jsonrpc_core::IoHandler;
+	use std::sync::Arc;
+	use substrate_test_runtime_client::{
+		DefaultTestClientBuilderExt,
+		TestClient,
+		TestClientBuilderExt,
+		TestClientBuilder,
+	};
+
+	fn test_attestation_rpc_handler<P>() -> Attestation<TestClient, P> {
+		let builder = TestClientBuilder::new();
+		let (client, _) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+
+		Attestation::new(client)
+	}
+
+	#[test]
+	fn working_issuers_rpc() {
+		let handler = test_attestation_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(AttestationApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "attestation_issuers",
+			"params":["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\
+			\"result\":[\"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY\"],\
+			\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_value_rpc() {
+		let handler = test_attestation_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(AttestationApi::to_delegate(handler));
+
+		// topic "isOver18"
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "attestation_value",
+			"params":[
+				"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+				"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+				[105, 115, 79, 118, 101, 114, 49, 56]
+			]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\"result\":\"0x1\",\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_value_rpc_without_claim() {
+		let handler = test_attestation_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(AttestationApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "attestation_value",
+			"params":[
+				"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+				"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+				[1, 2, 3]
+			]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\"result\":\"0x0\",\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+}
@@ -29,14 +29,14 @@ benchmarks! {
 	set_claim {
 		let issuer: T::AccountId = whitelisted_caller();
 		let holder: T::AccountId = account("holder", 0, SEED);
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		let value = AttestationValue::from(0xb33f);
 	}: set_claim(RawOrigin::Signed(issuer.clone()), holder.clone(), topic.clone(), value.clone())
 	verify {
 		let issuers: Vec<<T as frame_system::Config>::AccountId> = vec![issuer.clone()];
 		assert_eq!(Attestation::<T>::issuers(holder.clone()), issuers);
-		assert_eq!(Attestation::<T>::topics((holder.clone(), issuer.clone())), [topic.clone()]);
-		assert_eq!(Attestation::<T>::value((holder, issuer, topic)), value);
+		assert_eq!(Attestation::<T>::topics((holder.clone(), issuer.clone())), [topic_from_u256(topic)]);
+		assert_eq!(Attestation::<T>::value((holder, issuer, topic_from_u256(topic))), value);
 	}
 
 	remove_claim {
@@ -46,9 +46,9 @@ benchmarks! {
 
 		let holder: T::AccountId = account("holder", 0, SEED);
 
-		let topic1 = AttestationTopic::from(0xf00d);
-		let topic2 = AttestationTopic::from(0xf00e);
-		let topic3 = AttestationTopic::from(0xf00f);
+		let topic1 = U256::from(0xf00d);
+		let topic2 = U256::from(0xf00e);
+		let topic3 = U256::from(0xf00f);
 
 		let value = AttestationValue::from(0xb33f);
 
@@ -60,7 +60,7 @@ benchmarks! {
 	verify {
 		let issuers: Vec<<T as frame_system::Config>::AccountId> = vec![issuer2.clone(), issuer3.clone()];
 		assert_eq!(Attestation::<T>::issuers(holder.clone()), issuers);
-		assert_ne!(Attestation::<T>::value((holder, issuer1, topic1)), value);
+		assert_ne!(Attestation::<T>::value((holder, issuer1, topic_from_u256(topic1))), value);
 	}
 }
 
@@ -27,11 +27,13 @@
 //!
 //! ## Usage
 //!
-//! Topic and Value are U256 integers. This means that Topic and Value can technically store any value that can be represented in 256 bits.
+//! Topic is a byte string of at most `MaxTopicLen` bytes, so string-like topics can be stored as they are.
+//! Value is a U256 integer, it can store any value that can be represented in 256 bits.
 //!
 //! The user of the module must convert whatever value that they would like to store into a value that can be stored as a U256.
 //!
-//! It is recommended that Topic be a string value converted to hex and stored on the blockchain as a U256.
+//! Topics used to be U256 integers too. `set_claim` and `remove_claim` still accept them, storing them under
+//! their 32-byte big-endian representation, while `set_claim_bytes` and `remove_claim_bytes` take byte topics.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -40,71 +42,204 @@ mod mock;
 mod weights;
 
 use frame_support::sp_std::prelude::*;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure};
+use codec::{Decode, Encode};
+use frame_support::{
+	decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, traits::Get, weights::Weight,
+};
 use frame_system::ensure_signed;
 use sp_core::U256;
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, RuntimeDebug};
+use sp_std::collections::btree_map::BTreeMap;
 use weights::WeightInfo;
 
 pub trait Config: frame_system::Config {
 	type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
+	/// The maximum number of bytes in a topic
+	type MaxTopicLen: Get<u32>;
+	/// The maximum number of claims set in one `set_claims` call
+	type MaxClaimsPerBatch: Get<u32>;
+	/// The maximum number of issuers with claims about one holder
+	type MaxIssuersPerHolder: Get<u32>;
+	/// The maximum number of topics one issuer can claim about one holder
+	type MaxTopicsPerIssuerHolder: Get<u32>;
 	type WeightInfo: WeightInfo;
 }
 
-type AttestationTopic = U256;
-type AttestationValue = U256;
+pub type AttestationTopic = Vec<u8>;
+pub type AttestationValue = U256;
+
+/// The topic a legacy `U256` topic is stored as, its 32-byte big-endian representation
+pub fn topic_from_u256(topic: U256) -> AttestationTopic {
+	let mut bytes = [0_u8; 32];
+	topic.to_big_endian(&mut bytes);
+	bytes.to_vec()
+}
 
 decl_module! {
 	pub struct Module<T: Config> for enum Call where origin: T::Origin {
 		type Error = Error<T>;
 
+		/// The maximum number of bytes in a topic
+		const MaxTopicLen: u32 = T::MaxTopicLen::get();
+
+		/// The maximum number of claims set in one `set_claims` call
+		const MaxClaimsPerBatch: u32 = T::MaxClaimsPerBatch::get();
+
+		/// The maximum number of issuers with claims about one holder
+		const MaxIssuersPerHolder: u32 = T::MaxIssuersPerHolder::get();
+
+		/// The maximum number of topics one issuer can claim about one holder
+		const MaxTopicsPerIssuerHolder: u32 = T::MaxTopicsPerIssuerHolder::get();
+
 		fn deposit_event() = default;
 
 		/// Create or update an existing claim
 		/// The `issuer` of the claim comes from the extrinsic `origin`
 		/// The `topic` and `value` are both U256 which can hold any 32-byte encoded data.
+		/// The claim is stored under the 32-byte big-endian representation of `topic`.
+		#[weight = T::WeightInfo::set_claim()]
+		pub fn set_claim(origin, holder: T::AccountId, topic: U256, value: AttestationValue) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			let topic = topic_from_u256(topic);
+			Self::ensure_claims_fit(&issuer, &[(&holder, &topic)])?;
+
+			Self::create_or_update_claim(holder, issuer, topic, value);
+			Ok(())
+		}
+
+		/// Create or update an existing claim on a byte string topic
+		/// The `issuer` of the claim comes from the extrinsic `origin`
+		/// The `topic` can be at most `MaxTopicLen` bytes.
 		#[weight = T::WeightInfo::set_claim()]
-		pub fn set_claim(origin, holder: T::AccountId, topic: AttestationTopic, value: AttestationValue) -> DispatchResult {
+		pub fn set_claim_bytes(
+			origin,
+			holder: T::AccountId,
+			topic: AttestationTopic,
+			value: AttestationValue,
+		) -> DispatchResult {
 			let issuer = ensure_signed(origin)?;
+			ensure!(topic.len() <= T::MaxTopicLen::get() as usize, Error::<T>::TopicTooLong);
+			Self::ensure_claims_fit(&issuer, &[(&holder, &topic)])?;
 
 			Self::create_or_update_claim(holder, issuer, topic, value);
 			Ok(())
 		}
 
+		/// Create or update many claims from the same `issuer`, the extrinsic `origin`
+		/// Every claim is checked before any is set, so either all claims are set or none.
+		#[weight = T::WeightInfo::set_claim().saturating_mul(claims.len() as Weight)]
+		pub fn set_claims(
+			origin,
+			claims: Vec<(T::AccountId, AttestationTopic, AttestationValue)>,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			ensure!(claims.len() <= T::MaxClaimsPerBatch::get() as usize, Error::<T>::BatchTooLarge);
+			ensure!(
+				claims.iter().all(|(_, topic, _)| topic.len() <= T::MaxTopicLen::get() as usize),
+				Error::<T>::TopicTooLong
+			);
+			let new_claims: Vec<_> = claims.iter().map(|(holder, topic, _)| (holder, topic)).collect();
+			Self::ensure_claims_fit(&issuer, &new_claims)?;
+
+			for (holder, topic, value) in claims {
+				Self::create_or_update_claim(holder, issuer.clone(), topic, value);
+			}
+			Ok(())
+		}
+
 		/// Remove a claim, only the original issuer can remove a claim
 		/// If the `issuer` has not yet issued a claim of `topic`, this function will return error.
 		#[weight = T::WeightInfo::remove_claim()]
-		pub fn remove_claim(origin, holder: T::AccountId, topic: AttestationTopic) -> DispatchResult {
+		pub fn remove_claim(origin, holder: T::AccountId, topic: U256) -> DispatchResult {
 			let issuer = ensure_signed(origin)?;
 
-			ensure!(
-				<Topics<T>>::get((holder.clone(), issuer.clone())).contains(&topic),
-				Error::<T>::TopicNotRegistered
-			);
+			let topic = topic_from_u256(topic);
+			Self::delete_claim(&holder, &issuer, &topic)?;
 
-			<Values<T>>::remove((holder.clone(), issuer.clone(), topic));
+			Self::deposit_event(RawEvent::ClaimRemoved(holder, issuer, topic));
+			Ok(())
+		}
 
-			<Topics<T>>::mutate((holder.clone(), issuer.clone()),|topics| topics.retain(|vec_topic| *vec_topic != topic));
+		/// Remove a claim on a byte string topic, only the original issuer can remove a claim
+		/// If the `issuer` has not yet issued a claim of `topic`, this function will return error.
+		#[weight = T::WeightInfo::remove_claim()]
+		pub fn remove_claim_bytes(origin, holder: T::AccountId, topic: AttestationTopic) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
 
-			let remove_issuer = <Topics<T>>::get((holder.clone(), issuer.clone())).len().is_zero();
-			if remove_issuer {
-				<Issuers<T>>::mutate(&holder, |issuers| {
-					issuers.retain(|vec_issuer| *vec_issuer != issuer.clone())
-				});
-			}
+			Self::delete_claim(&holder, &issuer, &topic)?;
 
 			Self::deposit_event(RawEvent::ClaimRemoved(holder, issuer, topic));
+			Ok(())
+		}
+
+		/// Remove a claim made about the caller, the `holder` of the claim
+		/// If the `issuer` has not issued a claim of `topic` about the caller, this function will return error.
+		#[weight = T::WeightInfo::remove_claim()]
+		pub fn holder_remove_claim(origin, issuer: T::AccountId, topic: AttestationTopic) -> DispatchResult {
+			let holder = ensure_signed(origin)?;
 
+			Self::delete_claim(&holder, &issuer, &topic)?;
+
+			Self::deposit_event(RawEvent::ClaimRemovedByHolder(holder, issuer, topic));
 			Ok(())
 		}
+
+		/// On runtime upgrade, convert claims on `U256` topics to byte string topics
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get() == Releases::V0 as u32 {
+				StorageVersion::put(Releases::V1 as u32);
+				migrate_topics_to_bytes::<T>();
+				return T::BlockWeights::get().max_block;
+			}
+			Zero::zero()
+		}
 	}
 }
 
+// A value placed in storage that represents the current version of the storage. This value is used
+// by the `on_runtime_upgrade` logic to determine whether we run storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+enum Releases {
+	/// Storage version with `U256` topics.
+	V0 = 0,
+	/// Storage version with byte string topics.
+	V1 = 1,
+}
+
+/// Re-key every claim from its `U256` topic to the topic's big-endian bytes
+fn migrate_topics_to_bytes<T: Config>() {
+	#[allow(dead_code)]
+	mod old_storage {
+		use super::Config;
+		use sp_core::U256;
+		use sp_std::vec::Vec;
+
+		pub struct Module<T>(sp_std::marker::PhantomData<T>);
+		frame_support::decl_storage! {
+			trait Store for Module<T: Config> as Attestation {
+				pub Topics get(fn topics):
+					map hasher(blake2_128_concat) (T::AccountId, T::AccountId) => Vec<U256>;
+				pub Values get(fn value):
+					map hasher(blake2_128_concat) (T::AccountId, T::AccountId, U256) => U256;
+			}
+		}
+	}
+
+	let all_values = <old_storage::Values<T>>::drain().collect::<Vec<_>>();
+	all_values.into_iter().for_each(|((holder, issuer, topic), value)| {
+		<Values<T>>::insert((holder, issuer, topic_from_u256(topic)), value);
+	});
+
+	<Topics<T>>::translate::<Vec<U256>, _>(|_, topics| Some(topics.into_iter().map(topic_from_u256).collect()));
+}
+
 decl_event!(
 	pub enum Event<T> where <T as frame_system::Config>::AccountId {
 		ClaimCreated(AccountId, AccountId, AttestationTopic, AttestationValue),
 		ClaimRemoved(AccountId, AccountId, AttestationTopic),
 		ClaimUpdated(AccountId, AccountId, AttestationTopic, AttestationValue),
+		/// A claim was removed by its holder (holder, issuer, topic)
+		ClaimRemovedByHolder(AccountId, AccountId, AttestationTopic),
 	}
 );
 
@@ -129,6 +264,8 @@ decl_storage! {
 		/// A map from (holder, issuer, topic) to attested values
 		Values get(fn value):
 			map hasher(blake2_128_concat) (T::AccountId, T::AccountId, AttestationTopic) => AttestationValue;
+		/// Storage version of the module, see `Releases`
+		StorageVersion build(|_: &GenesisConfig| Releases::V1 as u32): u32;
 	}
 }
 
@@ -136,6 +273,14 @@ decl_error! {
 	/// Error for the attestation module.
 	pub enum Error for Module<T: Config> {
 		TopicNotRegistered,
+		/// The topic has more than `MaxTopicLen` bytes
+		TopicTooLong,
+		/// More than `MaxClaimsPerBatch` claims were given
+		BatchTooLarge,
+		/// The holder already has claims from `MaxIssuersPerHolder` issuers
+		TooManyIssuers,
+		/// The issuer already has `MaxTopicsPerIssuerHolder` claims about the holder
+		TooManyTopics,
 	}
 }
 
@@ -155,15 +300,15 @@ impl<T: Config> Module<T> {
 			}
 		});
 
-		let topic_exists: bool = <Topics<T>>::get((holder.clone(), issuer.clone())).contains(&topic);
+		// The value is the source of truth for whether this claim exists, so a set over an existing claim
+		// is always reported as an update
+		let topic_exists = Self::has_claim(&holder, &issuer, &topic);
 
-		<Topics<T>>::mutate((holder.clone(), issuer.clone()), |topics| {
-			if !topic_exists {
-				topics.push(topic)
-			}
-		});
+		if !topic_exists {
+			<Topics<T>>::append((holder.clone(), issuer.clone()), topic.clone());
+		}
 
-		<Values<T>>::insert((holder.clone(), issuer.clone(), topic), value);
+		<Values<T>>::insert((holder.clone(), issuer.clone(), topic.clone()), value);
 
 		if topic_exists {
 			Self::deposit_event(RawEvent::ClaimUpdated(holder, issuer, topic, value));
@@ -171,6 +316,77 @@ impl<T: Config> Module<T> {
 			Self::deposit_event(RawEvent::ClaimCreated(holder, issuer, topic, value));
 		}
 	}
+
+	/// Ensures setting the `issuer`'s claims on `(holder, topic)` stays within `MaxIssuersPerHolder`
+	/// and `MaxTopicsPerIssuerHolder`, counting every earlier claim in `claims` as already set
+	fn ensure_claims_fit(
+		issuer: &T::AccountId,
+		claims: &[(&T::AccountId, &AttestationTopic)],
+	) -> DispatchResult {
+		let mut pending_topics: BTreeMap<&T::AccountId, Vec<AttestationTopic>> = BTreeMap::new();
+		for (holder, topic) in claims {
+			let topics = pending_topics
+				.entry(*holder)
+				.or_insert_with(|| <Topics<T>>::get(((*holder).clone(), issuer.clone())));
+			if topics.contains(topic) {
+				continue;
+			}
+			ensure!(
+				topics.len() < T::MaxTopicsPerIssuerHolder::get() as usize,
+				Error::<T>::TooManyTopics
+			);
+			if topics.is_empty() {
+				let issuers = <Issuers<T>>::get(*holder);
+				ensure!(
+					issuers.contains(issuer) || issuers.len() < T::MaxIssuersPerHolder::get() as usize,
+					Error::<T>::TooManyIssuers
+				);
+			}
+			topics.push((*topic).clone());
+		}
+		Ok(())
+	}
+
+	/// Whether the `issuer` has a claim on `topic` about the `holder`
+	/// Unlike reading the value, this tells a claim with a zero value apart from no claim.
+	pub fn has_claim(holder: &T::AccountId, issuer: &T::AccountId, topic: &AttestationTopic) -> bool {
+		<Values<T>>::contains_key((holder, issuer, topic))
+	}
+
+	/// Gets every claim on `topic` about a `holder`, from any issuer
+	/// Returns the `(issuer, value)` pairs in the order the issuers first made a claim about the `holder`
+	pub fn claims_for(holder: &T::AccountId, topic: AttestationTopic) -> Vec<(T::AccountId, AttestationValue)> {
+		<Issuers<T>>::get(holder)
+			.into_iter()
+			.filter(|issuer| <Topics<T>>::get((holder.clone(), issuer.clone())).contains(&topic))
+			.map(|issuer| {
+				let value = <Values<T>>::get((holder.clone(), issuer.clone(), topic.clone()));
+				(issuer, value)
+			})
+			.collect()
+	}
+
+	/// Removes the claim on `topic` about a `holder` from an `issuer`
+	/// The `issuer` is removed from the `holder`'s issuers when it has no claims left
+	fn delete_claim(holder: &T::AccountId, issuer: &T::AccountId, topic: &AttestationTopic) -> DispatchResult {
+		ensure!(
+			<Topics<T>>::get((holder.clone(), issuer.clone())).contains(topic),
+			Error::<T>::TopicNotRegistered
+		);
+
+		<Values<T>>::remove((holder.clone(), issuer.clone(), topic.clone()));
+
+		<Topics<T>>::mutate((holder.clone(), issuer.clone()),|topics| topics.retain(|vec_topic| vec_topic != topic));
+
+		let remove_issuer = <Topics<T>>::get((holder.clone(), issuer.clone())).len().is_zero();
+		if remove_issuer {
+			<Issuers<T>>::mutate(holder, |issuers| {
+				issuers.retain(|vec_issuer| vec_issuer != issuer)
+			});
+		}
+
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -194,7 +410,7 @@ mod tests {
 	fn adding_claim_to_storage() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		let value = AttestationValue::from(0xb33f);
 		new_test_ext().execute_with(|| {
 			let result = Attestation::set_claim(Origin::signed(issuer), holder, topic, value);
@@ -202,15 +418,15 @@ mod tests {
 			assert_ok!(result);
 
 			assert_eq!(Attestation::issuers(holder), [issuer]);
-			assert_eq!(Attestation::topics((holder, issuer)), [topic]);
-			assert_eq!(Attestation::value((holder, issuer, topic)), value);
+			assert_eq!(Attestation::topics((holder, issuer)), [topic_from_u256(topic)]);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic))), value);
 		})
 	}
 
 	#[test]
 	fn account_can_claim_on_itself() {
 		let holder = 0x1d107;
-		let topic = AttestationTopic::from(0xf001);
+		let topic = U256::from(0xf001);
 		let value = AttestationValue::from(0xb01);
 		new_test_ext().execute_with(|| {
 			let result = Attestation::set_claim(Origin::signed(holder), holder, topic, value);
@@ -218,8 +434,8 @@ mod tests {
 			assert_ok!(result);
 
 			assert_eq!(Attestation::issuers(holder), [holder]);
-			assert_eq!(Attestation::topics((holder, holder)), [topic]);
-			assert_eq!(Attestation::value((holder, holder, topic)), value);
+			assert_eq!(Attestation::topics((holder, holder)), [topic_from_u256(topic)]);
+			assert_eq!(Attestation::value((holder, holder, topic_from_u256(topic))), value);
 		})
 	}
 
@@ -227,19 +443,19 @@ mod tests {
 	fn adding_existing_claim_overwrites_claim() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		let value_old = AttestationValue::from(0xb33f);
 		let value_new = AttestationValue::from(0xcabba93);
 		new_test_ext().execute_with(|| {
 			let result_old = Attestation::set_claim(Origin::signed(issuer), holder, topic, value_old);
 
 			assert_ok!(result_old);
-			assert_eq!(Attestation::value((holder, issuer, topic)), value_old);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic))), value_old);
 
 			let result_new = Attestation::set_claim(Origin::signed(issuer), holder, topic, value_new);
 
 			assert_ok!(result_new);
-			assert_eq!(Attestation::value((holder, issuer, topic)), value_new);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic))), value_new);
 		})
 	}
 
@@ -247,9 +463,9 @@ mod tests {
 	fn adding_multiple_claims_from_same_issuer() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic_food = AttestationTopic::from(0xf00d);
+		let topic_food = U256::from(0xf00d);
 		let value_food = AttestationValue::from(0xb33f);
-		let topic_loot = AttestationTopic::from(0x1007);
+		let topic_loot = U256::from(0x1007);
 		let value_loot = AttestationValue::from(0x901d);
 		new_test_ext().execute_with(|| {
 			let result_food = Attestation::set_claim(Origin::signed(issuer), holder, topic_food, value_food);
@@ -259,9 +475,12 @@ mod tests {
 			assert_ok!(result_loot);
 
 			assert_eq!(Attestation::issuers(holder), [issuer]);
-			assert_eq!(Attestation::topics((holder, issuer)), [topic_food, topic_loot]);
-			assert_eq!(Attestation::value((holder, issuer, topic_food)), value_food);
-			assert_eq!(Attestation::value((holder, issuer, topic_loot)), value_loot);
+			assert_eq!(
+				Attestation::topics((holder, issuer)),
+				[topic_from_u256(topic_food), topic_from_u256(topic_loot)]
+			);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_food))), value_food);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_loot))), value_loot);
 		})
 	}
 
@@ -270,7 +489,7 @@ mod tests {
 		let issuer_foo = 0xf00;
 		let issuer_boa = 0xb0a;
 		let holder = 0xbaa;
-		let topic_food = AttestationTopic::from(0xf00d);
+		let topic_food = U256::from(0xf00d);
 		let value_food_foo = AttestationValue::from(0xb33f);
 		let value_food_boa = AttestationValue::from(0x90a7);
 		new_test_ext().execute_with(|| {
@@ -281,10 +500,10 @@ mod tests {
 			assert_ok!(result_boa);
 
 			assert_eq!(Attestation::issuers(holder), [issuer_foo, issuer_boa]);
-			assert_eq!(Attestation::topics((holder, issuer_foo)), [topic_food]);
-			assert_eq!(Attestation::topics((holder, issuer_boa)), [topic_food]);
-			assert_eq!(Attestation::value((holder, issuer_foo, topic_food)), value_food_foo);
-			assert_eq!(Attestation::value((holder, issuer_boa, topic_food)), value_food_boa);
+			assert_eq!(Attestation::topics((holder, issuer_foo)), [topic_from_u256(topic_food)]);
+			assert_eq!(Attestation::topics((holder, issuer_boa)), [topic_from_u256(topic_food)]);
+			assert_eq!(Attestation::value((holder, issuer_foo, topic_from_u256(topic_food))), value_food_foo);
+			assert_eq!(Attestation::value((holder, issuer_boa, topic_from_u256(topic_food))), value_food_boa);
 		})
 	}
 
@@ -292,7 +511,7 @@ mod tests {
 	fn remove_claim_from_storage() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		let value = AttestationValue::from(0xb33f);
 		let invalid_value = AttestationValue::zero();
 		new_test_ext().execute_with(|| {
@@ -305,7 +524,7 @@ mod tests {
 
 			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
 			assert_eq!(Attestation::topics((holder, issuer)), []);
-			assert_eq!(Attestation::value((holder, issuer, topic)), invalid_value);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic))), invalid_value);
 		})
 	}
 
@@ -314,7 +533,7 @@ mod tests {
 		let issuer_foo = 0xf00;
 		let issuer_boa = 0xb0a;
 		let holder = 0xbaa;
-		let topic_food = AttestationTopic::from(0xf00d);
+		let topic_food = U256::from(0xf00d);
 		let value_food_foo = AttestationValue::from(0xb33f);
 		let value_food_boa = AttestationValue::from(0x90a7);
 		let invalid_value = AttestationValue::zero();
@@ -330,9 +549,9 @@ mod tests {
 
 			assert_eq!(Attestation::issuers(holder), [issuer_boa]);
 			assert_eq!(Attestation::topics((holder, issuer_foo)), []);
-			assert_eq!(Attestation::topics((holder, issuer_boa)), [topic_food]);
-			assert_eq!(Attestation::value((holder, issuer_foo, topic_food)), invalid_value);
-			assert_eq!(Attestation::value((holder, issuer_boa, topic_food)), value_food_boa);
+			assert_eq!(Attestation::topics((holder, issuer_boa)), [topic_from_u256(topic_food)]);
+			assert_eq!(Attestation::value((holder, issuer_foo, topic_from_u256(topic_food))), invalid_value);
+			assert_eq!(Attestation::value((holder, issuer_boa, topic_from_u256(topic_food))), value_food_boa);
 		})
 	}
 
@@ -340,9 +559,9 @@ mod tests {
 	fn remove_claim_from_account_with_multiple_claims_from_same_issuer() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic_food = AttestationTopic::from(0xf00d);
+		let topic_food = U256::from(0xf00d);
 		let value_food = AttestationValue::from(0xb33f);
-		let topic_loot = AttestationTopic::from(0x1007);
+		let topic_loot = U256::from(0x1007);
 		let value_loot = AttestationValue::from(0x901d);
 		let invalid_value = AttestationValue::zero();
 		new_test_ext().execute_with(|| {
@@ -356,9 +575,9 @@ mod tests {
 			assert_ok!(result_remove);
 
 			assert_eq!(Attestation::issuers(holder), [issuer]);
-			assert_eq!(Attestation::topics((holder, issuer)), [topic_loot]);
-			assert_eq!(Attestation::value((holder, issuer, topic_food)), invalid_value);
-			assert_eq!(Attestation::value((holder, issuer, topic_loot)), value_loot);
+			assert_eq!(Attestation::topics((holder, issuer)), [topic_from_u256(topic_loot)]);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_food))), invalid_value);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_loot))), value_loot);
 		})
 	}
 
@@ -366,9 +585,9 @@ mod tests {
 	fn issuer_is_removed_if_there_are_no_claims_left() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic_food = AttestationTopic::from(0xf00d);
+		let topic_food = U256::from(0xf00d);
 		let value_food = AttestationValue::from(0xb33f);
-		let topic_loot = AttestationTopic::from(0x1007);
+		let topic_loot = U256::from(0x1007);
 		let value_loot = AttestationValue::from(0x901d);
 		let invalid_value = AttestationValue::zero();
 		new_test_ext().execute_with(|| {
@@ -385,8 +604,8 @@ mod tests {
 
 			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
 			assert_eq!(Attestation::topics((holder, issuer)), []);
-			assert_eq!(Attestation::value((holder, issuer, topic_food)), invalid_value);
-			assert_eq!(Attestation::value((holder, issuer, topic_loot)), invalid_value);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_food))), invalid_value);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_loot))), invalid_value);
 		})
 	}
 
@@ -394,7 +613,7 @@ mod tests {
 	fn remove_claim_which_doesnt_exist_fails() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		new_test_ext().execute_with(|| {
 			assert_noop!(
 				Attestation::remove_claim(Origin::signed(issuer), holder, topic),
@@ -407,13 +626,14 @@ mod tests {
 	fn created_claim_emits_event() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		let value = AttestationValue::from(0xb33f);
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
 			assert_ok!(Attestation::set_claim(Origin::signed(issuer), holder, topic, value));
 
-			let expected_event = TestEvent::prml_attestation(RawEvent::ClaimCreated(holder, issuer, topic, value));
+			let expected_event =
+				TestEvent::prml_attestation(RawEvent::ClaimCreated(holder, issuer, topic_from_u256(topic), value));
 			// Assert
 			assert!(System::events().iter().any(|record| record.event == expected_event));
 		})
@@ -423,14 +643,15 @@ mod tests {
 	fn removing_claim_emits_event() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		let value = AttestationValue::from(0xb33f);
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
 			assert_ok!(Attestation::set_claim(Origin::signed(issuer), holder, topic, value));
 			assert_ok!(Attestation::remove_claim(Origin::signed(issuer), holder, topic));
 
-			let expected_event = TestEvent::prml_attestation(RawEvent::ClaimRemoved(holder, issuer, topic));
+			let expected_event =
+				TestEvent::prml_attestation(RawEvent::ClaimRemoved(holder, issuer, topic_from_u256(topic)));
 			// Assert
 			assert!(System::events().iter().any(|record| record.event == expected_event));
 		})
@@ -440,7 +661,7 @@ mod tests {
 	fn updating_claim_emits_event() {
 		let issuer = 0xf00;
 		let holder = 0xbaa;
-		let topic = AttestationTopic::from(0xf00d);
+		let topic = U256::from(0xf00d);
 		let value_old = AttestationValue::from(0xb33f);
 		let value_new = AttestationValue::from(0xcabba93);
 		new_test_ext().execute_with(|| {
@@ -448,9 +669,376 @@ mod tests {
 			assert_ok!(Attestation::set_claim(Origin::signed(issuer), holder, topic, value_old));
 			assert_ok!(Attestation::set_claim(Origin::signed(issuer), holder, topic, value_new));
 
-			let expected_event = TestEvent::prml_attestation(RawEvent::ClaimUpdated(holder, issuer, topic, value_new));
+			let expected_event =
+				TestEvent::prml_attestation(RawEvent::ClaimUpdated(holder, issuer, topic_from_u256(topic), value_new));
 			// Assert
 			assert!(System::events().iter().any(|record| record.event == expected_event));
 		})
 	}
+
+	#[test]
+	fn legacy_topic_is_stored_as_big_endian_bytes() {
+		let mut expected = vec![0_u8; 32];
+		expected[30] = 0xf0;
+		expected[31] = 0x0d;
+		assert_eq!(topic_from_u256(U256::from(0xf00d)), expected);
+	}
+
+	#[test]
+	fn adding_claim_with_byte_topic_to_storage() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let topic = b"isOver18".to_vec();
+		let value = AttestationValue::from(1);
+		new_test_ext().execute_with(|| {
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, topic.clone(), value));
+
+			assert_eq!(Attestation::issuers(holder), [issuer]);
+			assert_eq!(Attestation::topics((holder, issuer)), [topic.clone()]);
+			assert_eq!(Attestation::value((holder, issuer, topic.clone())), value);
+
+			assert_ok!(Attestation::remove_claim_bytes(Origin::signed(issuer), holder, topic.clone()));
+
+			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
+			assert_eq!(Attestation::topics((holder, issuer)), <Vec<AttestationTopic>>::new());
+			assert_eq!(Attestation::value((holder, issuer, topic)), AttestationValue::zero());
+		})
+	}
+
+	#[test]
+	fn legacy_and_byte_topics_address_the_same_claim() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let topic = U256::from(0xf00d);
+		let value = AttestationValue::from(0xb33f);
+		new_test_ext().execute_with(|| {
+			assert_ok!(Attestation::set_claim(Origin::signed(issuer), holder, topic, value));
+			assert_ok!(Attestation::remove_claim_bytes(Origin::signed(issuer), holder, topic_from_u256(topic)));
+
+			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
+		})
+	}
+
+	#[test]
+	fn adding_claim_with_too_long_topic_fails() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let max_topic_len = <Test as Config>::MaxTopicLen::get() as usize;
+		let value = AttestationValue::from(1);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Attestation::set_claim_bytes(Origin::signed(issuer), holder, vec![1; max_topic_len + 1], value),
+				Error::<Test>::TopicTooLong
+			);
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, vec![1; max_topic_len], value));
+		})
+	}
+
+	#[test]
+	fn migrate_topics_to_bytes_rekeys_claims() {
+		use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade, Blake2_128Concat, StorageHasher};
+
+		let issuer: AccountId = 0xf00;
+		let holder: AccountId = 0xbaa;
+		let topic_food = U256::from(0xf00d);
+		let topic_loot = U256::from(0x1007);
+		let value_food = AttestationValue::from(0xb33f);
+		let value_loot = AttestationValue::from(0x901d);
+		new_test_ext().execute_with(|| {
+			let storage_key = |item: &[u8], key: Vec<u8>| {
+				let mut storage_key = Vec::new();
+				storage_key.extend_from_slice(&sp_io::hashing::twox_128(b"Attestation"));
+				storage_key.extend_from_slice(&sp_io::hashing::twox_128(item));
+				storage_key.extend_from_slice(&Blake2_128Concat::hash(&key));
+				storage_key
+			};
+			// Claims as stored with `U256` topics
+			<Issuers<Test>>::insert(holder, vec![issuer]);
+			unhashed::put(
+				&storage_key(b"Topics", (holder, issuer).encode()),
+				&vec![topic_food, topic_loot],
+			);
+			unhashed::put(&storage_key(b"Values", (holder, issuer, topic_food).encode()), &value_food);
+			unhashed::put(&storage_key(b"Values", (holder, issuer, topic_loot).encode()), &value_loot);
+
+			let _ = Attestation::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get(), Releases::V1 as u32);
+			assert_eq!(
+				Attestation::topics((holder, issuer)),
+				[topic_from_u256(topic_food), topic_from_u256(topic_loot)]
+			);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_food))), value_food);
+			assert_eq!(Attestation::value((holder, issuer, topic_from_u256(topic_loot))), value_loot);
+			assert!(unhashed::get_raw(&storage_key(b"Values", (holder, issuer, topic_food).encode())).is_none());
+
+			// Claims on the migrated topics can be removed with the legacy extrinsic
+			assert_ok!(Attestation::remove_claim(Origin::signed(issuer), holder, topic_food));
+			assert_ok!(Attestation::remove_claim(Origin::signed(issuer), holder, topic_loot));
+			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
+		})
+	}
+
+	#[test]
+	fn claim_maps_are_iterable() {
+		let issuer = 0xf00;
+		let holder_baa = 0xbaa;
+		let holder_bee = 0xbee;
+		let topic = b"food".to_vec();
+		let value = AttestationValue::from(0xb33f);
+		new_test_ext().execute_with(|| {
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder_baa, topic.clone(), value));
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder_bee, topic.clone(), value));
+
+			let mut holders: Vec<AccountId> = <Issuers<Test>>::iter().map(|(holder, _)| holder).collect();
+			holders.sort();
+			assert_eq!(holders, vec![holder_baa, holder_bee]);
+
+			let mut values: Vec<_> = <Values<Test>>::iter().collect();
+			values.sort();
+			assert_eq!(
+				values,
+				vec![((holder_baa, issuer, topic.clone()), value), ((holder_bee, issuer, topic), value)]
+			);
+		})
+	}
+
+	#[test]
+	fn claims_for_collects_claims_from_every_issuer() {
+		let issuer_foo = 0xf00;
+		let issuer_boa = 0xb0a;
+		let issuer_baz = 0xba2;
+		let holder = 0xbaa;
+		let topic_food = b"food".to_vec();
+		let topic_loot = b"loot".to_vec();
+		let food_foo = AttestationValue::from(0xb33f);
+		let food_boa = AttestationValue::from(0x90a7);
+		let loot_baz = AttestationValue::from(0x901d);
+		new_test_ext().execute_with(|| {
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer_foo), holder, topic_food.clone(), food_foo));
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer_baz), holder, topic_loot.clone(), loot_baz));
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer_boa), holder, topic_food.clone(), food_boa));
+
+			assert_eq!(
+				Attestation::claims_for(&holder, topic_food),
+				vec![(issuer_foo, food_foo), (issuer_boa, food_boa)]
+			);
+			assert_eq!(Attestation::claims_for(&holder, topic_loot), vec![(issuer_baz, loot_baz)]);
+			assert_eq!(Attestation::claims_for(&holder, b"none".to_vec()), vec![]);
+		})
+	}
+
+	#[test]
+	fn set_claims_keeps_storage_consistent() {
+		let issuer = 0xf00;
+		let holder_baa = 0xbaa;
+		let holder_bee = 0xbee;
+		let topic_food = b"food".to_vec();
+		let topic_loot = b"loot".to_vec();
+		let value_food = AttestationValue::from(0xb33f);
+		let value_loot = AttestationValue::from(0x901d);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Attestation::set_claims(
+				Origin::signed(issuer),
+				vec![
+					(holder_baa, topic_food.clone(), value_food),
+					(holder_baa, topic_loot.clone(), value_loot),
+					(holder_bee, topic_food.clone(), value_food),
+				]
+			));
+
+			assert_eq!(Attestation::issuers(holder_baa), [issuer]);
+			assert_eq!(Attestation::issuers(holder_bee), [issuer]);
+			assert_eq!(Attestation::topics((holder_baa, issuer)), [topic_food.clone(), topic_loot.clone()]);
+			assert_eq!(Attestation::topics((holder_bee, issuer)), [topic_food.clone()]);
+			assert_eq!(Attestation::value((holder_baa, issuer, topic_food.clone())), value_food);
+			assert_eq!(Attestation::value((holder_baa, issuer, topic_loot.clone())), value_loot);
+			assert_eq!(Attestation::value((holder_bee, issuer, topic_food.clone())), value_food);
+
+			let expected_event =
+				TestEvent::prml_attestation(RawEvent::ClaimCreated(holder_bee, issuer, topic_food, value_food));
+			assert!(System::events().iter().any(|record| record.event == expected_event));
+			assert_eq!(System::events().len(), 3);
+		})
+	}
+
+	#[test]
+	fn set_claims_sets_nothing_if_a_topic_is_too_long() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let max_topic_len = <Test as Config>::MaxTopicLen::get() as usize;
+		let value = AttestationValue::from(1);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Attestation::set_claims(
+					Origin::signed(issuer),
+					vec![(holder, b"food".to_vec(), value), (holder, vec![1; max_topic_len + 1], value)]
+				),
+				Error::<Test>::TopicTooLong
+			);
+			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
+		})
+	}
+
+	#[test]
+	fn set_claims_rejects_too_many_claims() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let max_claims = <Test as Config>::MaxClaimsPerBatch::get() as usize;
+		let value = AttestationValue::from(1);
+		new_test_ext().execute_with(|| {
+			let claims = vec![(holder, b"food".to_vec(), value); max_claims + 1];
+			assert_noop!(
+				Attestation::set_claims(Origin::signed(issuer), claims),
+				Error::<Test>::BatchTooLarge
+			);
+		})
+	}
+
+	#[test]
+	fn has_claim_distinguishes_zero_value_from_no_claim() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let topic = b"food".to_vec();
+		let zero = AttestationValue::zero();
+		new_test_ext().execute_with(|| {
+			assert!(!Attestation::has_claim(&holder, &issuer, &topic));
+
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, topic.clone(), zero));
+			assert!(Attestation::has_claim(&holder, &issuer, &topic));
+			assert_eq!(Attestation::value((holder, issuer, topic.clone())), zero);
+			assert!(!Attestation::has_claim(&issuer, &holder, &topic));
+
+			assert_ok!(Attestation::remove_claim_bytes(Origin::signed(issuer), holder, topic.clone()));
+			assert!(!Attestation::has_claim(&holder, &issuer, &topic));
+		})
+	}
+
+	#[test]
+	fn first_set_creates_and_second_set_updates() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let topic = b"food".to_vec();
+		let value_old = AttestationValue::from(0xb33f);
+		let value_new = AttestationValue::from(0xcabba93);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let created = TestEvent::prml_attestation(RawEvent::ClaimCreated(holder, issuer, topic.clone(), value_old));
+			let updated = TestEvent::prml_attestation(RawEvent::ClaimUpdated(holder, issuer, topic.clone(), value_new));
+
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, topic.clone(), value_old));
+			let events: Vec<_> = System::events().into_iter().map(|record| record.event).collect();
+			assert_eq!(events, vec![created.clone()]);
+
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, topic.clone(), value_new));
+			let events: Vec<_> = System::events().into_iter().map(|record| record.event).collect();
+			assert_eq!(events, vec![created, updated]);
+			assert_eq!(Attestation::topics((holder, issuer)), [topic]);
+		})
+	}
+
+	#[test]
+	fn claims_from_too_many_issuers_are_rejected() {
+		let holder = 0xbaa;
+		let max_issuers = <Test as Config>::MaxIssuersPerHolder::get() as u64;
+		let topic = b"food".to_vec();
+		let value = AttestationValue::from(1);
+		new_test_ext().execute_with(|| {
+			for issuer in 1..=max_issuers {
+				assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, topic.clone(), value));
+			}
+			assert_noop!(
+				Attestation::set_claim_bytes(Origin::signed(max_issuers + 1), holder, topic.clone(), value),
+				Error::<Test>::TooManyIssuers
+			);
+			// Existing issuers can still add topics
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(1), holder, b"loot".to_vec(), value));
+
+			// Room is made once an issuer has no claims left
+			assert_ok!(Attestation::remove_claim_bytes(Origin::signed(2), holder, topic.clone()));
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(max_issuers + 1), holder, topic, value));
+		})
+	}
+
+	#[test]
+	fn too_many_topics_from_one_issuer_are_rejected() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let max_topics = <Test as Config>::MaxTopicsPerIssuerHolder::get() as u8;
+		let value = AttestationValue::from(1);
+		new_test_ext().execute_with(|| {
+			for topic in 0..max_topics {
+				assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, vec![topic], value));
+			}
+			assert_noop!(
+				Attestation::set_claim_bytes(Origin::signed(issuer), holder, vec![max_topics], value),
+				Error::<Test>::TooManyTopics
+			);
+			// Existing claims can still be updated
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, vec![0], value + 1));
+			// The bound is per holder
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), 0xbee, vec![max_topics], value));
+		})
+	}
+
+	#[test]
+	fn set_claims_sets_nothing_if_the_batch_exceeds_the_topic_bound() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let max_topics = <Test as Config>::MaxTopicsPerIssuerHolder::get() as u8;
+		let value = AttestationValue::from(1);
+		new_test_ext().execute_with(|| {
+			let claims = (0..=max_topics).map(|topic| (holder, vec![topic], value)).collect();
+			assert_noop!(Attestation::set_claims(Origin::signed(issuer), claims), Error::<Test>::TooManyTopics);
+			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
+
+			// Repeated topics in a batch are only counted once
+			let claims = vec![(holder, vec![0], value); max_topics as usize + 1];
+			assert_ok!(Attestation::set_claims(Origin::signed(issuer), claims));
+		})
+	}
+
+	#[test]
+	fn holder_can_remove_claim_about_themselves() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let topic = b"food".to_vec();
+		let value = AttestationValue::from(0xb33f);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, topic.clone(), value));
+
+			assert_ok!(Attestation::holder_remove_claim(Origin::signed(holder), issuer, topic.clone()));
+
+			assert_eq!(Attestation::issuers(holder), <Vec<AccountId>>::new());
+			assert_eq!(Attestation::topics((holder, issuer)), <Vec<AttestationTopic>>::new());
+			assert!(!Attestation::has_claim(&holder, &issuer, &topic));
+			let expected_event = TestEvent::prml_attestation(RawEvent::ClaimRemovedByHolder(holder, issuer, topic));
+			assert!(System::events().iter().any(|record| record.event == expected_event));
+		})
+	}
+
+	#[test]
+	fn third_party_cannot_remove_claim_as_holder() {
+		let issuer = 0xf00;
+		let holder = 0xbaa;
+		let third_party = 0x3;
+		let topic = b"food".to_vec();
+		let value = AttestationValue::from(0xb33f);
+		new_test_ext().execute_with(|| {
+			assert_ok!(Attestation::set_claim_bytes(Origin::signed(issuer), holder, topic.clone(), value));
+
+			assert_noop!(
+				Attestation::holder_remove_claim(Origin::signed(third_party), issuer, topic.clone()),
+				Error::<Test>::TopicNotRegistered
+			);
+			// The issuer cannot use the holder path either
+			assert_noop!(
+				Attestation::holder_remove_claim(Origin::signed(issuer), issuer, topic.clone()),
+				Error::<Test>::TopicNotRegistered
+			);
+			assert!(Attestation::has_claim(&holder, &issuer, &topic));
+		})
+	}
 }
@@ -40,12 +40,16 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Module, Call, Config, Storage, Event<T>},
-		Attestation: prml_attestation::{Module, Call, Storage, Event<T>},
+		Attestation: prml_attestation::{Module, Call, Storage, Event<T>, Config},
 	}
 );
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
+	pub const MaxTopicLen: u32 = 32;
+	pub const MaxClaimsPerBatch: u32 = 3;
+	pub const MaxIssuersPerHolder: u32 = 3;
+	pub const MaxTopicsPerIssuerHolder: u32 = 2;
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = ();
@@ -74,6 +78,10 @@ impl frame_system::Config for Test {
 
 impl Config for Test {
 	type Event = Event;
+	type MaxTopicLen = MaxTopicLen;
+	type MaxClaimsPerBatch = MaxClaimsPerBatch;
+	type MaxIssuersPerHolder = MaxIssuersPerHolder;
+	type MaxTopicsPerIssuerHolder = MaxTopicsPerIssuerHolder;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,47 @@
+// Copyright 2020 Plug New Zealand Limited
+// This file is part of Plug.
+
+// Plug is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Plug is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Plug. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition required by Consortium Permission RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding permission claim lookups.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use sp_std::vec::Vec;
+
+/// Type used for topic names, as in the consortium permission module.
+pub type Topic = Vec<u8>;
+/// Type used for claim values, as in the consortium permission module.
+pub type Value = Vec<u8>;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query permission claims.
+	pub trait PermissionClaimApi<AccountId: Decode + Encode> {
+		/// Get the issuer and value of the claim on a topic about a holder, if any.
+		fn permission_claim(holder: AccountId, topic: Topic) -> Option<(AccountId, Value)>;
+		/// Whether the holder has a claim with the given value on an enabled topic.
+		fn has_permission(holder: AccountId, topic: Topic, value: Value) -> bool;
+		/// Get up to `limit` claims as `((holder, topic), (issuer, value))`, starting with the claim keyed `start`,
+		/// and the key to start the next page from if there are more claims.
+		fn claims_page(
+			start: Option<(AccountId, Topic)>,
+			limit: u32,
+		) -> (Vec<((AccountId, Topic), (AccountId, Value))>, Option<(AccountId, Topic)>);
+	}
+}
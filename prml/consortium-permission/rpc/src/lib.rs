@@ -0,0 +1,178 @@
+// Copyright 2020 Plug New Zealand Limited
+// This file is part of Plug.
+
+// Plug is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Plug is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Plug. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the consortium permission module.
+
+pub use self::gen_client::Client as ConsortiumPermissionClient;
+use codec::{Decode, Encode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+pub use prml_consortium_permission_rpc_runtime_api::{PermissionClaimApi, Topic, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+#[rpc]
+pub trait ConsortiumPermissionApi<BlockHash, AccountId> {
+	/// Get the issuer and value of the claim on a topic about a holder, if any.
+	#[rpc(name = "consortiumPermission_claim")]
+	fn permission_claim(
+		&self,
+		holder: AccountId,
+		topic: Topic,
+		at: Option<BlockHash>,
+	) -> Result<Option<(AccountId, Value)>>;
+
+	/// Whether the holder has a claim with the given value on an enabled topic.
+	#[rpc(name = "consortiumPermission_hasPermission")]
+	fn has_permission(&self, holder: AccountId, topic: Topic, value: Value, at: Option<BlockHash>) -> Result<bool>;
+}
+
+/// A struct that implements the [`ConsortiumPermissionApi`].
+pub struct ConsortiumPermission<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> ConsortiumPermission<C, P> {
+	/// Create new `ConsortiumPermission` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		ConsortiumPermission {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl<C, Block, AccountId> ConsortiumPermissionApi<<Block as BlockT>::Hash, AccountId>
+	for ConsortiumPermission<C, (Block, AccountId)>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: PermissionClaimApi<Block, AccountId>,
+	AccountId: Decode + Encode + Send + Sync + 'static,
+{
+	fn permission_claim(
+		&self,
+		holder: AccountId,
+		topic: Topic,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<(AccountId, Value)>> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.client.runtime_api().permission_claim(&at, holder, topic).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError as i64),
+			message: "Unable to query permission claim.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn has_permission(
+		&self,
+		holder: AccountId,
+		topic: Topic,
+		value: Value,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<bool> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.client.runtime_api().has_permission(&at, holder, topic, value).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError as i64),
+			message: "Unable to query permission.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{ConsortiumPermission, ConsortiumPermissionApi};
+	use jsonrpc_core::IoHandler;
+	use std::sync::Arc;
+	use substrate_test_runtime_client::{
+		DefaultTestClientBuilderExt,
+		TestClient,
+		TestClientBuilderExt,
+		TestClientBuilder,
+	};
+
+	fn test_cp_rpc_handler<P>() -> ConsortiumPermission<TestClient, P> {
+		let builder = TestClientBuilder::new();
+		let (client, _) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+
+		ConsortiumPermission::new(client)
+	}
+
+	#[test]
+	fn working_claim_rpc() {
+		let handler = test_cp_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(ConsortiumPermissionApi::to_delegate(handler));
+
+		// topic "access"
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "consortiumPermission_claim",
+			"params":["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", [97, 99, 99, 101, 115, 115]]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\
+			\"result\":[\"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY\",[1]],\
+			\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_claim_rpc_without_claim() {
+		let handler = test_cp_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(ConsortiumPermissionApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "consortiumPermission_claim",
+			"params":["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", [1, 2, 3]]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\"result\":null,\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_has_permission_rpc() {
+		let handler = test_cp_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(ConsortiumPermissionApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "consortiumPermission_hasPermission",
+			"params":["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", [97, 99, 99, 101, 115, 115], [1]]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\"result\":true,\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+}
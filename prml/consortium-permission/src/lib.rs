@@ -22,7 +22,8 @@
 //! Once a topic is authorized for an "issuers", they can grant and revoke permissions for other
 //! chain users on this topic.
 //!
-//! Runtime modules can use this module to look up granted permissions when needed.
+//! Runtime modules can use this module to look up granted permissions when needed, e.g. with
+//! `has_claim_value`.
 //!
 //! ## Dispatchable methods
 //!
@@ -38,13 +39,17 @@
 //! pub fn add_topic(origin, topic: Topic) { ... }
 //! pub fn enable_topic(origin, topic: Topic) { ... }
 //! pub fn disable_topic(origin, topic: Topic) { ... }
+//! pub fn remove_topic(origin, topic: Topic) { ... }
 //!
 //! /// Manage permission Claims. Requires caller to be an "issuer".
 //! pub fn make_claim(origin, holder: T::AccountId, topic: Topic, value: Value) { ... }
+//! pub fn make_claims(origin, claims: Vec<(T::AccountId, Topic, Value)>) { ... }
+//! pub fn update_claim_value(origin, holder: T::AccountId, topic: Topic, value: Value) { ... }
 //! pub fn revoke_claim(origin, holder: T::AccountId, topic: Topic) { ... }
 //!
 //! /// Revokes a preexisting claim about a holder. Requires Root.
 //! pub fn sudo_revoke_claim(origin, holder: T::AccountId, topic: Topic) { ... }
+//! pub fn sudo_revoke_all_claims(origin, holder: T::AccountId, claim_count: u32) { ... }
 //! ```
 //!
 //! ## Interfacing with other modules
@@ -62,13 +67,15 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+use codec::{Decode, Encode};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, traits::Get,
-    storage::{StorageMap, IterableStorageMap}
+    storage::{unhashed, StorageMap, StorageValue, IterableStorageMap, StoragePrefixedMap},
+    weights::Weight, ReversibleStorageHasher, Twox64Concat,
 };
 use frame_system::{ensure_root, ensure_signed};
-use sp_runtime::DispatchResult;
-use sp_std::prelude::*;
+use sp_runtime::{traits::Zero, DispatchResult, RuntimeDebug};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 
 /// Type used for topic names.
 pub type Topic = Vec<u8>;
@@ -95,6 +102,10 @@ pub trait Trait: frame_system::Config {
     type MaximumTopicSize: Get<usize>;
     /// The maximum number of bytes allowed for a value.
     type MaximumValueSize: Get<usize>;
+    /// The maximum number of claims allowed in a single `make_claims` batch.
+    type MaximumBatchSize: Get<usize>;
+    /// The maximum number of topics an issuer can be authorized on.
+    type MaxTopicsPerIssuer: Get<u32>;
     /// Provides an interface for setting issuer permissions
     type IssuerPermissions: IssuerPermissions<AccountId = <Self as frame_system::Config>::AccountId, Topic = Topic>;
 }
@@ -113,6 +124,12 @@ decl_storage! {
         IssuerClaims get(fn issuer_claims): map hasher(twox_64_concat) T::AccountId => Vec<(T::AccountId, Topic)>;
         /// Map of holder to all topics that have been claimed about them.
         HolderClaims get(fn holder_claims): map hasher(twox_64_concat) T::AccountId => Vec<Topic>;
+        /// Map of topic to the number of issuers authorized on it.
+        TopicIssuerCount get(fn topic_issuer_count): map hasher(twox_64_concat) Topic => u32;
+        /// Map of topic to the number of claims made on it.
+        TopicClaimCount get(fn topic_claim_count): map hasher(twox_64_concat) Topic => u32;
+        /// Storage version of the module, see `Releases`
+        StorageVersion build(|_: &GenesisConfig<T>| Releases::V1 as u32): u32;
     }
     add_extra_genesis {
         config(issuers): Vec<(T::AccountId, Vec<Topic>)>;
@@ -132,18 +149,28 @@ decl_event! {
         IssuerWithTopicRemoved(AccountId, Topic),
         /// An issuer is force-removed with all of its topics.
         IssuerForceRemoved(AccountId),
+        /// A topic of a force-removed issuer has been revoked.
+        IssuerTopicAutoRevoked(AccountId, Topic),
         /// A claim has been made.
         ClaimMade(AccountId, AccountId, Topic, Value),
+        /// A batch of claims has been made by an issuer (issuer, number of claims).
+        ClaimsBatchMade(AccountId, u32),
+        /// The value of an existing claim has been updated by its issuer.
+        ClaimValueUpdated(AccountId, AccountId, Topic, Value),
         /// A claim has been revoked.
         ClaimRevoked(AccountId, AccountId, Topic),
         /// A claim has been revoked by sudo.
         ClaimRevokedBySudo(AccountId, Topic),
+        /// All claims about a holder have been revoked by sudo (holder, number of claims).
+        AllClaimsRevoked(AccountId, u32),
         /// A new topic is added.
         TopicAdded(Topic),
         /// An existing topic is enabled.
         TopicEnabled(Topic),
         /// An existing topic is disabled.
         TopicDisabled(Topic),
+        /// An existing topic is removed.
+        TopicRemoved(Topic),
     }
 }
 
@@ -165,6 +192,16 @@ decl_error! {
         ValueExceedsAllowableSize,
         /// Attempt to remove claim that doesn't exist.
         CannotRemoveNonExistentClaim,
+        /// Too many claims in a single batch.
+        BatchTooLarge,
+        /// The caller is not the issuer of this claim.
+        NotClaimIssuer,
+        /// The holder has more claims than the given witness count.
+        TooManyClaims,
+        /// Topic is still authorized for an issuer or referenced by a claim.
+        TopicInUse,
+        /// The issuer is already authorized on the maximum number of topics.
+        TooManyTopics,
     }
 }
 
@@ -183,10 +220,15 @@ decl_module! {
             let mut current_topics = Self::issuers(&who);
             ensure!(!current_topics.contains(&topic), Error::<T>::IssuerWithTopicAlreadyExists );
             ensure!(Self::topics().contains(&topic), Error::<T>::InvalidTopic);
+            ensure!(
+                current_topics.len() < T::MaxTopicsPerIssuer::get() as usize,
+                Error::<T>::TooManyTopics
+            );
 
             // Add to the topic from the list of topics "who" is authorized to make.
             current_topics.push(topic.clone());
             Issuers::<T>::insert(who.clone(), current_topics);
+            TopicIssuerCount::mutate(&topic, |count| *count = count.saturating_add(1));
 
             T::IssuerPermissions::grant_issuer_permissions(&who, &topic);
 
@@ -213,6 +255,7 @@ decl_module! {
             else {
                 Issuers::<T>::insert(who.clone(), current_topics);
             }
+            TopicIssuerCount::mutate(&topic, |count| *count = count.saturating_sub(1));
 
             T::IssuerPermissions::revoke_issuer_permissions(&who, &topic);
 
@@ -228,7 +271,9 @@ decl_module! {
             // Notify the revocation of all current permissions.
             let current_topics = Self::issuers(&who);
             for topic in current_topics {
+                TopicIssuerCount::mutate(&topic, |count| *count = count.saturating_sub(1));
                 T::IssuerPermissions::revoke_issuer_permissions(&who, &topic);
+                Self::deposit_event(RawEvent::IssuerTopicAutoRevoked(who.clone(), topic));
             }
 
             // Remove topics for this issuer.
@@ -258,6 +303,14 @@ decl_module! {
             Self::deposit_event(RawEvent::TopicDisabled(topic));
         }
 
+        /// Remove an existing topic as root.
+        /// The topic must no longer be authorized for any issuer nor referenced by any claim.
+        pub fn remove_topic(origin, topic: Topic) {
+            ensure_root(origin)?;
+            Self::delete_topic(&topic)?;
+            Self::deposit_event(RawEvent::TopicRemoved(topic));
+        }
+
         /// Makes a claim on a topic about a holder.
         pub fn make_claim(origin, holder: T::AccountId, topic: Topic, value: Value) {
             let issuer = ensure_signed(origin)?;
@@ -271,6 +324,49 @@ decl_module! {
             Self::deposit_event(RawEvent::ClaimMade(issuer, holder, topic, value));
         }
 
+        /// Makes a batch of claims on topics about holders.
+        /// Every claim is validated before any is made, so either all claims are made or none.
+        pub fn make_claims(origin, claims: Vec<(T::AccountId, Topic, Value)>) {
+            let issuer = ensure_signed(origin)?;
+            ensure!(claims.len() <= T::MaximumBatchSize::get(), Error::<T>::BatchTooLarge);
+
+            let issuer_topics = Self::issuers(&issuer);
+            let topics = Self::topics();
+            let distinct_topics: BTreeSet<&Topic> = claims.iter().map(|(_, topic, _)| topic).collect();
+            for topic in distinct_topics {
+                ensure!(issuer_topics.contains(topic), Error::<T>::IssuerNotAuthorizedOnTopic);
+                ensure!(topics.contains(topic), Error::<T>::InvalidTopic);
+                ensure!(Self::topic_enabled(topic), Error::<T>::DisabledTopic);
+            }
+            ensure!(
+                claims.iter().all(|(_, _, value)| value.len() <= T::MaximumValueSize::get()),
+                Error::<T>::ValueExceedsAllowableSize
+            );
+
+            for (holder, topic, value) in claims.iter() {
+                Self::do_make_claim(&issuer, holder, topic, value);
+            }
+
+            Self::deposit_event(RawEvent::ClaimsBatchMade(issuer, claims.len() as u32));
+        }
+
+        /// Updates the value of a preexisting claim about a holder.
+        /// Only the issuer of the claim can update it, and only while it could make the claim anew.
+        pub fn update_claim_value(origin, holder: T::AccountId, topic: Topic, value: Value) {
+            let issuer = ensure_signed(origin)?;
+            ensure!(Claim::<T>::contains_key((&holder, &topic)), Error::<T>::CannotRemoveNonExistentClaim);
+            let (claim_issuer, _) = Self::claim((&holder, &topic));
+            ensure!(claim_issuer == issuer, Error::<T>::NotClaimIssuer);
+            ensure!(Self::issuers(&issuer).contains(&topic), Error::<T>::IssuerNotAuthorizedOnTopic);
+            ensure!(Self::topics().contains(&topic), Error::<T>::InvalidTopic);
+            ensure!(Self::topic_enabled(&topic), Error::<T>::DisabledTopic);
+            ensure!(value.len() <= T::MaximumValueSize::get(), Error::<T>::ValueExceedsAllowableSize);
+
+            Claim::<T>::insert((&holder, &topic), (&issuer, &value));
+
+            Self::deposit_event(RawEvent::ClaimValueUpdated(issuer, holder, topic, value));
+        }
+
         /// Revokes a preexisting claim about a holder.
         pub fn revoke_claim(origin, holder: T::AccountId, topic: Topic) {
             let issuer = ensure_signed(origin)?;
@@ -291,6 +387,53 @@ decl_module! {
 
             Self::deposit_event(RawEvent::ClaimRevokedBySudo(holder, topic));
         }
+
+        /// Revokes every claim about a holder - root only.
+        /// `claim_count` must be at least the number of claims held, to keep the weight bounded.
+        pub fn sudo_revoke_all_claims(origin, holder: T::AccountId, claim_count: u32) {
+            ensure_root(origin)?;
+            let topics = Self::holder_claims(&holder);
+            ensure!(topics.len() <= claim_count as usize, Error::<T>::TooManyClaims);
+
+            for topic in topics.iter() {
+                Self::do_revoke_claim(holder.clone(), topic.clone());
+            }
+            HolderClaims::<T>::remove(&holder);
+
+            Self::deposit_event(RawEvent::AllClaimsRevoked(holder, topics.len() as u32));
+        }
+
+        /// On runtime upgrade, count the issuers and claims using each topic
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get() == Releases::V0 as u32 {
+                StorageVersion::put(Releases::V1 as u32);
+                migrate_topic_counts::<T>();
+                return T::BlockWeights::get().max_block;
+            }
+            Zero::zero()
+        }
+    }
+}
+
+// A value placed in storage that represents the current version of the storage. This value is used
+// by the `on_runtime_upgrade` logic to determine whether we run storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+enum Releases {
+    /// Storage version without per topic counts.
+    V0 = 0,
+    /// Storage version counting the issuers and claims using each topic.
+    V1 = 1,
+}
+
+/// Count the issuers authorized on, and the claims made on, each topic
+fn migrate_topic_counts<T: Config>() {
+    for (_issuer, topics) in Issuers::<T>::iter() {
+        for topic in topics {
+            TopicIssuerCount::mutate(&topic, |count| *count = count.saturating_add(1));
+        }
+    }
+    for ((_holder, topic), _claim) in Claim::<T>::iter() {
+        TopicClaimCount::mutate(&topic, |count| *count = count.saturating_add(1));
     }
 }
 
@@ -298,8 +441,10 @@ impl<T: Config> Module<T> {
     /// Initialises whitelisted issuers configured in genesis.
     fn initialise_issuers(issuers: &Vec<(T::AccountId, Vec<Topic>)>) {
         for (issuer, topics) in issuers {
+            topics.iter().for_each(Self::ensure_genesis_topic_size);
             Issuers::<T>::insert(issuer, topics);
             for topic in topics {
+                TopicIssuerCount::mutate(topic, |count| *count = count.saturating_add(1));
                 T::IssuerPermissions::grant_issuer_permissions(&issuer, &topic);
             }
         }
@@ -314,6 +459,66 @@ impl<T: Config> Module<T> {
         ).count() as u32
     }
 
+    /// Lists the accounts that have been granted a specific permission.
+    /// Takes a topic and value, iterate through all existing claims, and collect the
+    /// holders of claims with matching topic and value.
+    pub fn holders_with_permission(topic: &Topic, value: &Value) -> Vec<T::AccountId> {
+        Claim::<T>::iter().filter_map(
+            |((holder, t), (_issuer, v))| if t == *topic && v == *value { Some(holder) } else { None }
+        ).collect()
+    }
+
+    /// Pages through all claims in storage order, without loading them all at once.
+    /// Returns up to `limit` claims, starting with the claim keyed `start` or the first claim if `None`,
+    /// and the key to start the next page from if there are more claims.
+    pub fn claims_page(
+        start: Option<(T::AccountId, Topic)>,
+        limit: u32,
+    ) -> (Vec<((T::AccountId, Topic), (T::AccountId, Value))>, Option<(T::AccountId, Topic)>) {
+        let prefix = Claim::<T>::final_prefix();
+        let next_key = |key: &[u8]| sp_io::storage::next_key(key).filter(|next| next.starts_with(&prefix));
+        let decode_key = |key: &[u8]| {
+            <(T::AccountId, Topic)>::decode(&mut Twox64Concat::reverse(&key[prefix.len()..])).ok()
+        };
+
+        let mut current_key = match start {
+            Some(start) => {
+                let key = Claim::<T>::hashed_key_for(start);
+                if unhashed::exists(&key) { Some(key) } else { next_key(&key) }
+            }
+            None => next_key(&prefix),
+        };
+        let mut claims = Vec::new();
+        while let Some(key) = current_key {
+            if claims.len() >= limit as usize {
+                return (claims, decode_key(&key));
+            }
+            if let (Some(holder_topic), Some(claim)) = (decode_key(&key), unhashed::get(&key)) {
+                claims.push((holder_topic, claim));
+            }
+            current_key = next_key(&key);
+        }
+
+        (claims, None)
+    }
+
+    /// The issuer and value of the claim on a topic about a holder, if any.
+    pub fn permission_claim(holder: &T::AccountId, topic: &Topic) -> Option<(T::AccountId, Value)> {
+        if Claim::<T>::contains_key((holder, topic)) {
+            Some(Self::claim((holder, topic)))
+        } else {
+            None
+        }
+    }
+
+    /// Whether a claim with the given value exists about a holder on a topic, and the topic is
+    /// enabled. Claims on disabled topics do not grant anything.
+    pub fn has_claim_value(holder: &T::AccountId, topic: &Topic, value: &Value) -> bool {
+        Self::topic_enabled(topic)
+            && Claim::<T>::contains_key((holder, topic))
+            && Self::claim((holder, topic)).1 == *value
+    }
+
     /// Performs all storage changes to make a claim by an issuer on a topic about a holder.
     pub fn do_make_claim(
         issuer: &T::AccountId,
@@ -325,6 +530,7 @@ impl<T: Config> Module<T> {
         if !holder_claims.contains(&topic) {
             holder_claims.push(topic.clone());
             HolderClaims::<T>::insert(&holder, holder_claims);
+            TopicClaimCount::mutate(topic, |count| *count = count.saturating_add(1));
         } else {
             // Remove from previous issuer's claim list
             let (old_issuer, _) = Self::claim((&holder, &topic));
@@ -351,6 +557,9 @@ impl<T: Config> Module<T> {
         holder_claims.retain(|x| *x != topic.clone());
         HolderClaims::<T>::insert(&holder, holder_claims);
 
+        if Claim::<T>::contains_key((&holder, &topic)) {
+            TopicClaimCount::mutate(&topic, |count| *count = count.saturating_sub(1));
+        }
         Claim::<T>::remove((holder, topic));
     }
 
@@ -363,12 +572,24 @@ impl<T: Config> Module<T> {
 
     /// Initialises reserved topics at genesis.
     fn initialise_topics(topics: &Vec<Topic>) {
+        topics.iter().for_each(Self::ensure_genesis_topic_size);
         Topics::put(topics.clone());
         for topic in topics {
             TopicEnabled::insert(topic, true);
         }
     }
 
+    /// Panics if a topic configured at genesis exceeds `MaximumTopicSize`, so that a misconfigured
+    /// chain spec is caught when the chain is built.
+    fn ensure_genesis_topic_size(topic: &Topic) {
+        assert!(
+            topic.len() <= T::MaximumTopicSize::get(),
+            "Genesis topic {:?} exceeds the maximum topic size of {} bytes",
+            topic,
+            T::MaximumTopicSize::get(),
+        );
+    }
+
     /// Performs all storage changes to add a topic.
     fn insert_topic(topic: &[u8]) -> DispatchResult {
         ensure!(
@@ -383,6 +604,23 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Performs all storage changes to remove a topic that is no longer in use.
+    /// Whether it is in use is read from the topic's issuer and claim counts, rather than by scanning.
+    fn delete_topic(topic: &Topic) -> DispatchResult {
+        let mut topics = Self::topics();
+        ensure!(topics.contains(topic), Error::<T>::InvalidTopic);
+        ensure!(
+            Self::topic_issuer_count(topic).is_zero() && Self::topic_claim_count(topic).is_zero(),
+            Error::<T>::TopicInUse
+        );
+        topics.retain(|t| t != topic);
+        Topics::put(topics);
+        TopicEnabled::remove(topic);
+        TopicIssuerCount::remove(topic);
+        TopicClaimCount::remove(topic);
+        Ok(())
+    }
+
     /// Performs all storage changes to revoke a claim on a topic about a holder.
     fn update_topic(topic: &Topic, enabled: bool) -> DispatchResult {
         ensure!(
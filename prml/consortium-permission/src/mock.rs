@@ -90,6 +90,8 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MaximumTopicSize: usize = 32;
     pub const MaximumValueSize: usize = 32;
+    pub const MaximumBatchSize: usize = 3;
+    pub const MaxTopicsPerIssuer: u32 = 2;
 }
 
 impl frame_system::Config for Test {
@@ -117,6 +119,8 @@ impl Config for Test {
     type Event = TestEvent;
     type MaximumTopicSize = MaximumTopicSize;
     type MaximumValueSize = MaximumValueSize;
+    type MaximumBatchSize = MaximumBatchSize;
+    type MaxTopicsPerIssuer = MaxTopicsPerIssuer;
     type IssuerPermissions = IssuerPermissionsMock;
 }
 
@@ -175,6 +179,9 @@ impl ExtBuilder {
                 ConsortiumPermission::initialise_issuers(&self.genesis_issuers)
             }
             for i in self.issuers.into_iter() {
+                for topic in i.1.iter() {
+                    <crate::TopicIssuerCount>::mutate(topic, |count| *count += 1);
+                }
                 <crate::Issuers<Test>>::insert(i.0, i.1);
             }
         });
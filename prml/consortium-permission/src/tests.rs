@@ -117,6 +117,29 @@ fn add_issuer_with_topic_rejects_adding_existing_account() {
         });
 }
 
+#[test]
+fn add_issuer_with_topic_rejects_too_many_topics() {
+    ExtBuilder::default()
+        .topic(b"first", true)
+        .topic(b"second", true)
+        .topic(b"third", true)
+        .build()
+        .execute_with(|| {
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, b"first".to_vec()));
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, b"second".to_vec()));
+            assert_eq!(
+                ConsortiumPermission::issuers(&ALICE).len() as u32,
+                <mock::Test as Trait>::MaxTopicsPerIssuer::get()
+            );
+            assert_noop!(
+                ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, b"third".to_vec()),
+                Error::<Test>::TooManyTopics
+            );
+            // The limit is per issuer
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, BOB, b"third".to_vec()));
+        });
+}
+
 #[test]
 fn add_issuer_with_topic_populates_storage() {
     ExtBuilder::default()
@@ -371,15 +394,44 @@ fn force_remove_issuer_works() {
                 vec![ACCESS_TOPIC.to_vec()]
             );
 
-            // The correct event should be deposited.
+            // The correct event should be deposited, after one auto-revoke event per topic.
             let events = System::events();
             assert_eq!(
-                events[0].event,
+                events.last().unwrap().event,
                 TestEvent::consortium_permission(RawEvent::IssuerForceRemoved(ALICE))
             );
         });
 }
 
+#[test]
+fn force_remove_issuer_auto_revokes_each_topic() {
+    ExtBuilder::default()
+        .genesis_topic(ACCESS_TOPIC)
+        .genesis_topic(&[1, 2, 3, 4, 5])
+        .issuer(vec![
+            (ALICE, vec![ACCESS_TOPIC.to_vec(), vec![1, 2, 3, 4, 5]]),
+            (BOB, vec![ACCESS_TOPIC.to_vec()]),
+        ])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ConsortiumPermission::force_remove_issuer(Origin::ROOT, ALICE));
+
+            let auto_revoked = System::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    TestEvent::consortium_permission(RawEvent::IssuerTopicAutoRevoked(issuer, topic)) => {
+                        Some((issuer, topic))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                auto_revoked,
+                vec![(ALICE, ACCESS_TOPIC.to_vec()), (ALICE, vec![1, 2, 3, 4, 5])]
+            );
+        });
+}
+
 #[test]
 fn force_remove_issuer_loses_only_self_assigned_access() {
     ExtBuilder::default().genesis_topic(ACCESS_TOPIC).build().execute_with(|| {
@@ -608,6 +660,263 @@ fn claim_value_is_just_right() {
         });
 }
 
+#[test]
+fn make_claims_in_a_batch() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec(), b"can_mint_burn".to_vec()])])
+        .topic(b"access", true)
+        .topic(b"can_mint_burn", true)
+        .build()
+        .execute_with(|| {
+            let mint_topic = b"can_mint_burn".to_vec();
+            assert_ok!(ConsortiumPermission::make_claims(
+                Origin::signed(ALICE),
+                vec![
+                    (BOB, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]),
+                    (CHARLIE, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]),
+                    (CHARLIE, mint_topic.clone(), vec![PERMISSION_GRANTED]),
+                ]
+            ));
+            assert_eq!(
+                ConsortiumPermission::claim((BOB, ACCESS_TOPIC.to_vec())),
+                (ALICE, vec![PERMISSION_GRANTED])
+            );
+            assert_eq!(
+                ConsortiumPermission::holder_claims(CHARLIE),
+                [ACCESS_TOPIC.to_vec(), mint_topic.clone()]
+            );
+            assert_eq!(ConsortiumPermission::issuer_claims(ALICE).len(), 3);
+            let events = System::events();
+            assert_eq!(events.len(), 1);
+            assert_eq!(
+                events[0].event,
+                TestEvent::consortium_permission(RawEvent::ClaimsBatchMade(ALICE, 3))
+            );
+        });
+}
+
+#[test]
+fn make_claims_rolls_back_whole_batch_on_unauthorized_topic() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .topic(b"can_mint_burn", true)
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ConsortiumPermission::make_claims(
+                    Origin::signed(ALICE),
+                    vec![
+                        (BOB, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]),
+                        (CHARLIE, b"can_mint_burn".to_vec(), vec![PERMISSION_GRANTED]),
+                    ]
+                ),
+                Error::<Test>::IssuerNotAuthorizedOnTopic
+            );
+            assert!(ConsortiumPermission::holder_claims(BOB).is_empty());
+            assert!(ConsortiumPermission::issuer_claims(ALICE).is_empty());
+        });
+}
+
+#[test]
+fn make_claims_rejects_oversized_batch() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            let claims = vec![
+                (BOB, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]);
+                <mock::Test as Trait>::MaximumBatchSize::get() + 1
+            ];
+            assert_noop!(
+                ConsortiumPermission::make_claims(Origin::signed(ALICE), claims),
+                Error::<Test>::BatchTooLarge
+            );
+        });
+}
+
+#[test]
+fn make_claims_rejects_long_value() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ConsortiumPermission::make_claims(
+                    Origin::signed(ALICE),
+                    vec![
+                        (BOB, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]),
+                        (
+                            CHARLIE,
+                            ACCESS_TOPIC.to_vec(),
+                            vec![PERMISSION_GRANTED; <mock::Test as Trait>::MaximumValueSize::get() + 1]
+                        ),
+                    ]
+                ),
+                Error::<Test>::ValueExceedsAllowableSize
+            );
+        });
+}
+
+#[test]
+fn update_claim_value_fails_if_claim_doesnt_exist() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ConsortiumPermission::update_claim_value(
+                    Origin::signed(ALICE),
+                    CHARLIE,
+                    ACCESS_TOPIC.to_vec(),
+                    vec![0x0]
+                ),
+                Error::<Test>::CannotRemoveNonExistentClaim
+            );
+        });
+}
+
+#[test]
+fn update_claim_value_fails_if_not_claim_issuer() {
+    ExtBuilder::default()
+        .issuer(vec![
+            (ALICE, vec![ACCESS_TOPIC.to_vec()]),
+            (BOB, vec![ACCESS_TOPIC.to_vec()]),
+        ])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            let topic = ACCESS_TOPIC.to_vec();
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            // Bob is authorized on the topic but did not issue this claim
+            assert_noop!(
+                ConsortiumPermission::update_claim_value(Origin::signed(BOB), CHARLIE, topic.clone(), vec![0x0]),
+                Error::<Test>::NotClaimIssuer
+            );
+            assert_noop!(
+                ConsortiumPermission::update_claim_value(Origin::signed(CHARLIE), CHARLIE, topic, vec![0x0]),
+                Error::<Test>::NotClaimIssuer
+            );
+        });
+}
+
+#[test]
+fn update_claim_value_fails_if_issuer_is_no_longer_authorized() {
+    ExtBuilder::default()
+        .issuer(vec![
+            (ALICE, vec![ACCESS_TOPIC.to_vec()]),
+            (BOB, vec![ACCESS_TOPIC.to_vec()]),
+        ])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            let topic = ACCESS_TOPIC.to_vec();
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(BOB),
+                ALICE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+
+            assert_ok!(ConsortiumPermission::remove_issuer_with_topic(Origin::ROOT, ALICE, topic.clone()));
+            assert_noop!(
+                ConsortiumPermission::update_claim_value(Origin::signed(ALICE), CHARLIE, topic.clone(), vec![0x0]),
+                Error::<Test>::IssuerNotAuthorizedOnTopic
+            );
+
+            assert_ok!(ConsortiumPermission::force_remove_issuer(Origin::ROOT, BOB));
+            assert_noop!(
+                ConsortiumPermission::update_claim_value(Origin::signed(BOB), ALICE, topic, vec![0x0]),
+                Error::<Test>::IssuerNotAuthorizedOnTopic
+            );
+        });
+}
+
+#[test]
+fn update_claim_value_fails_if_topic_is_disabled() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            let topic = ACCESS_TOPIC.to_vec();
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+
+            assert_ok!(ConsortiumPermission::disable_topic(Origin::ROOT, topic.clone()));
+            assert_noop!(
+                ConsortiumPermission::update_claim_value(Origin::signed(ALICE), CHARLIE, topic.clone(), vec![0x0]),
+                Error::<Test>::DisabledTopic
+            );
+
+            assert_ok!(ConsortiumPermission::enable_topic(Origin::ROOT, topic.clone()));
+            assert_ok!(ConsortiumPermission::update_claim_value(Origin::signed(ALICE), CHARLIE, topic, vec![0x0]));
+        });
+}
+
+#[test]
+fn update_claim_value() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            let topic = ACCESS_TOPIC.to_vec();
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            assert_ok!(ConsortiumPermission::update_claim_value(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![0x0]
+            ));
+            assert_eq!(
+                ConsortiumPermission::claim((CHARLIE, &topic)),
+                (ALICE, vec![0x0])
+            );
+            assert_eq!(
+                ConsortiumPermission::issuer_claims(ALICE),
+                [(CHARLIE, topic.clone())]
+            );
+            assert_eq!(
+                ConsortiumPermission::holder_claims(CHARLIE),
+                [topic.clone()]
+            );
+            let events = System::events();
+            assert_eq!(
+                events[1].event,
+                TestEvent::consortium_permission(RawEvent::ClaimValueUpdated(
+                    ALICE,
+                    CHARLIE,
+                    topic,
+                    vec![0x0]
+                ))
+            );
+        });
+}
+
 #[test]
 fn claim_revocation_fails_if_it_doesnt_exist() {
     ExtBuilder::default()
@@ -806,6 +1115,87 @@ fn sudo_revoke_a_claim() {
         });
 }
 
+#[test]
+fn sudo_revoke_all_claims_requires_root() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ConsortiumPermission::sudo_revoke_all_claims(Origin::signed(ALICE), CHARLIE, 0),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn sudo_revoke_all_claims_rejects_low_witness() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec(), b"can_mint_burn".to_vec()])])
+        .topic(b"access", true)
+        .topic(b"can_mint_burn", true)
+        .build()
+        .execute_with(|| {
+            assert_ok!(ConsortiumPermission::make_claims(
+                Origin::signed(ALICE),
+                vec![
+                    (CHARLIE, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]),
+                    (CHARLIE, b"can_mint_burn".to_vec(), vec![PERMISSION_GRANTED]),
+                ]
+            ));
+            assert_noop!(
+                ConsortiumPermission::sudo_revoke_all_claims(Origin::ROOT, CHARLIE, 1),
+                Error::<Test>::TooManyClaims
+            );
+        });
+}
+
+#[test]
+fn sudo_revoke_all_claims() {
+    ExtBuilder::default()
+        .issuer(vec![
+            (ALICE, vec![ACCESS_TOPIC.to_vec(), b"can_mint_burn".to_vec()]),
+            (BOB, vec![b"can_mint_burn".to_vec()]),
+        ])
+        .topic(b"access", true)
+        .topic(b"can_mint_burn", true)
+        .build()
+        .execute_with(|| {
+            let mint_topic = b"can_mint_burn".to_vec();
+            assert_ok!(ConsortiumPermission::make_claims(
+                Origin::signed(ALICE),
+                vec![
+                    (CHARLIE, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]),
+                    (BOB, ACCESS_TOPIC.to_vec(), vec![PERMISSION_GRANTED]),
+                ]
+            ));
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(BOB),
+                CHARLIE,
+                mint_topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+
+            assert_ok!(ConsortiumPermission::sudo_revoke_all_claims(Origin::ROOT, CHARLIE, 2));
+
+            assert!(ConsortiumPermission::holder_claims(CHARLIE).is_empty());
+            assert!(!<crate::Claim<Test>>::contains_key((CHARLIE, ACCESS_TOPIC.to_vec())));
+            assert!(!<crate::Claim<Test>>::contains_key((CHARLIE, mint_topic)));
+            assert!(ConsortiumPermission::issuer_claims(BOB).is_empty());
+            // Claims about other holders are untouched
+            assert_eq!(
+                ConsortiumPermission::issuer_claims(ALICE),
+                [(BOB, ACCESS_TOPIC.to_vec())]
+            );
+            assert_eq!(
+                ConsortiumPermission::holder_claims(BOB),
+                [ACCESS_TOPIC.to_vec()]
+            );
+            let events = System::events();
+            assert_eq!(
+                events[2].event,
+                TestEvent::consortium_permission(RawEvent::AllClaimsRevoked(CHARLIE, 2))
+            );
+        });
+}
+
 // Topics
 #[test]
 fn initialise_topics_works() {
@@ -842,6 +1232,22 @@ fn add_topic_rejects_adding_existing_topic() {
     });
 }
 
+#[test]
+#[should_panic(expected = "exceeds the maximum topic size of 32 bytes")]
+fn genesis_rejects_long_topic_name() {
+    ExtBuilder::default()
+        .genesis_topic(&vec![0_u8; MaximumTopicSize::get() + 1])
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "exceeds the maximum topic size of 32 bytes")]
+fn genesis_rejects_long_issuer_topic_name() {
+    ExtBuilder::default()
+        .genesis_issuer(vec![(ALICE, vec![vec![0_u8; MaximumTopicSize::get() + 1]])])
+        .build();
+}
+
 #[test]
 fn add_topic_rejects_long_topic_name() {
     ExtBuilder::default().build().execute_with(|| {
@@ -1004,6 +1410,142 @@ fn disable_topic_emits_events() {
 }
 
 
+#[test]
+fn remove_topic_requires_root() {
+    let topic = b"test";
+    ExtBuilder::default()
+        .topic(topic, true)
+        .build()
+        .execute_with(|| {
+            let topic = topic.to_vec();
+            assert_noop!(
+                ConsortiumPermission::remove_topic(Origin::signed(ALICE), topic.clone()),
+                BadOrigin
+            );
+            assert_ok!(ConsortiumPermission::remove_topic(Origin::ROOT, topic));
+        });
+}
+
+#[test]
+fn remove_topic_rejects_unknown_topic() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ConsortiumPermission::remove_topic(Origin::ROOT, b"test".to_vec()),
+            Error::<Test>::InvalidTopic
+        );
+    });
+}
+
+#[test]
+fn remove_topic_rejects_topic_in_use() {
+    let topic = b"test";
+    ExtBuilder::default()
+        .topic(topic, true)
+        .build()
+        .execute_with(|| {
+            let topic = topic.to_vec();
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, topic.clone()));
+            assert_noop!(
+                ConsortiumPermission::remove_topic(Origin::ROOT, topic.clone()),
+                Error::<Test>::TopicInUse
+            );
+
+            // The claim still references the topic after the issuer is removed
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            assert_ok!(ConsortiumPermission::remove_issuer_with_topic(Origin::ROOT, ALICE, topic.clone()));
+            assert_noop!(
+                ConsortiumPermission::remove_topic(Origin::ROOT, topic.clone()),
+                Error::<Test>::TopicInUse
+            );
+
+            assert_ok!(ConsortiumPermission::sudo_revoke_claim(Origin::ROOT, CHARLIE, topic.clone()));
+            assert_ok!(ConsortiumPermission::remove_topic(Origin::ROOT, topic));
+        });
+}
+
+#[test]
+fn remove_topic_updates_storage() {
+    let topic = b"test";
+    ExtBuilder::default()
+        .topic(b"access", true)
+        .topic(topic, true)
+        .build()
+        .execute_with(|| {
+            let topic = topic.to_vec();
+            assert_ok!(ConsortiumPermission::remove_topic(Origin::ROOT, topic.clone()));
+            assert_eq!(ConsortiumPermission::topics(), vec![ACCESS_TOPIC.to_vec()]);
+            assert!(!<crate::TopicEnabled>::contains_key(&topic));
+            let events = System::events();
+            assert_eq!(
+                events[0].event,
+                TestEvent::consortium_permission(RawEvent::TopicRemoved(topic))
+            );
+        });
+}
+
+#[test]
+fn topic_counts_track_issuers_and_claims() {
+    let topic = b"test";
+    ExtBuilder::default()
+        .topic(topic, true)
+        .build()
+        .execute_with(|| {
+            let topic = topic.to_vec();
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, topic.clone()));
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, BOB, topic.clone()));
+            assert_eq!(ConsortiumPermission::topic_issuer_count(&topic), 2);
+
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            // Reissuing a claim on the same holder and topic does not add to the count
+            assert_ok!(ConsortiumPermission::make_claim(Origin::signed(BOB), CHARLIE, topic.clone(), vec![0x0]));
+            assert_eq!(ConsortiumPermission::topic_claim_count(&topic), 1);
+
+            assert_ok!(ConsortiumPermission::remove_issuer_with_topic(Origin::ROOT, ALICE, topic.clone()));
+            assert_ok!(ConsortiumPermission::force_remove_issuer(Origin::ROOT, BOB));
+            assert_eq!(ConsortiumPermission::topic_issuer_count(&topic), 0);
+            assert_ok!(ConsortiumPermission::sudo_revoke_all_claims(Origin::ROOT, CHARLIE, 1));
+            assert_eq!(ConsortiumPermission::topic_claim_count(&topic), 0);
+        });
+}
+
+#[test]
+fn migrate_topic_counts_on_runtime_upgrade() {
+    use frame_support::traits::OnRuntimeUpgrade;
+    let topic = b"test";
+    ExtBuilder::default()
+        .topic(b"access", true)
+        .topic(topic, true)
+        .build()
+        .execute_with(|| {
+            let topic = topic.to_vec();
+            // Issuers and claims as stored before the counts were kept
+            <crate::Issuers<Test>>::insert(ALICE, vec![ACCESS_TOPIC.to_vec(), topic.clone()]);
+            <crate::Issuers<Test>>::insert(BOB, vec![topic.clone()]);
+            <crate::Claim<Test>>::insert((CHARLIE, &topic), (BOB, vec![PERMISSION_GRANTED]));
+
+            let _ = ConsortiumPermission::on_runtime_upgrade();
+
+            assert_eq!(<crate::StorageVersion>::get(), Releases::V1 as u32);
+            assert_eq!(ConsortiumPermission::topic_issuer_count(ACCESS_TOPIC.to_vec()), 1);
+            assert_eq!(ConsortiumPermission::topic_issuer_count(&topic), 2);
+            assert_eq!(ConsortiumPermission::topic_claim_count(&topic), 1);
+            assert_noop!(
+                ConsortiumPermission::remove_topic(Origin::ROOT, topic),
+                Error::<Test>::TopicInUse
+            );
+        });
+}
+
 #[test]
 fn can_count_permissioned_accounts() {
     ExtBuilder::default()
@@ -1062,3 +1604,135 @@ fn can_count_permissioned_accounts() {
         });
 }
 
+#[test]
+fn can_list_permissioned_accounts() {
+    ExtBuilder::default()
+        .topic(b"access", true)
+        .topic(b"can_mint_burn", true)
+        .build()
+        .execute_with(|| {
+            let topic = String::from("can_mint_burn").into_bytes();
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, ACCESS_TOPIC.to_vec()));
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, BOB, ACCESS_TOPIC.to_vec()));
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, topic.clone()));
+
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                BOB,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            // A different value is not the same permission.
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                ALICE,
+                topic.clone(),
+                vec![0x0]
+            ));
+
+            let mut holders = ConsortiumPermission::holders_with_permission(&topic, &vec![PERMISSION_GRANTED]);
+            holders.sort();
+            assert_eq!(holders, vec![BOB, CHARLIE]);
+            assert_eq!(
+                holders.len() as u32,
+                ConsortiumPermission::granted_permission_count(&topic, &vec![PERMISSION_GRANTED])
+            );
+
+            let mut access_holders =
+                ConsortiumPermission::holders_with_permission(&ACCESS_TOPIC.to_vec(), &vec![ACCESS_VALUE]);
+            access_holders.sort();
+            assert_eq!(access_holders, vec![ALICE, BOB]);
+        });
+}
+
+#[test]
+fn claims_pages_reconstruct_all_claims() {
+    ExtBuilder::default()
+        .topic(b"access", true)
+        .topic(b"can_mint_burn", true)
+        .build()
+        .execute_with(|| {
+            let topic = String::from("can_mint_burn").into_bytes();
+            // Authorizing issuers on "access" also claims it about them
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, ACCESS_TOPIC.to_vec()));
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, BOB, ACCESS_TOPIC.to_vec()));
+            assert_ok!(ConsortiumPermission::add_issuer_with_topic(Origin::ROOT, ALICE, topic.clone()));
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            let mut all_claims = <Claim<Test>>::iter().collect::<Vec<_>>();
+            assert_eq!(all_claims.len(), 3);
+
+            let (first_page, next) = ConsortiumPermission::claims_page(None, 2);
+            assert_eq!(first_page.len(), 2);
+            assert!(next.is_some());
+            let (second_page, next) = ConsortiumPermission::claims_page(next, 2);
+            assert_eq!(second_page.len(), 1);
+            assert_eq!(next, None);
+
+            let mut paged_claims = first_page.into_iter().chain(second_page).collect::<Vec<_>>();
+            all_claims.sort();
+            paged_claims.sort();
+            assert_eq!(paged_claims, all_claims);
+
+            // A page which exactly covers the claims has no continuation
+            assert_eq!(ConsortiumPermission::claims_page(None, 3).1, None);
+        });
+}
+
+#[test]
+fn has_claim_value_requires_matching_value_and_enabled_topic() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            let topic = ACCESS_TOPIC.to_vec();
+            assert!(!ConsortiumPermission::has_claim_value(&CHARLIE, &topic, &vec![PERMISSION_GRANTED]));
+
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            assert!(ConsortiumPermission::has_claim_value(&CHARLIE, &topic, &vec![PERMISSION_GRANTED]));
+            assert!(!ConsortiumPermission::has_claim_value(&CHARLIE, &topic, &vec![0x0]));
+            assert!(!ConsortiumPermission::has_claim_value(&BOB, &topic, &vec![PERMISSION_GRANTED]));
+
+            // The claim stays in storage but no longer counts once the topic is disabled.
+            assert_ok!(ConsortiumPermission::disable_topic(Origin::ROOT, topic.clone()));
+            assert!(!ConsortiumPermission::has_claim_value(&CHARLIE, &topic, &vec![PERMISSION_GRANTED]));
+        });
+}
+
+#[test]
+fn permission_claim_returns_existing_claim() {
+    ExtBuilder::default()
+        .issuer(vec![(ALICE, vec![ACCESS_TOPIC.to_vec()])])
+        .topic(b"access", true)
+        .build()
+        .execute_with(|| {
+            let topic = ACCESS_TOPIC.to_vec();
+            assert_eq!(ConsortiumPermission::permission_claim(&CHARLIE, &topic), None);
+            assert_ok!(ConsortiumPermission::make_claim(
+                Origin::signed(ALICE),
+                CHARLIE,
+                topic.clone(),
+                vec![PERMISSION_GRANTED]
+            ));
+            assert_eq!(
+                ConsortiumPermission::permission_claim(&CHARLIE, &topic),
+                Some((ALICE, vec![PERMISSION_GRANTED]))
+            );
+        });
+}
@@ -26,4 +26,10 @@ pub mod error_code {
 	pub const VALIDATION_EXPIRED: u8 = 181;
 	pub const VALIDATION_PREMATURE: u8 = 182;
 	pub const VALIDATION_CONVERSION: u8 = 183;
+}
+
+pub mod signature_version {
+	//! Doughnut Signature Version Constants
+	pub const SR25519: u8 = 0;
+	pub const ED25519: u8 = 1;
 }
\ No newline at end of file
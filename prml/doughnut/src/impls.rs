@@ -14,17 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with Plug. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{DoughnutRuntime, PlugDoughnut, constants::error_code};
-use sp_std::{self, convert::TryInto, prelude::*};
+use crate::{
+	Config, DoughnutAccountConversion, DoughnutRuntime, PlugDoughnut, VerifiedDoughnuts,
+	constants::{error_code, signature_version},
+};
+use sp_core::{ed25519, sr25519};
+use sp_std::{self, any::Any, convert::{TryFrom, TryInto}, marker::PhantomData, prelude::*};
 use sp_runtime::{
-	Doughnut,
-	traits::{PlugDoughnutApi, DoughnutApi, DoughnutVerify, SignedExtension, ValidationError, VerifyError},
+	Doughnut, DispatchError, DispatchResultWithInfo,
+	traits::{
+		PlugDoughnutApi, Dispatchable, DoughnutApi, DoughnutVerify, SignedExtension, UniqueSaturatedInto,
+		ValidationError, Verify, VerifyError,
+	},
 	transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
 };
 use frame_support::{
+	additional_traits::DelegatedDispatchVerifier,
 	dispatch::DispatchInfo,
-	traits::Time,
+	traits::{Get, GetCallMetadata, Time},
+	weights::DispatchClass,
+	Blake2_256, StorageHasher, StorageMap,
 };
+use codec::Encode;
 
 // Proxy calls to the inner Doughnut type and provide Runtime type conversions where required.
 impl<Runtime> PlugDoughnutApi for PlugDoughnut<Runtime>
@@ -91,6 +102,224 @@ impl<Runtime: DoughnutRuntime> DoughnutVerify for  PlugDoughnut<Runtime> {
 	}
 }
 
+/// Check `doughnut` is usable at the current chain time, i.e. it has not expired and its not before time has passed.
+/// Fails with the `VALIDATION_EXPIRED` or `VALIDATION_PREMATURE` error code respectively.
+pub fn validate_time_window<Runtime>(doughnut: &Runtime::Doughnut) -> Result<(), TransactionValidityError>
+where
+	Runtime: DoughnutRuntime,
+	<Runtime::Doughnut as PlugDoughnutApi>::Timestamp: Into<u64>,
+{
+	// Convert chain reported timestamp from milliseconds into seconds as per doughnut timestamp spec.
+	let now: u64 = (Runtime::TimestampProvider::now() / 1000_u32.into()).unique_saturated_into();
+	if now >= doughnut.expiry().into() {
+		return Err(InvalidTransaction::Custom(error_code::VALIDATION_EXPIRED).into())
+	}
+	if now < doughnut.not_before().into() {
+		return Err(InvalidTransaction::Custom(error_code::VALIDATION_PREMATURE).into())
+	}
+	Ok(())
+}
+
+/// The runtime account of the doughnut issuer, this is the origin delegated calls should run under
+pub fn issuer_account<Runtime>(doughnut: &Runtime::Doughnut) -> Result<Runtime::AccountId, &'static str>
+where
+	Runtime: DoughnutRuntime,
+	<Runtime::Doughnut as PlugDoughnutApi>::PublicKey: AsRef<[u8]>,
+{
+	Runtime::AccountIdConverter::to_account_id(doughnut.issuer().as_ref())
+}
+
+/// Dispatch `call` with the doughnut issuer as the signed origin, rather than `signer`, the holder who signed the
+/// extrinsic.
+/// The doughnut is checked in full before dispatch: its signature must be its issuer's, `signer` must be its holder,
+/// it must be within its time window and `Verifier` must find it permits the module and method of `call`.
+pub fn dispatch_as_issuer<Runtime, Verifier, Call>(
+	doughnut: &Runtime::Doughnut,
+	signer: &Runtime::AccountId,
+	args: Vec<(&str, &dyn Any)>,
+	call: Call,
+) -> DispatchResultWithInfo<Call::PostInfo>
+where
+	Runtime: DoughnutRuntime,
+	<Runtime::Doughnut as PlugDoughnutApi>::PublicKey: AsRef<[u8]>,
+	<Runtime::Doughnut as PlugDoughnutApi>::Signature: AsRef<[u8]>,
+	<Runtime::Doughnut as PlugDoughnutApi>::Timestamp: Into<u64>,
+	Verifier: DelegatedDispatchVerifier<Doughnut = Runtime::Doughnut, AccountId = Runtime::AccountId>,
+	Call: Dispatchable + GetCallMetadata,
+	Call::Origin: From<Option<Runtime::AccountId>>,
+	Call::PostInfo: Default,
+{
+	verify_signature::<Runtime>(doughnut).map_err(DispatchError::Other)?;
+	if holder_account::<Runtime>(doughnut).map_err(DispatchError::Other)? != *signer {
+		return Err(DispatchError::Other("Doughnut is not held by the signer").into())
+	}
+	validate_time_window::<Runtime>(doughnut).map_err(|err| match err {
+		TransactionValidityError::Invalid(InvalidTransaction::Custom(error_code::VALIDATION_PREMATURE)) =>
+			DispatchError::Other("Doughnut is not yet valid"),
+		_ => DispatchError::Other("Doughnut has expired"),
+	})?;
+	// The module and method are taken from the call itself, so the doughnut is checked against what is dispatched
+	let metadata = call.get_call_metadata();
+	Verifier::verify_dispatch(doughnut, metadata.pallet_name, metadata.function_name, args)
+		.map_err(DispatchError::Other)?;
+	let issuer = issuer_account::<Runtime>(doughnut).map_err(DispatchError::Other)?;
+	call.dispatch(Some(issuer).into())
+}
+
+/// The runtime account of the doughnut holder
+pub fn holder_account<Runtime>(doughnut: &Runtime::Doughnut) -> Result<Runtime::AccountId, &'static str>
+where
+	Runtime: DoughnutRuntime,
+	<Runtime::Doughnut as PlugDoughnutApi>::PublicKey: AsRef<[u8]>,
+{
+	Runtime::AccountIdConverter::to_account_id(doughnut.holder().as_ref())
+}
+
+/// Check the doughnut payload was signed by its issuer, using the scheme given by its signature version.
+/// sr25519 and ed25519 signatures are supported.
+pub fn verify_signature<Runtime>(doughnut: &Runtime::Doughnut) -> Result<(), &'static str>
+where
+	Runtime: DoughnutRuntime,
+	<Runtime::Doughnut as PlugDoughnutApi>::PublicKey: AsRef<[u8]>,
+	<Runtime::Doughnut as PlugDoughnutApi>::Signature: AsRef<[u8]>,
+{
+	let issuer = doughnut.issuer();
+	let signature = doughnut.signature();
+	let payload = doughnut.payload();
+	let verified = match doughnut.signature_version() {
+		signature_version::SR25519 => {
+			let issuer = sr25519::Public::try_from(issuer.as_ref())
+				.map_err(|_| "Doughnut issuer is not a valid sr25519 public key")?;
+			let signature = sr25519::Signature::try_from(signature.as_ref())
+				.map_err(|_| "Doughnut signature is not a valid sr25519 signature")?;
+			signature.verify(payload.as_slice(), &issuer)
+		},
+		signature_version::ED25519 => {
+			let issuer = ed25519::Public::try_from(issuer.as_ref())
+				.map_err(|_| "Doughnut issuer is not a valid ed25519 public key")?;
+			let signature = ed25519::Signature::try_from(signature.as_ref())
+				.map_err(|_| "Doughnut signature is not a valid ed25519 signature")?;
+			signature.verify(payload.as_slice(), &issuer)
+		},
+		_ => return Err("Doughnut signature version is not supported"),
+	};
+	if verified {
+		Ok(())
+	} else {
+		Err("Doughnut signature does not match its issuer")
+	}
+}
+
+/// Verifies the signature of a doughnut
+pub trait DoughnutSignatureVerifier<Doughnut> {
+	fn verify(doughnut: &Doughnut) -> Result<(), VerifyError>;
+}
+
+/// Verifies a doughnut with its own `DoughnutVerify` implementation
+pub struct VerifyDoughnut;
+
+impl<Doughnut: DoughnutVerify> DoughnutSignatureVerifier<Doughnut> for VerifyDoughnut {
+	fn verify(doughnut: &Doughnut) -> Result<(), VerifyError> {
+		doughnut.verify()
+	}
+}
+
+/// Verifies doughnuts with `Verifier`, remembering those that verify for the rest of the block so a doughnut
+/// authorizing many calls in a block has its signature checked once.
+/// Verified doughnuts are kept in the `VerifiedDoughnuts` storage of runtime `T`, keyed by the hash of their encoding,
+/// which the pallet clears when the block is finalized. Caching a doughnut registers the weight of writing and later
+/// removing it.
+pub struct VerificationCache<T, Verifier>(PhantomData<(T, Verifier)>);
+
+impl<T, Doughnut, Verifier> DoughnutSignatureVerifier<Doughnut> for VerificationCache<T, Verifier>
+where
+	T: Config,
+	Doughnut: Encode,
+	Verifier: DoughnutSignatureVerifier<Doughnut>,
+{
+	fn verify(doughnut: &Doughnut) -> Result<(), VerifyError> {
+		let key = Blake2_256::hash(&doughnut.encode());
+		if VerifiedDoughnuts::contains_key(key) {
+			return Ok(())
+		}
+		Verifier::verify(doughnut)?;
+		VerifiedDoughnuts::insert(key, ());
+		frame_system::Module::<T>::register_extra_weight_unchecked(
+			T::DbWeight::get().writes(2),
+			DispatchClass::Mandatory,
+		);
+		Ok(())
+	}
+}
+
+/// A set of `DelegatedDispatchVerifier`s, each verifying dispatches for the doughnut domain named by its `DOMAIN`.
+/// This is implemented for tuples of up to 4 verifiers.
+pub trait DomainVerifiers<Doughnut, AccountId> {
+	/// Verify the dispatch with the first verifier whose domain the doughnut has.
+	/// Returns `None` if the doughnut has none of the verifiers' domains.
+	fn verify_in_domain(
+		doughnut: &Doughnut,
+		module: &str,
+		method: &str,
+		args: Vec<(&str, &dyn Any)>,
+	) -> Option<Result<(), &'static str>>;
+}
+
+macro_rules! impl_domain_verifiers {
+	($($verifier:ident),+) => {
+		impl<Doughnut, AccountId, $($verifier),+> DomainVerifiers<Doughnut, AccountId> for ($($verifier,)+)
+		where
+			Doughnut: PlugDoughnutApi,
+			$($verifier: DelegatedDispatchVerifier<Doughnut = Doughnut, AccountId = AccountId>,)+
+		{
+			fn verify_in_domain(
+				doughnut: &Doughnut,
+				module: &str,
+				method: &str,
+				args: Vec<(&str, &dyn Any)>,
+			) -> Option<Result<(), &'static str>> {
+				$(
+					let domain = <$verifier as DelegatedDispatchVerifier>::DOMAIN;
+					if doughnut.get_domain(domain).is_some() {
+						return Some($verifier::verify_dispatch(doughnut, module, method, args));
+					}
+				)+
+				None
+			}
+		}
+	};
+}
+
+impl_domain_verifiers!(A);
+impl_domain_verifiers!(A, B);
+impl_domain_verifiers!(A, B, C);
+impl_domain_verifiers!(A, B, C, D);
+
+/// Routes dispatch verification to one of several `DelegatedDispatchVerifier`s, given as a tuple, by doughnut domain.
+/// A doughnut is verified by the first verifier in the tuple whose `DOMAIN` it has, so several permission domains
+/// can be used on one runtime.
+pub struct DomainRouter<Runtime, Verifiers>(PhantomData<(Runtime, Verifiers)>);
+
+impl<Runtime, Verifiers> DelegatedDispatchVerifier for DomainRouter<Runtime, Verifiers>
+where
+	Runtime: DoughnutRuntime,
+	Verifiers: DomainVerifiers<Runtime::Doughnut, Runtime::AccountId>,
+{
+	type Doughnut = Runtime::Doughnut;
+	type AccountId = Runtime::AccountId;
+	/// The router has no domain of its own, the domains are those of its verifiers
+	const DOMAIN: &'static str = "";
+	fn verify_dispatch(
+		doughnut: &Runtime::Doughnut,
+		module: &str,
+		method: &str,
+		args: Vec<(&str, &dyn Any)>,
+	) -> Result<(), &'static str> {
+		Verifiers::verify_in_domain(doughnut, module, method, args)
+			.unwrap_or(Err("Doughnut does not grant permission for any supported domain"))
+	}
+}
+
 impl<Runtime> SignedExtension for PlugDoughnut<Runtime>
 where
 	Runtime: DoughnutRuntime + Eq + Clone + Send + Sync,
@@ -106,7 +335,7 @@ where
 	fn validate(&self, who: &Self::AccountId, _call: &Self::Call, _info: Self::DispatchInfo, _len: usize) -> Result<ValidTransaction, TransactionValidityError>
 	{
 		// Check doughnut signature verifies
-		if let Err(err) = self.verify() {
+		if let Err(err) = Runtime::SignatureVerifier::verify(self) {
 			let code = match err {
 				VerifyError::Invalid => error_code::VERIFY_INVALID,
 				VerifyError::UnsupportedVersion => error_code::VERIFY_UNSUPPORTED_VERSION,
@@ -137,6 +366,9 @@ mod tests {
 	use sp_core::crypto::Pair;
 	use sp_keyring::{AccountKeyring, Ed25519Keyring};
 	use sp_runtime::{DoughnutV0, Doughnut, MultiSignature, traits::{IdentifyAccount, Verify, DoughnutSigning}};
+	use crate::{DomainRouter, PlugDomain, PlugDoughnutDispatcher};
+	use codec::Encode;
+	use frame_support::{additional_traits::DelegatedDispatchVerifier, traits::CallMetadata};
 
 	type Signature = MultiSignature;
 	type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
@@ -158,6 +390,8 @@ mod tests {
 		type Call = ();
 		type Doughnut = PlugDoughnut<Self>;
 		type TimestampProvider = FixedTimestampProvider;
+		type AccountIdConverter = crate::RawPublicKeyAccount;
+		type SignatureVerifier = crate::VerifyDoughnut;
 	}
 
 	// Helper function to create a DoughnutV0
@@ -234,6 +468,27 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn plug_doughnut_pre_dispatch_rejects_expired() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.expiry = 49;
+		doughnut.sign_sr25519(&issuer.pair().to_ed25519_bytes()).expect("it signs ok");
+
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		// an expired doughnut can not be dispatched even if it was valid when it entered the pool
+		assert_eq!(
+			<PlugDoughnut<_> as SignedExtension>::pre_dispatch(
+				plug_doughnut,
+				&holder.to_account_id(), // who
+				&(), // Call
+				Default::default(), // DispatchInfo
+				0usize // len
+			),
+			Err(InvalidTransaction::Custom(error_code::VALIDATION_EXPIRED).into())
+		);
+	}
+
 	#[test]
 	fn plug_doughnut_does_not_validate_bad_holder() {
 		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
@@ -372,4 +627,513 @@ mod tests {
 			Err(InvalidTransaction::Custom(error_code::VERIFY_INVALID).into())
 		);
 	}
+
+	// Helper function to create a doughnut with a plug domain
+	fn make_plug_doughnut(domain: &PlugDomain) -> PlugDoughnut<Runtime> {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.domains = vec![("plug".to_string(), domain.encode())];
+		PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut))
+	}
+
+	#[test]
+	fn plug_doughnut_dispatch_verifies_permitted_method() {
+		let domain = PlugDomain {
+			modules: vec![(b"GenericAsset".to_vec(), vec![b"transfer".to_vec()])],
+		};
+		let plug_doughnut = make_plug_doughnut(&domain);
+
+		assert_eq!(
+			PlugDoughnutDispatcher::<Runtime>::verify_dispatch(&plug_doughnut, "GenericAsset", "transfer", vec![]),
+			Ok(())
+		);
+		assert_eq!(
+			PlugDoughnutDispatcher::<Runtime>::verify_dispatch(&plug_doughnut, "GenericAsset", "burn", vec![]),
+			Err("Doughnut does not grant permission for this module and method")
+		);
+		assert_eq!(
+			PlugDoughnutDispatcher::<Runtime>::verify_dispatch(&plug_doughnut, "Attestation", "transfer", vec![]),
+			Err("Doughnut does not grant permission for this module and method")
+		);
+	}
+
+	#[test]
+	fn plug_doughnut_dispatch_requires_plug_domain() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(
+			PlugDoughnutDispatcher::<Runtime>::verify_dispatch(&plug_doughnut, "GenericAsset", "transfer", vec![]),
+			Err("Doughnut does not grant permission for the plug domain")
+		);
+	}
+
+	#[test]
+	fn plug_doughnut_dispatch_rejects_malformed_plug_domain() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.domains = vec![("plug".to_string(), vec![0xff])];
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(
+			PlugDoughnutDispatcher::<Runtime>::verify_dispatch(&plug_doughnut, "GenericAsset", "transfer", vec![]),
+			Err("Doughnut plug domain is malformed")
+		);
+	}
+
+	/// Allows every dispatch on doughnuts with the "other" domain
+	pub struct OtherDomainVerifier;
+	impl DelegatedDispatchVerifier for OtherDomainVerifier {
+		type Doughnut = PlugDoughnut<Runtime>;
+		type AccountId = AccountId;
+		const DOMAIN: &'static str = "other";
+		fn verify_dispatch(
+			_doughnut: &Self::Doughnut,
+			_module: &str,
+			_method: &str,
+			_args: Vec<(&str, &dyn Any)>,
+		) -> Result<(), &'static str> {
+			Ok(())
+		}
+	}
+
+	type Router = DomainRouter<Runtime, (PlugDoughnutDispatcher<Runtime>, OtherDomainVerifier)>;
+
+	#[test]
+	fn domain_router_routes_by_doughnut_domain() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+
+		// routed to the plug verifier, which only permits `GenericAsset::transfer`
+		let domain = PlugDomain {
+			modules: vec![(b"GenericAsset".to_vec(), vec![b"transfer".to_vec()])],
+		};
+		let plug_doughnut = make_plug_doughnut(&domain);
+		assert_eq!(Router::verify_dispatch(&plug_doughnut, "GenericAsset", "transfer", vec![]), Ok(()));
+		assert_eq!(
+			Router::verify_dispatch(&plug_doughnut, "GenericAsset", "burn", vec![]),
+			Err("Doughnut does not grant permission for this module and method")
+		);
+
+		// routed to the other verifier, which permits everything
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.domains = vec![("other".to_string(), vec![])];
+		let other_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		assert_eq!(Router::verify_dispatch(&other_doughnut, "GenericAsset", "burn", vec![]), Ok(()));
+	}
+
+	#[test]
+	fn domain_router_prefers_the_first_verifier_with_a_matching_domain() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.domains = vec![("other".to_string(), vec![]), ("plug".to_string(), PlugDomain::default().encode())];
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(
+			Router::verify_dispatch(&plug_doughnut, "GenericAsset", "transfer", vec![]),
+			Err("Doughnut does not grant permission for this module and method")
+		);
+	}
+
+	#[test]
+	fn domain_router_rejects_doughnut_without_a_routed_domain() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(
+			Router::verify_dispatch(&plug_doughnut, "GenericAsset", "transfer", vec![]),
+			Err("Doughnut does not grant permission for any supported domain")
+		);
+	}
+
+	thread_local! {
+		static VERIFICATIONS: std::cell::Cell<u32> = std::cell::Cell::new(0);
+	}
+
+	/// Verifies every doughnut, counting how many times it is called
+	pub struct CountingVerifier;
+	impl DoughnutSignatureVerifier<PlugDoughnut<Runtime>> for CountingVerifier {
+		fn verify(_doughnut: &PlugDoughnut<Runtime>) -> Result<(), VerifyError> {
+			VERIFICATIONS.with(|count| count.set(count.get() + 1));
+			Ok(())
+		}
+	}
+
+	/// A runtime with the pallet keeping the doughnuts verified by `VerificationCache`
+	mod cache_runtime {
+		use frame_support::{parameter_types, weights::constants::RocksDbWeight};
+		use sp_core::H256;
+		use sp_runtime::{testing::Header, traits::{BlakeTwo256, IdentityLookup}};
+		use crate as prml_doughnut;
+
+		type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+		type Block = frame_system::mocking::MockBlock<Test>;
+
+		frame_support::construct_runtime!(
+			pub enum Test where
+				Block = Block,
+				NodeBlock = Block,
+				UncheckedExtrinsic = UncheckedExtrinsic,
+			{
+				System: frame_system::{Module, Call, Config, Storage, Event<T>},
+				Doughnut: prml_doughnut::{Module, Call, Storage},
+			}
+		);
+
+		parameter_types! {
+			pub const BlockHashCount: u64 = 250;
+		}
+		impl frame_system::Config for Test {
+			type BaseCallFilter = ();
+			type BlockWeights = ();
+			type BlockLength = ();
+			type DbWeight = RocksDbWeight;
+			type Origin = Origin;
+			type Index = u64;
+			type Call = Call;
+			type BlockNumber = u64;
+			type Hash = H256;
+			type Hashing = BlakeTwo256;
+			type AccountId = u64;
+			type Lookup = IdentityLookup<Self::AccountId>;
+			type Header = Header;
+			type Event = Event;
+			type BlockHashCount = BlockHashCount;
+			type Version = ();
+			type PalletInfo = PalletInfo;
+			type AccountData = ();
+			type OnNewAccount = ();
+			type OnKilledAccount = ();
+			type SystemWeightInfo = ();
+			type SS58Prefix = ();
+		}
+
+		impl prml_doughnut::Config for Test {}
+
+		pub fn new_test_ext() -> sp_io::TestExternalities {
+			frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+		}
+	}
+
+	#[test]
+	fn verification_cache_verifies_each_doughnut_once_per_block() {
+		use cache_runtime::{Doughnut as DoughnutModule, System, Test};
+		use frame_support::{traits::{OnFinalize, OnInitialize}, weights::constants::RocksDbWeight};
+		type Cache = VerificationCache<Test, CountingVerifier>;
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		let doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		let other_doughnut = make_doughnut(holder.to_raw_public(), issuer.to_raw_public());
+		let other_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(other_doughnut));
+
+		cache_runtime::new_test_ext().execute_with(|| {
+			assert_eq!(Cache::verify(&doughnut), Ok(()));
+			assert_eq!(Cache::verify(&doughnut), Ok(()));
+			assert_eq!(VERIFICATIONS.with(|count| count.get()), 1);
+
+			assert_eq!(Cache::verify(&other_doughnut), Ok(()));
+			assert_eq!(VERIFICATIONS.with(|count| count.get()), 2);
+			// writing and removing each cached doughnut is charged
+			assert_eq!(
+				*System::block_weight().get(DispatchClass::Mandatory),
+				RocksDbWeight::get().writes(4)
+			);
+
+			// the cache does not outlive the block
+			DoughnutModule::on_finalize(1);
+			assert_eq!(DoughnutModule::on_initialize(2), RocksDbWeight::get().writes(1));
+			assert_eq!(Cache::verify(&doughnut), Ok(()));
+			assert_eq!(VERIFICATIONS.with(|count| count.get()), 3);
+		});
+	}
+
+	#[test]
+	fn verification_cache_does_not_remember_failures() {
+		type Cache = VerificationCache<cache_runtime::Test, VerifyDoughnut>;
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		let doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		cache_runtime::new_test_ext().execute_with(|| {
+			assert_eq!(Cache::verify(&doughnut), Err(VerifyError::Invalid));
+			assert_eq!(Cache::verify(&doughnut), Err(VerifyError::Invalid));
+		});
+	}
+
+	#[test]
+	fn validate_time_window_works() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		// now is 50 seconds
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.not_before = 50;
+		doughnut.expiry = 51;
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(validate_time_window::<Runtime>(&plug_doughnut), Ok(()));
+	}
+
+	#[test]
+	fn validate_time_window_fails_when_expired() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.expiry = 50;
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(
+			validate_time_window::<Runtime>(&plug_doughnut),
+			Err(InvalidTransaction::Custom(error_code::VALIDATION_EXPIRED).into())
+		);
+	}
+
+	#[test]
+	fn validate_time_window_fails_when_premature() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.not_before = 51;
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(
+			validate_time_window::<Runtime>(&plug_doughnut),
+			Err(InvalidTransaction::Custom(error_code::VALIDATION_PREMATURE).into())
+		);
+	}
+
+	#[test]
+	fn verify_signature_works_for_sr25519() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.sign_sr25519(&issuer.pair().to_ed25519_bytes()).expect("it signs ok");
+
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		assert_eq!(verify_signature::<Runtime>(&plug_doughnut), Ok(()));
+	}
+
+	#[test]
+	fn verify_signature_works_for_ed25519() {
+		let (issuer, holder) = (Ed25519Keyring::Alice, Ed25519Keyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.signature_version = signature_version::ED25519;
+		doughnut.signature = issuer.pair().sign(&doughnut.payload()).into();
+
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		assert_eq!(verify_signature::<Runtime>(&plug_doughnut), Ok(()));
+	}
+
+	#[test]
+	fn verify_signature_fails_for_tampered_doughnut() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.sign_sr25519(&issuer.pair().to_ed25519_bytes()).expect("it signs ok");
+		// extend the doughnut after it was signed
+		doughnut.expiry = 6000;
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		assert_eq!(verify_signature::<Runtime>(&plug_doughnut), Err("Doughnut signature does not match its issuer"));
+
+		let (issuer, holder) = (Ed25519Keyring::Alice, Ed25519Keyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.signature_version = signature_version::ED25519;
+		doughnut.signature = issuer.pair().sign(&doughnut.payload()).into();
+		doughnut.expiry = 6000;
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		assert_eq!(verify_signature::<Runtime>(&plug_doughnut), Err("Doughnut signature does not match its issuer"));
+	}
+
+	#[test]
+	fn verify_signature_fails_for_unknown_signature_version() {
+		let (issuer, holder) = (Ed25519Keyring::Alice, Ed25519Keyring::Bob);
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.signature_version = 200;
+		doughnut.signature = issuer.pair().sign(&doughnut.payload()).into();
+
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		assert_eq!(verify_signature::<Runtime>(&plug_doughnut), Err("Doughnut signature version is not supported"));
+	}
+
+	#[test]
+	fn issuer_and_holder_accounts_round_trip() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+
+		assert_eq!(issuer_account::<Runtime>(&plug_doughnut), Ok(issuer.to_account_id()));
+		assert_eq!(holder_account::<Runtime>(&plug_doughnut), Ok(holder.to_account_id()));
+	}
+
+	thread_local! {
+		static BALANCES: std::cell::RefCell<std::collections::HashMap<AccountId, u64>> = Default::default();
+	}
+
+	fn balance(who: &AccountId) -> u64 {
+		BALANCES.with(|b| b.borrow().get(who).cloned().unwrap_or_default())
+	}
+
+	/// A call which debits `amount` from the balance of its signed origin
+	struct Debit(u64);
+
+	impl GetCallMetadata for Debit {
+		fn get_module_names() -> &'static [&'static str] {
+			&["Test"]
+		}
+		fn get_call_names(_module: &str) -> &'static [&'static str] {
+			&["debit"]
+		}
+		fn get_call_metadata(&self) -> CallMetadata {
+			CallMetadata { pallet_name: "Test", function_name: "debit" }
+		}
+	}
+
+	impl Dispatchable for Debit {
+		type Origin = Option<AccountId>;
+		type Config = ();
+		type Info = ();
+		type PostInfo = ();
+		fn dispatch(self, origin: Self::Origin) -> DispatchResultWithInfo<()> {
+			let who = origin.ok_or(DispatchError::BadOrigin)?;
+			BALANCES.with(|b| {
+				let mut balances = b.borrow_mut();
+				let balance = balances.entry(who).or_default();
+				*balance = balance.checked_sub(self.0).ok_or(DispatchError::Other("Insufficient balance"))?;
+				Ok(())
+			})
+		}
+	}
+
+	/// A call of `Test::credit`, which the test doughnuts do not permit, but which debits like `Debit`
+	struct Credit(u64);
+
+	impl GetCallMetadata for Credit {
+		fn get_module_names() -> &'static [&'static str] {
+			&["Test"]
+		}
+		fn get_call_names(_module: &str) -> &'static [&'static str] {
+			&["credit"]
+		}
+		fn get_call_metadata(&self) -> CallMetadata {
+			CallMetadata { pallet_name: "Test", function_name: "credit" }
+		}
+	}
+
+	impl Dispatchable for Credit {
+		type Origin = Option<AccountId>;
+		type Config = ();
+		type Info = ();
+		type PostInfo = ();
+		fn dispatch(self, origin: Self::Origin) -> DispatchResultWithInfo<()> {
+			Debit(self.0).dispatch(origin)
+		}
+	}
+
+	/// Dispatch `Debit(30)` as the doughnut issuer, signed by `signer`
+	fn debit_as_issuer(doughnut: &PlugDoughnut<Runtime>, signer: &AccountId) -> DispatchResultWithInfo<()> {
+		dispatch_as_issuer::<Runtime, PlugDoughnutDispatcher<Runtime>, _>(doughnut, signer, vec![], Debit(30))
+	}
+
+	// Helper function to create a DoughnutV0 permitting `Test::debit`, which is signed by its issuer after `modify`
+	fn make_debit_doughnut(
+		issuer: AccountKeyring,
+		holder: AccountKeyring,
+		modify: impl FnOnce(&mut DoughnutV0),
+	) -> PlugDoughnut<Runtime> {
+		let domain = PlugDomain { modules: vec![(b"Test".to_vec(), vec![b"debit".to_vec()])] };
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.domains = vec![("plug".to_string(), domain.encode())];
+		modify(&mut doughnut);
+		doughnut.sign_sr25519(&issuer.pair().to_ed25519_bytes()).expect("it signs ok");
+		PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut))
+	}
+
+	#[test]
+	fn dispatch_as_issuer_debits_the_issuer() {
+		let (issuer, holder) = (AccountKeyring::Alice, AccountKeyring::Bob);
+		let plug_doughnut = make_debit_doughnut(issuer, holder, |_| {});
+		BALANCES.with(|b| {
+			let mut balances = b.borrow_mut();
+			balances.insert(issuer.to_account_id(), 100);
+			balances.insert(holder.to_account_id(), 100);
+		});
+
+		assert_eq!(debit_as_issuer(&plug_doughnut, &holder.to_account_id()), Ok(()));
+		assert_eq!(balance(&issuer.to_account_id()), 70);
+		assert_eq!(balance(&holder.to_account_id()), 100);
+	}
+
+	#[test]
+	fn dispatch_as_issuer_fails_with_the_issuer_balance() {
+		let (issuer, holder) = (AccountKeyring::Charlie, AccountKeyring::Dave);
+		let plug_doughnut = make_debit_doughnut(issuer, holder, |_| {});
+		BALANCES.with(|b| b.borrow_mut().insert(holder.to_account_id(), 100));
+
+		assert_eq!(
+			debit_as_issuer(&plug_doughnut, &holder.to_account_id()),
+			Err(DispatchError::Other("Insufficient balance").into())
+		);
+		assert_eq!(balance(&holder.to_account_id()), 100);
+	}
+
+	#[test]
+	fn dispatch_as_issuer_rejects_doughnut_outside_its_time_window() {
+		let (issuer, holder) = (AccountKeyring::Eve, AccountKeyring::Ferdie);
+		BALANCES.with(|b| b.borrow_mut().insert(issuer.to_account_id(), 100));
+
+		let plug_doughnut = make_debit_doughnut(issuer, holder, |doughnut| doughnut.expiry = 49);
+		assert_eq!(
+			debit_as_issuer(&plug_doughnut, &holder.to_account_id()),
+			Err(DispatchError::Other("Doughnut has expired").into())
+		);
+
+		let plug_doughnut = make_debit_doughnut(issuer, holder, |doughnut| doughnut.not_before = 51);
+		assert_eq!(
+			debit_as_issuer(&plug_doughnut, &holder.to_account_id()),
+			Err(DispatchError::Other("Doughnut is not yet valid").into())
+		);
+		assert_eq!(balance(&issuer.to_account_id()), 100);
+	}
+
+	#[test]
+	fn dispatch_as_issuer_rejects_doughnut_that_does_not_authorize_the_call() {
+		let (issuer, holder) = (AccountKeyring::One, AccountKeyring::Two);
+		BALANCES.with(|b| b.borrow_mut().insert(issuer.to_account_id(), 100));
+		let plug_doughnut = make_debit_doughnut(issuer, holder, |_| {});
+
+		// signed by someone other than the holder
+		assert_eq!(
+			debit_as_issuer(&plug_doughnut, &issuer.to_account_id()),
+			Err(DispatchError::Other("Doughnut is not held by the signer").into())
+		);
+		// the doughnut is not signed by its issuer
+		let mut doughnut = make_doughnut(issuer.to_raw_public(), holder.to_raw_public());
+		doughnut.sign_sr25519(&holder.pair().to_ed25519_bytes()).expect("it signs ok");
+		let plug_doughnut = PlugDoughnut::<Runtime>::new(Doughnut::V0(doughnut));
+		assert_eq!(
+			debit_as_issuer(&plug_doughnut, &holder.to_account_id()),
+			Err(DispatchError::Other("Doughnut signature does not match its issuer").into())
+		);
+		assert_eq!(balance(&issuer.to_account_id()), 100);
+	}
+
+	#[test]
+	fn dispatch_as_issuer_rejects_a_call_the_doughnut_does_not_permit() {
+		let (issuer, holder) = (AccountKeyring::Dave, AccountKeyring::Eve);
+		BALANCES.with(|b| b.borrow_mut().insert(issuer.to_account_id(), 100));
+		let plug_doughnut = make_debit_doughnut(issuer, holder, |_| {});
+
+		// the doughnut permits `Test::debit`, the call is `Test::credit`
+		assert_eq!(
+			dispatch_as_issuer::<Runtime, PlugDoughnutDispatcher<Runtime>, _>(
+				&plug_doughnut,
+				&holder.to_account_id(),
+				vec![],
+				Credit(30),
+			),
+			Err(DispatchError::Other("Doughnut does not grant permission for this module and method").into())
+		);
+		assert_eq!(balance(&issuer.to_account_id()), 100);
+	}
+
+	#[test]
+	fn raw_public_key_account_requires_32_bytes() {
+		let account: Result<AccountId, _> = crate::RawPublicKeyAccount::to_account_id(&[1u8; 31]);
+		assert_eq!(account, Err("Doughnut public key is not 32 bytes"));
+	}
 }
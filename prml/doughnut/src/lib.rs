@@ -17,31 +17,85 @@
 
 //! A collection of doughnut traits and structs which provide doughnut integration for a plug runtime.
 //! This includes validation and signature verification and type conversions.
+//! The pallet here keeps the doughnuts `VerificationCache` has verified in the current block, and must be included in
+//! the runtime when the cache is used.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Encode, Decode};
-use sp_std::{self, prelude::Vec, any::Any};
+use sp_std::{self, convert::TryFrom, prelude::Vec, any::Any};
 use sp_runtime::{
-	Doughnut,
+	Doughnut, RuntimeDebug,
 	traits::{PlugDoughnutApi, Member},
 };
 use frame_support::{
 	additional_traits::DelegatedDispatchVerifier,
-	traits::Time,
+	decl_module, decl_storage,
+	storage::StoragePrefixedMap,
+	traits::{Get, Time},
+	weights::Weight,
 	Parameter,
 };
 
 mod constants;
-pub use constants::error_code;
+pub use constants::{error_code, signature_version};
 mod impls;
+pub use impls::{
+	dispatch_as_issuer, holder_account, issuer_account, validate_time_window, verify_signature, DomainRouter,
+	DomainVerifiers, DoughnutSignatureVerifier, VerificationCache, VerifyDoughnut,
+};
 
 // TODO: This should eventually become a super trait for `system::Config` so that all doughnut functionality may be moved here
 /// A runtime which supports doughnut verification and validation
-pub trait DoughnutRuntime {
+pub trait DoughnutRuntime: Sized {
 	type AccountId: Member + Parameter;
 	type Call;
 	type Doughnut: Member + Parameter + PlugDoughnutApi;
 	type TimestampProvider: Time;
+	/// Maps doughnut issuer and holder public keys to runtime accounts
+	type AccountIdConverter: DoughnutAccountConversion<Self::AccountId>;
+	/// Verifies doughnut signatures when they are validated as a signed extension,
+	/// e.g. `VerificationCache<Runtime, VerifyDoughnut>` to verify each doughnut at most once per block
+	type SignatureVerifier: DoughnutSignatureVerifier<PlugDoughnut<Self>>;
+}
+
+/// Configures the pallet which keeps the doughnuts verified by `VerificationCache` for the current block
+pub trait Config: frame_system::Config {}
+
+decl_storage! {
+	trait Store for Module<T: Config> as Doughnut {
+		/// The hashes of the doughnuts verified by `VerificationCache` in the current block
+		pub VerifiedDoughnuts: map hasher(identity) [u8; 32] => Option<()>;
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Config> for enum Call where origin: T::Origin {
+		/// Account for clearing the verified doughnuts on finalize.
+		/// Removing each doughnut is charged by `VerificationCache` as it is verified.
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			T::DbWeight::get().writes(1)
+		}
+
+		/// Forget the doughnuts verified in this block, so the cache never outlives it
+		fn on_finalize(_n: T::BlockNumber) {
+			VerifiedDoughnuts::remove_all();
+		}
+	}
+}
+
+/// Converts the raw public key bytes of a doughnut issuer or holder into a runtime account id
+pub trait DoughnutAccountConversion<AccountId> {
+	fn to_account_id(public_key: &[u8]) -> Result<AccountId, &'static str>;
+}
+
+/// Uses a 32 byte public key as the account id directly, as with `AccountId32`
+pub struct RawPublicKeyAccount;
+
+impl<AccountId: From<[u8; 32]>> DoughnutAccountConversion<AccountId> for RawPublicKeyAccount {
+	fn to_account_id(public_key: &[u8]) -> Result<AccountId, &'static str> {
+		let raw = <[u8; 32]>::try_from(public_key).map_err(|_| "Doughnut public key is not 32 bytes")?;
+		Ok(raw.into())
+	}
 }
 
 /// A doughnut wrapped for compatibility with the extrinsic transport layer and the plug runtime types.
@@ -68,6 +122,23 @@ where
 	}
 }
 
+/// The permissions granted by a doughnut's "plug" domain, stored SCALE encoded in the domain payload.
+/// Each entry is a module name with the names of the methods it allows to be dispatched.
+#[derive(Encode, Decode, Clone, Default, Eq, PartialEq, RuntimeDebug)]
+pub struct PlugDomain {
+	pub modules: Vec<(Vec<u8>, Vec<Vec<u8>>)>,
+}
+
+impl PlugDomain {
+	/// Whether `method` of `module` is allowed
+	pub fn permits(&self, module: &str, method: &str) -> bool {
+		self.modules
+			.iter()
+			.find(|(name, _)| name.as_slice() == module.as_bytes())
+			.map_or(false, |(_, methods)| methods.iter().any(|name| name.as_slice() == method.as_bytes()))
+	}
+}
+
 /// It verifies that a doughnut allows execution of a module+method combination
 pub struct PlugDoughnutDispatcher<Runtime: DoughnutRuntime>(sp_std::marker::PhantomData<Runtime>);
 
@@ -76,12 +147,21 @@ impl<Runtime: DoughnutRuntime> DelegatedDispatchVerifier for PlugDoughnutDispatc
 	type AccountId = Runtime::AccountId;
 	const DOMAIN: &'static str = "plug";
 	/// Verify a Doughnut proof authorizes method dispatch given some input parameters
+	/// The doughnut's "plug" domain must permit `method` of `module`, arguments are not restricted.
 	fn verify_dispatch(
-		_doughnut: &Runtime::Doughnut,
-		_module: &str,
-		_method: &str,
+		doughnut: &Runtime::Doughnut,
+		module: &str,
+		method: &str,
 		_args: Vec<(&str, &dyn Any)>,
 	) -> Result<(), &'static str> {
-		Err("Doughnut dispatch verification is not implemented for this domain")
+		let mut domain_payload = doughnut
+			.get_domain(Self::DOMAIN)
+			.ok_or("Doughnut does not grant permission for the plug domain")?;
+		let domain = PlugDomain::decode(&mut domain_payload).map_err(|_| "Doughnut plug domain is malformed")?;
+		if domain.permits(module, method) {
+			Ok(())
+		} else {
+			Err("Doughnut does not grant permission for this module and method")
+		}
 	}
 }
@@ -25,14 +25,36 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use prml_generic_asset::AssetInfo;
+use prml_generic_asset::{AssetInfo, AssetInfoV0};
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 	/// The API to query asset meta information.
-	pub trait AssetMetaApi<AssetId: Decode + Encode>
+	///
+	/// Version 2 added every call but `asset_meta`, and its asset info has since gained fields.
+	#[api_version(2)]
+	pub trait AssetMetaApi<AssetId: Decode + Encode, Balance: Decode + Encode, AccountId: Decode + Encode>
 	{
 		/// Get all assets data paired with their ids.
 		fn asset_meta() -> Vec<(AssetId, AssetInfo)>;
+		/// Get all assets data paired with their ids, in the asset info encoding of version 1.
+		#[changed_in(2)]
+		fn asset_meta() -> Vec<(AssetId, AssetInfoV0)>;
+		/// Get the total issuance of an asset.
+		fn total_issuance(asset_id: AssetId) -> Balance;
+		/// Get the total amount of an asset ever reclaimed as dust.
+		fn total_dust_reclaimed(asset_id: AssetId) -> Balance;
+		/// Get the most of an asset an account can transfer, accounting for locks and,
+		/// if `keep_alive` is set, the existential deposit.
+		fn transferable_balance(asset_id: AssetId, who: AccountId, keep_alive: bool) -> Balance;
+		/// Get the free, reserved and frozen balances of an account, where frozen is the largest active lock.
+		fn balance_detailed(asset_id: AssetId, who: AccountId) -> (Balance, Balance, Balance);
+		/// Get up to `limit` holders of an asset with their free balances, starting after `start_key`.
+		/// Also returns the key to continue from, if there are more holders.
+		fn holders(
+			asset_id: AssetId,
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(AccountId, Balance)>, Option<Vec<u8>>);
 	}
 }
@@ -22,16 +22,77 @@ use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
 use prml_generic_asset::AssetInfo;
 pub use prml_generic_asset_rpc_runtime_api::AssetMetaApi;
-use sp_api::ProvideRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
-use std::sync::Arc;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::{Block as BlockT, MaybeDisplay}};
+use std::{convert::TryInto, sync::Arc};
 
 #[rpc]
-pub trait GenericAssetApi<BlockHash, ResponseType> {
+pub trait GenericAssetApi<BlockHash, AccountId, AssetId, ResponseType> {
 	/// Get all assets data paired with their ids.
 	#[rpc(name = "genericAsset_registeredAssets")]
 	fn asset_meta(&self, at: Option<BlockHash>) -> Result<ResponseType>;
+
+	/// Get the total issuance of an asset.
+	#[rpc(name = "genericAsset_totalIssuance")]
+	fn total_issuance(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Get the total amount of an asset ever reclaimed as dust.
+	#[rpc(name = "genericAsset_totalDustReclaimed")]
+	fn total_dust_reclaimed(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Get the most of an asset an account can transfer, accounting for locks and,
+	/// if `keep_alive` is set, the existential deposit.
+	#[rpc(name = "genericAsset_transferable")]
+	fn transferable_balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		keep_alive: bool,
+		at: Option<BlockHash>,
+	) -> Result<NumberOrHex>;
+
+	/// Get the free, reserved and frozen balances of an account.
+	#[rpc(name = "genericAsset_balanceDetailed")]
+	fn balance_detailed(&self, asset_id: AssetId, who: AccountId, at: Option<BlockHash>) -> Result<BalanceDetails>;
+
+	/// Get a page of the holders of an asset with their free balances.
+	/// At most `MAX_HOLDERS_PAGE` holders are returned at once.
+	#[rpc(name = "genericAsset_holders")]
+	fn holders(
+		&self,
+		asset_id: AssetId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<HoldersPage<AccountId>>;
+}
+
+/// The most holders returned by a single `genericAsset_holders` call.
+pub const MAX_HOLDERS_PAGE: u32 = 1_000;
+
+/// A page of asset holders, with the key to request the next page from.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HoldersPage<AccountId> {
+	/// The holders and their free balances.
+	pub holders: Vec<(AccountId, NumberOrHex)>,
+	/// The `start_key` for the next page, `None` if this is the last page.
+	pub next_key: Option<Vec<u8>>,
+}
+
+/// The balances of an account in an asset.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceDetails {
+	/// The free balance, including any frozen amount.
+	pub free: NumberOrHex,
+	/// The reserved balance.
+	pub reserved: NumberOrHex,
+	/// The amount of the free balance frozen by locks, this is the largest active lock.
+	pub frozen: NumberOrHex,
 }
 
 /// A struct that implements the [`GenericAssetApi`].
@@ -54,27 +115,217 @@ impl<C, P> GenericAsset<C, P> {
 pub enum Error {
 	/// The call to runtime failed.
 	RuntimeError,
+	/// The runtime does not have the version of the runtime api needed for the call.
+	UnsupportedRuntime,
 }
 
-impl<C, Block, AssetId> GenericAssetApi<<Block as BlockT>::Hash, Vec<(AssetId, AssetInfo)>>
-	for GenericAsset<C, (Block, AssetId)>
+impl<C, Block, AccountId, AssetId, Balance> GenericAsset<C, (Block, AccountId, AssetId, Balance)>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: AssetMetaApi<Block, AssetId, Balance, AccountId>,
+	AccountId: Decode + Encode,
+	AssetId: Decode + Encode,
+	Balance: Decode + Encode,
+{
+	/// Whether the runtime at `at` has `version` or later of the `AssetMetaApi`.
+	fn has_api_version(&self, at: &BlockId<Block>, version: u32) -> Result<bool> {
+		self.client
+			.runtime_api()
+			.has_api_with::<dyn AssetMetaApi<Block, AssetId, Balance, AccountId>, _>(at, |v| v >= version)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError as i64),
+				message: "Unable to query the runtime api version.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	/// Check that the runtime at `at` has version 2 of the `AssetMetaApi`, which added all but `asset_meta`.
+	fn ensure_api_v2(&self, at: &BlockId<Block>) -> Result<()> {
+		if self.has_api_version(at, 2)? {
+			Ok(())
+		} else {
+			Err(RpcError {
+				code: ErrorCode::ServerError(Error::UnsupportedRuntime as i64),
+				message: "The runtime at this block does not support this call.".into(),
+				data: None,
+			})
+		}
+	}
+}
+
+impl<C, Block, AccountId, AssetId, Balance>
+	GenericAssetApi<<Block as BlockT>::Hash, AccountId, AssetId, Vec<(AssetId, AssetInfo)>>
+	for GenericAsset<C, (Block, AccountId, AssetId, Balance)>
 where
 	Block: BlockT,
 	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-	C::Api: AssetMetaApi<Block, AssetId>,
+	C::Api: AssetMetaApi<Block, AssetId, Balance, AccountId>,
+	AccountId: Decode + Encode + Send + Sync + 'static,
 	AssetId: Decode + Encode + Send + Sync + 'static,
+	Balance: Decode + Encode + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
 {
 	fn asset_meta(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(AssetId, AssetInfo)>> {
 		let at = BlockId::hash(at.unwrap_or_else(||
 			// If the block hash is not supplied assume the best block.
 			self.client.info().best_hash));
 
-		self.client.runtime_api().asset_meta(&at).map_err(|e| RpcError {
+		let asset_meta = if self.has_api_version(&at, 2)? {
+			self.client.runtime_api().asset_meta(&at)
+		} else {
+			#[allow(deprecated)]
+			self.client
+				.runtime_api()
+				.asset_meta_before_version_2(&at)
+				.map(|assets| assets.into_iter().map(|(asset_id, info)| (asset_id, info.into())).collect())
+		};
+
+		asset_meta.map_err(|e| RpcError {
 			code: ErrorCode::ServerError(Error::RuntimeError as i64),
 			message: "Unable to query asset meta data.".into(),
 			data: Some(format!("{:?}", e).into()),
 		})
 	}
+
+	fn total_issuance(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.ensure_api_v2(&at)?;
+		let total_issuance = self.client.runtime_api().total_issuance(&at, asset_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError as i64),
+			message: "Unable to query total issuance.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+
+		total_issuance.try_into().map_err(|_| RpcError {
+			code: ErrorCode::InvalidParams,
+			message: format!("{} doesn't fit in NumberOrHex representation", total_issuance),
+			data: None,
+		})
+	}
+
+	fn total_dust_reclaimed(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.ensure_api_v2(&at)?;
+		let total_dust = self.client.runtime_api().total_dust_reclaimed(&at, asset_id).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError as i64),
+			message: "Unable to query total dust reclaimed.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+
+		total_dust.try_into().map_err(|_| RpcError {
+			code: ErrorCode::InvalidParams,
+			message: format!("{} doesn't fit in NumberOrHex representation", total_dust),
+			data: None,
+		})
+	}
+
+	fn transferable_balance(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		keep_alive: bool,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.ensure_api_v2(&at)?;
+		let transferable = self
+			.client
+			.runtime_api()
+			.transferable_balance(&at, asset_id, who, keep_alive)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError as i64),
+				message: "Unable to query transferable balance.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		transferable.try_into().map_err(|_| RpcError {
+			code: ErrorCode::InvalidParams,
+			message: format!("{} doesn't fit in NumberOrHex representation", transferable),
+			data: None,
+		})
+	}
+
+	fn balance_detailed(
+		&self,
+		asset_id: AssetId,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<BalanceDetails> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.ensure_api_v2(&at)?;
+		let (free, reserved, frozen) = self
+			.client
+			.runtime_api()
+			.balance_detailed(&at, asset_id, who)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError as i64),
+				message: "Unable to query balance details.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		let to_number_or_hex = |balance: Balance| {
+			balance.try_into().map_err(|_| RpcError {
+				code: ErrorCode::InvalidParams,
+				message: format!("{} doesn't fit in NumberOrHex representation", balance),
+				data: None,
+			})
+		};
+
+		Ok(BalanceDetails {
+			free: to_number_or_hex(free)?,
+			reserved: to_number_or_hex(reserved)?,
+			frozen: to_number_or_hex(frozen)?,
+		})
+	}
+
+	fn holders(
+		&self,
+		asset_id: AssetId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<HoldersPage<AccountId>> {
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		self.ensure_api_v2(&at)?;
+		let (holders, next_key) = self
+			.client
+			.runtime_api()
+			.holders(&at, asset_id, start_key, limit.min(MAX_HOLDERS_PAGE))
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError as i64),
+				message: "Unable to query asset holders.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		let holders = holders
+			.into_iter()
+			.map(|(who, balance)| {
+				let balance = balance.try_into().map_err(|_| RpcError {
+					code: ErrorCode::InvalidParams,
+					message: format!("{} doesn't fit in NumberOrHex representation", balance),
+					data: None,
+				})?;
+				Ok((who, balance))
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(HoldersPage { holders, next_key })
+	}
 }
 
 #[cfg(test)]
@@ -110,8 +361,90 @@ mod test {
 		let response = "{\"jsonrpc\":\"2.0\",\
 			\"result\":[[0,{\
 			\"decimal_places\":4,\
+			\"dust_threshold\":1,\
 			\"existential_deposit\":1,\
-			\"symbol\":[]}]],\
+			\"sufficient\":false,\
+			\"symbol\":[],\
+			\"transfer_fee\":0}]],\
+			\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_total_issuance_rpc() {
+		let handler = test_ga_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(GenericAssetApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "genericAsset_totalIssuance",
+			"params":[0]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\"result\":1000,\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_total_dust_reclaimed_rpc() {
+		let handler = test_ga_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(GenericAssetApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "genericAsset_totalDustReclaimed",
+			"params":[0]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\"result\":7,\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_transferable_rpc() {
+		let handler = test_ga_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(GenericAssetApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "genericAsset_transferable",
+			"params":[0, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", true]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\"result\":999,\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_balance_detailed_rpc() {
+		let handler = test_ga_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(GenericAssetApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "genericAsset_balanceDetailed",
+			"params":[0, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\
+			\"result\":{\"free\":1000,\"frozen\":300,\"reserved\":20},\
+			\"id\":\"1\"}";
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn working_holders_rpc() {
+		let handler = test_ga_rpc_handler();
+		let mut io = IoHandler::new();
+		io.extend_with(GenericAssetApi::to_delegate(handler));
+
+		let request = r#"{
+			"id":"1", "jsonrpc":"2.0",
+			"method": "genericAsset_holders",
+			"params":[0, [1, 2, 3], 10]}"#;
+		let response = "{\"jsonrpc\":\"2.0\",\
+			\"result\":{\"holders\":[],\"nextKey\":[1,2,3]},\
 			\"id\":\"1\"}";
 
 		assert_eq!(Some(response.into()), io.handle_request_sync(request));
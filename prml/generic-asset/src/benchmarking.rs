@@ -72,7 +72,9 @@ benchmarks! {
 			initial_issuance: initial_balance,
 			permissions,
 		};
-		let asset_info = AssetInfo::default();
+		// A sufficient asset, so it may be minted to a new account
+		let mut asset_info = AssetInfo::default();
+		asset_info.set_sufficient(true);
 		let decimal_factor: T::Balance = 10u32.pow(asset_info.decimal_places().into()).into();
 
 		let _ = GenericAsset::<T>::create(
@@ -127,7 +129,9 @@ benchmarks! {
 			initial_issuance: initial_balance,
 			permissions,
 		};
-		let asset_info = AssetInfo::default();
+		// A sufficient asset, so it may be minted to a new account
+		let mut asset_info = AssetInfo::default();
+		asset_info.set_sufficient(true);
 		let decimal_factor: T::Balance = 10u32.pow(asset_info.decimal_places().into()).into();
 
 		let _ = GenericAsset::<T>::create(
@@ -145,6 +149,42 @@ benchmarks! {
 		assert_eq!(GenericAsset::<T>::free_balance(asset_id, &mint_to.clone()), mint_amount);
 	}
 
+	// Benchmark `mint_many` over the number of recipients, each of which is a new account.
+	mint_many {
+		let n in 1 .. T::MaxMintBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let initial_balance = T::Balance::from(5_000_000u32);
+		let asset_id = GenericAsset::<T>::next_asset_id();
+		let permissions = PermissionLatest::<T::AccountId>::new(caller.clone());
+		let asset_options :AssetOptions<T::Balance, T::AccountId> = AssetOptions {
+			initial_issuance: initial_balance,
+			permissions,
+		};
+		// A sufficient asset, so it may be minted to a new account
+		let mut asset_info = AssetInfo::default();
+		asset_info.set_sufficient(true);
+		let decimal_factor: T::Balance = 10u32.pow(asset_info.decimal_places().into()).into();
+
+		let _ = GenericAsset::<T>::create(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			asset_options,
+			asset_info,
+		);
+
+		let mint_amount = T::Balance::from(1_000_000u32);
+		let recipients: Vec<(T::AccountId, T::Balance)> = (0..n)
+			.map(|i| (account("recipient", i, SEED), mint_amount))
+			.collect();
+	}: mint_many(RawOrigin::Signed(caller.clone()), asset_id, recipients.clone())
+	verify {
+		let total_issuance = initial_balance.mul(decimal_factor).add(mint_amount.mul(T::Balance::from(n)));
+		assert_eq!(GenericAsset::<T>::total_issuance(&asset_id), total_issuance);
+		for (to, amount) in recipients {
+			assert_eq!(GenericAsset::<T>::free_balance(asset_id, &to), amount);
+		}
+	}
+
 	// Benchmark `update_asset_info`, create asset from ROOT account.
 	// Update the asset info
 	update_asset_info {
@@ -180,12 +220,14 @@ benchmarks! {
 			update: Owner::Address(caller.clone()),
 			mint: Owner::None,
 			burn: Owner::None,
+			freeze: Owner::None,
 		};
 
 		let new_permission = PermissionLatest {
 			update: Owner::Address(caller.clone()),
 			mint: Owner::Address(caller.clone()),
 			burn: Owner::None,
+			freeze: Owner::None,
 		};
 		let asset_id = GenericAsset::<T>::next_asset_id();
 		let asset_options :AssetOptions<T::Balance, T::AccountId> = AssetOptions {
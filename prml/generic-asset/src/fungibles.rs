@@ -0,0 +1,289 @@
+// Copyright 2019-2021 Plug New Zealand Ltd.
+// This file is part of Plug.
+
+// Plug is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Plug is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Plug.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `fungibles` trait implementations for the `GenericAsset` module
+
+use crate::{Config, Error, Module, TotalIssuance};
+use frame_support::traits::{ExistenceRequirement, WithdrawReasons};
+use prml_support::fungibles::{DepositConsequence, Inspect, Mutate, Transfer, WithdrawConsequence};
+use sp_runtime::{
+	traits::{CheckedAdd, CheckedSub, UniqueSaturatedInto, Zero},
+	DispatchError, DispatchResult,
+};
+
+impl<T: Config> Inspect<T::AccountId> for Module<T> {
+	type AssetId = T::AssetId;
+	type Balance = T::Balance;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		<Module<T>>::total_issuance(asset)
+	}
+
+	fn minimum_balance(asset: Self::AssetId) -> Self::Balance {
+		<Module<T>>::asset_meta(asset).existential_deposit().unique_saturated_into()
+	}
+
+	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		<Module<T>>::total_balance(asset, who)
+	}
+
+	fn reducible_balance(asset: Self::AssetId, who: &T::AccountId, keep_alive: bool) -> Self::Balance {
+		<Module<T>>::transferable_balance(asset, who, keep_alive)
+	}
+
+	fn can_deposit(asset: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DepositConsequence {
+		if !<TotalIssuance<T>>::contains_key(asset) {
+			return DepositConsequence::UnknownAsset;
+		}
+		if <Module<T>>::total_issuance(asset).checked_add(&amount).is_none() {
+			return DepositConsequence::Overflow;
+		}
+		let balance = <Module<T>>::total_balance(asset, who);
+		let can_hold = <Module<T>>::ensure_can_hold(who, <Module<T>>::is_sufficient(asset), !balance.is_zero()).is_ok();
+		match balance.checked_add(&amount) {
+			None => DepositConsequence::Overflow,
+			Some(new_balance) if new_balance < Self::minimum_balance(asset) => DepositConsequence::BelowMinimum,
+			Some(_) if !can_hold => DepositConsequence::CannotCreate,
+			Some(_) => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if amount.is_zero() {
+			return WithdrawConsequence::Success;
+		}
+		if !<TotalIssuance<T>>::contains_key(asset) {
+			return WithdrawConsequence::UnknownAsset;
+		}
+		if <Module<T>>::total_issuance(asset).checked_sub(&amount).is_none() {
+			return WithdrawConsequence::Underflow;
+		}
+		<Module<T>>::withdraw_consequence(asset, who, amount, WithdrawReasons::all())
+	}
+}
+
+/// NOTE: `fungibles` traits are for runtime internal use only. Unlike the `mint` and `burn` extrinsics,
+/// these do not check the asset's `Mint` or `Burn` permissions. They do check the recipient of a mint, and
+/// the locks on a burn, as a transfer would.
+impl<T: Config> Mutate<T::AccountId> for Module<T> {
+	fn mint_into(asset: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		let holds_some = !<Module<T>>::total_balance(asset, who).is_zero();
+		let sufficient = <Module<T>>::is_sufficient(asset);
+		<Module<T>>::ensure_can_mint(who, &amount, Self::minimum_balance(asset), holds_some, sufficient)?;
+		<Module<T>>::do_mint_free(asset, who, &amount)
+	}
+
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		let new_total_issuance = <Module<T>>::total_issuance(asset)
+			.checked_sub(&amount)
+			.ok_or(Error::<T>::TotalBurningUnderflow)?;
+		let new_balance = <Module<T>>::free_balance(asset, who)
+			.checked_sub(&amount)
+			.ok_or(Error::<T>::FreeBurningUnderflow)?;
+		<Module<T>>::ensure_can_withdraw(asset, who, amount, WithdrawReasons::all(), new_balance)?;
+
+		<TotalIssuance<T>>::insert(asset, new_total_issuance);
+		<Module<T>>::set_free_balance(asset, who, new_balance);
+		if <Module<T>>::is_dust(asset, new_balance) {
+			<Module<T>>::reclaim_free_balance(asset, who, ExistenceRequirement::AllowDeath);
+		}
+		Ok(amount)
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Module<T> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		let req = if keep_alive {
+			ExistenceRequirement::KeepAlive
+		} else {
+			ExistenceRequirement::AllowDeath
+		};
+		<Module<T>>::make_transfer(asset, source, dest, amount, req)?;
+		Ok(amount)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{
+			new_test_ext_with_balance, new_test_ext_with_default, GenericAsset, GenericAssetModuleId, System, Test,
+			ALICE, BOB, ID_1, INITIAL_BALANCE, STAKING_ASSET_ID, TEST1_ASSET_ID,
+		},
+		FreeBalance,
+	};
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::traits::AccountIdConversion;
+
+	#[test]
+	fn inspect_balances() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			GenericAsset::set_reserved_balance(STAKING_ASSET_ID, &ALICE, 50);
+
+			assert_eq!(<GenericAsset as Inspect<_>>::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE);
+			assert_eq!(<GenericAsset as Inspect<_>>::minimum_balance(TEST1_ASSET_ID), 3);
+			assert_eq!(<GenericAsset as Inspect<_>>::balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE + 50);
+			assert_eq!(<GenericAsset as Inspect<_>>::balance(STAKING_ASSET_ID, &BOB), 0);
+		});
+	}
+
+	#[test]
+	fn inspect_reducible_balance() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER);
+
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::reducible_balance(STAKING_ASSET_ID, &ALICE, false),
+				INITIAL_BALANCE - 30
+			);
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::reducible_balance(STAKING_ASSET_ID, &ALICE, true),
+				INITIAL_BALANCE - 30 - 1
+			);
+		});
+	}
+
+	#[test]
+	fn inspect_can_deposit() {
+		new_test_ext_with_default().execute_with(|| {
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_deposit(STAKING_ASSET_ID + 1, &ALICE, 10),
+				DepositConsequence::UnknownAsset
+			);
+			<TotalIssuance<Test>>::insert(STAKING_ASSET_ID, u64::max_value() - 5);
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_deposit(STAKING_ASSET_ID, &ALICE, 10),
+				DepositConsequence::Overflow
+			);
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_deposit(STAKING_ASSET_ID, &ALICE, 5),
+				DepositConsequence::Success
+			);
+			// TEST1_ASSET_ID is not sufficient and BOB does not exist
+			<TotalIssuance<Test>>::insert(TEST1_ASSET_ID, 0);
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_deposit(TEST1_ASSET_ID, &BOB, 10),
+				DepositConsequence::CannotCreate
+			);
+		});
+	}
+
+	#[test]
+	fn inspect_can_withdraw() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_withdraw(STAKING_ASSET_ID + 1, &ALICE, 10),
+				WithdrawConsequence::UnknownAsset
+			);
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_withdraw(STAKING_ASSET_ID, &BOB, 10),
+				WithdrawConsequence::NoFunds
+			);
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_withdraw(STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE),
+				WithdrawConsequence::Success
+			);
+
+			GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER);
+			assert_eq!(
+				<GenericAsset as Inspect<_>>::can_withdraw(STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE - 20),
+				WithdrawConsequence::Frozen
+			);
+		});
+	}
+
+	#[test]
+	fn mutate_and_transfer_round_trip() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			// ALICE has no `Mint` or `Burn` permission, the trait path does not need it
+			assert_ok!(<GenericAsset as Mutate<_>>::mint_into(STAKING_ASSET_ID, &ALICE, 50));
+			assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE + 50);
+			assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE + 50);
+
+			assert_eq!(
+				<GenericAsset as Transfer<_>>::transfer(STAKING_ASSET_ID, &ALICE, &BOB, 70, true),
+				Ok(70)
+			);
+			assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE - 20);
+			assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 70);
+
+			assert_eq!(<GenericAsset as Mutate<_>>::burn_from(STAKING_ASSET_ID, &BOB, 70), Ok(70));
+			assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 0);
+			assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE - 20);
+		});
+	}
+
+	#[test]
+	fn burn_from_locked_funds_should_fail() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::all());
+			assert_noop!(
+				<GenericAsset as Mutate<_>>::burn_from(STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE - 20),
+				Error::<Test>::LiquidityRestrictions
+			);
+			assert_eq!(<GenericAsset as Mutate<_>>::burn_from(STAKING_ASSET_ID, &ALICE, 70), Ok(70));
+			assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), 30);
+		});
+	}
+
+	#[test]
+	fn burn_from_reclaims_the_dust_left() {
+		new_test_ext_with_balance(TEST1_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			// TEST1_ASSET_ID has an existential deposit of 3
+			assert_eq!(<GenericAsset as Mutate<_>>::burn_from(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE - 2), Ok(98));
+			assert!(!<FreeBalance<Test>>::contains_key(TEST1_ASSET_ID, &ALICE));
+			assert_eq!(GenericAsset::total_dust_reclaimed(TEST1_ASSET_ID), 2);
+			assert!(!System::account_exists(&ALICE));
+		});
+	}
+
+	#[test]
+	fn mint_into_blocked_recipient_should_fail() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			let module_account = GenericAssetModuleId::get().into_account();
+			assert_noop!(
+				<GenericAsset as Mutate<_>>::mint_into(STAKING_ASSET_ID, &module_account, 50),
+				Error::<Test>::BlockedRecipient
+			);
+			assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE);
+		});
+	}
+
+	#[test]
+	fn burn_from_more_than_free_balance_should_fail() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+			assert_noop!(
+				<GenericAsset as Mutate<_>>::burn_from(STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE + 1),
+				Error::<Test>::TotalBurningUnderflow
+			);
+		});
+	}
+}
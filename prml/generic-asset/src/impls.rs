@@ -17,13 +17,34 @@
 //! Extra trait implementations for the `GenericAsset` module
 
 use crate::{Config, Error, Module, NegativeImbalance, PositiveImbalance, SpendingAssetIdAuthority};
-use frame_support::traits::{ExistenceRequirement, Imbalance, SignedImbalance, WithdrawReasons};
+use frame_support::traits::{
+	BalanceStatus, ExistenceRequirement, Get, Imbalance, OnUnbalanced, SignedImbalance, WithdrawReasons,
+};
 use prml_support::{AssetIdAuthority, MultiCurrencyAccounting};
 use sp_runtime::{
 	traits::{CheckedSub, UniqueSaturatedInto, Zero},
-	DispatchError, DispatchResult,
+	DispatchError, DispatchResult, Perbill,
 };
-use sp_std::result;
+use sp_std::{marker::PhantomData, result};
+
+/// A dust handler which burns the `BurnRatio` portion of the dust, reducing total issuance, and passes the rest
+/// on to `Treasury`.
+pub struct SplitDust<Treasury, BurnRatio>(PhantomData<(Treasury, BurnRatio)>);
+
+impl<T, Treasury, BurnRatio> OnUnbalanced<NegativeImbalance<T>> for SplitDust<Treasury, BurnRatio>
+where
+	T: Config,
+	Treasury: OnUnbalanced<NegativeImbalance<T>>,
+	BurnRatio: Get<Perbill>,
+{
+	fn on_nonzero_unbalanced(amount: NegativeImbalance<T>) {
+		let to_burn = BurnRatio::get() * amount.peek();
+		let (burned, rest) = amount.split(to_burn);
+		// Dropping the negative imbalance reduces total issuance
+		drop(burned);
+		Treasury::on_unbalanced(rest);
+	}
+}
 
 impl<T: Config> MultiCurrencyAccounting for Module<T> {
 	type AccountId = T::AccountId;
@@ -171,6 +192,7 @@ impl<T: Config> MultiCurrencyAccounting for Module<T> {
 			who,
 			beneficiary,
 			amount,
+			BalanceStatus::Free,
 		)
 	}
 
@@ -179,7 +201,7 @@ impl<T: Config> MultiCurrencyAccounting for Module<T> {
 			return Zero::zero();
 		}
 
-		<Module<T>>::unreserve(
+		<Module<T>>::unreserve_silent(
 			currency.unwrap_or_else(|| Self::DefaultCurrencyId::asset_id()),
 			who,
 			amount,
@@ -191,11 +213,52 @@ impl<T: Config> MultiCurrencyAccounting for Module<T> {
 mod tests {
 	use super::*;
 	use crate::mock::{
-		new_test_ext_with_balance, new_test_ext_with_default, GenericAsset, Test, STAKING_ASSET_ID, TEST1_ASSET_ID,
-		TEST2_ASSET_ID,
+		new_test_ext_with_balance, new_test_ext_with_default, GenericAsset, Test, TransferImbalanceToTreasury,
+		TreasuryModuleId, STAKING_ASSET_ID, TEST1_ASSET_ID, TEST2_ASSET_ID,
 	};
-	use frame_support::{assert_noop, assert_ok};
-	use sp_runtime::traits::Zero;
+	use frame_support::{assert_noop, assert_ok, parameter_types};
+	use sp_runtime::traits::{AccountIdConversion, Zero};
+
+	parameter_types! {
+		pub const BurnNone: Perbill = Perbill::zero();
+		pub const BurnForty: Perbill = Perbill::from_percent(40);
+		pub const BurnAll: Perbill = Perbill::one();
+	}
+
+	/// Reclaim `dust` of the staking asset from `alice` through `SplitDust`, returning the treasury's share and the
+	/// total issuance afterwards
+	fn split_dust<BurnRatio: Get<Perbill>>(dust: u64) -> (u64, u64) {
+		let (alice, asset_id) = (1, STAKING_ASSET_ID);
+		GenericAsset::set_free_balance(asset_id, &alice, GenericAsset::free_balance(asset_id, &alice) - dust);
+		SplitDust::<TransferImbalanceToTreasury, BurnRatio>::on_unbalanced(NegativeImbalance::new(dust, asset_id));
+
+		let treasury_account_id = TreasuryModuleId::get().into_account();
+		(
+			GenericAsset::free_balance(asset_id, &treasury_account_id),
+			GenericAsset::total_issuance(asset_id),
+		)
+	}
+
+	#[test]
+	fn split_dust_burns_its_share_and_forwards_the_rest() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, 1, 100).execute_with(|| {
+			assert_eq!(split_dust::<BurnForty>(10), (6, 96));
+		});
+	}
+
+	#[test]
+	fn split_dust_with_no_burn_forwards_everything() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, 1, 100).execute_with(|| {
+			assert_eq!(split_dust::<BurnNone>(10), (10, 100));
+		});
+	}
+
+	#[test]
+	fn split_dust_with_full_burn_forwards_nothing() {
+		new_test_ext_with_balance(STAKING_ASSET_ID, 1, 100).execute_with(|| {
+			assert_eq!(split_dust::<BurnAll>(10), (0, 90));
+		});
+	}
 
 	#[test]
 	fn multi_accounting_minimum_balance() {
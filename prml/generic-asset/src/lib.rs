@@ -46,6 +46,10 @@
 //! can still be slashed, but only after all the free balance has been slashed. If the reserved balance falls below the
 //! existential deposit then it and any related functionality will be deleted. When both it and the free balance are
 //! deleted, then the account is said to be dead.
+//! - **Sufficient Asset:** An asset meant to be held without any of the native assets. The staking and spending assets
+//! are always sufficient, other assets are when their `AssetInfo` says so. An account is kept alive while it holds a
+//! balance of any sufficient asset. Other assets cannot keep an account alive, so they are only credited to accounts
+//! which already exist.
 //! - **Imbalance:** A condition when some assets were credited or debited without equal and opposite accounting
 //! (i.e. a difference between total issuance and account balances). Functions that result in an imbalance will
 //! return an object of the `Imbalance` trait that can be managed within your runtime logic. (If an imbalance is
@@ -61,9 +65,17 @@
 //! - `Currency`: Functions for dealing with a fungible assets system.
 //! - `ReservableCurrency`: Functions for dealing with assets that can be reserved from an account.
 //! - `LockableCurrency`: Functions for dealing with accounts that allow liquidity restrictions.
+//! - `NamedReservableCurrency`: As `ReservableCurrency`, with reserves held under distinct `ReserveId`s, from
+//! `prml_support`.
 //! - `Imbalance`: Functions for handling imbalances between total issuance in the system and account balances.
 //! Must be used when a function creates new assets (e.g. a reward) or destroys some assets (e.g. a system fee).
 //!
+//! The Generic Asset module itself implements the multi-asset `fungibles::{Inspect, Mutate, Transfer}` traits from
+//! `prml_support`.
+//!
+//! `SplitDust` may be used as the `OnDustImbalance` handler to burn a fixed portion of reclaimed dust and pass
+//! the rest on to another handler, such as a treasury.
+//!
 //! The Generic Asset module provides two types of `AssetCurrency` as follows.
 //!
 //! - `StakingAssetCurrency`: Currency for staking.
@@ -75,34 +87,68 @@
 //!
 //! - `create`: Create a new kind of asset and nominates the owner of this asset. The origin of this call must
 //! be root.
+//! - `create_with_endowment`: As `create`, but also crediting the free balances of a list of endowed accounts.
 //! - `transfer`: Transfer some liquid free balance to another account.
+//! - `transfer_by_symbol`: As `transfer`, identifying the asset by its symbol.
 //! - `update_permission`: Updates permission for a given `asset_id` and an account. The origin of this call
 //! must have update permissions.
+//! - `transfer_ownership`: Moves all permissions for a given `asset_id` to a new owner. The origin of this call
+//! must have update permissions.
+//! - `set_existential_deposit`: Updates the existential deposit of an asset. The origin of this call must be root
+//! or have update permissions.
 //! - `mint`: Mint an asset, increases its total issuance. The origin of this call must have mint permissions.
+//! - `mint_many`: Mint an asset to many accounts at once. The origin of this call must have mint permissions.
 //! - `burn`: Burn an asset, decreases its total issuance. The origin of this call must have burn permissions.
+//! - `burn_self`: Burn an asset from the origin's own free balance, decreases its total issuance.
 //! - `create_reserved`: Create a new kind of reserved asset. The origin of this call must be root.
+//! - `set_balance`: Set the free and reserved balances of an account, adjusting the total issuance. The origin of
+//! this call must be root.
 //!
 //! ### Public Functions
 //!
 //! - `total_balance`: Get an account's total balance of an asset kind.
 //! - `free_balance`: Get an account's free balance of an asset kind.
 //! - `reserved_balance`: Get an account's reserved balance of an asset kind.
+//! - `asset_exists_for`: Check whether an account holds any of an asset kind, ignoring dust.
+//! - `is_dust`: Check whether a free balance is below the asset's dust threshold and would be reclaimed.
+//! - `is_sufficient`: Check whether an asset kind is meant to be held on its own, i.e. it is native or sufficient.
+//! - `holds_any_asset`: Check whether an account has a free or reserved balance of any asset.
+//! - `total_dust_reclaimed`: Get the total amount of an asset kind ever reclaimed as dust.
+//! - `blocking_lock`: Get the id of the lock stopping a withdrawal, if any.
+//! - `transferable_balance`: Get the most an account can transfer of an asset kind, accounting for locks and
+//! optionally the existential deposit.
 //! - `create_asset`: Creates an asset.
+//! - `create_asset_with_endowment`: Creates an asset, minting an initial distribution to the endowed accounts.
 //! - `make_transfer`: Transfer some liquid free balance from one account to another.
 //! This will not emit the `Transferred` event.
-//! - `make_transfer_with_event`: Transfer some liquid free balance from one account to another.
-//! This will emit the `Transferred` event.
+//! - `make_transfer_with_event`: Transfer some liquid free balance from one account to another, burning the
+//! asset's transfer fee from the sender. This will emit the `Transferred` event.
 //! - `reserve`: Moves an amount from free balance to reserved balance.
-//! - `unreserve`: Move up to an amount from reserved balance to free balance. This function cannot fail.
+//! - `unreserve`: Move up to an amount from reserved balance to free balance, emitting the `Unreserved` event.
+//! This function cannot fail.
+//! - `unreserve_silent`: As `unreserve`, without emitting an event.
+//! - `reserve_with_event`: As `reserve`, but emitting the `Reserved` event.
+//! - `reserve_named`, `unreserve_named`, `slash_reserved_named`, `repatriate_reserved_named`: As above, but only
+//! touching the portion of the reserved balance held under a given `ReserveId`.
 //! - `mint_free`: Mint to an account's free balance.
+//! - `mint_reserved`: Mint to an account's reserved balance.
 //! - `burn_free`: Burn an account's free balance.
 //! - `slash`: Deduct up to an amount from the combined balance of `who`, preferring to deduct from the
 //!	free balance. This function cannot fail.
+//! - `slash_and_settle`: As `slash`, but returning a `NegativeImbalance` which maintains total issuance.
 //! - `slash_reserved`: Deduct up to an amount from reserved balance of an account. This function cannot fail.
 //! - `repatriate_reserved`: Move up to an amount from reserved balance of an account to free balance of another
 //! account.
-//! - `check_permission`: Check permission to perform burn, mint or update.
+//! - `increase_balance`, `decrease_balance`: Mint to or burn from an account's free balance, clamping the amount
+//! rather than failing.
+//! - `holders`: Page through the accounts holding a free balance of an asset kind.
+//! - `id_for_symbol`: Look up the asset with a given symbol. Asset symbols are unique.
+//! - `check_permission`: Check permission to perform burn, mint, update or freeze.
 //! - `ensure_can_withdraw`: Check if the account is able to make a withdrawal of the given amount
+//!	for the given reason. Expired locks are ignored.
+//! - `set_lock_until`: Set a lock on an account's free balance which is released at the given block.
+//! - `active_locks`: Get the locks of an account which have not yet expired.
+//! - `withdraw_consequence`: Get the consequence of the account making a withdrawal of the given amount
 //!	for the given reason.
 //!
 //! ### Usage
@@ -154,26 +200,30 @@
 use codec::{Codec, Decode, Encode, FullCodec};
 
 use sp_runtime::traits::{
-	AtLeast32BitUnsigned, Bounded, CheckedAdd, CheckedSub, MaybeSerializeDeserialize, Member, One, UniqueSaturatedInto,
-	Zero,
+	AtLeast32BitUnsigned, Bounded, CheckedAdd, CheckedSub, MaybeSerializeDeserialize, Member, One, Saturating,
+	UniqueSaturatedInto, Zero,
 };
 use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug, SaturatedConversion};
 
 use frame_support::{
 	decl_error, decl_event, decl_module, decl_storage, ensure,
 	traits::{
-		BalanceStatus, Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
-		ReservableCurrency, SignedImbalance, WithdrawReasons,
+		BalanceStatus, Contains, Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
+		OnKilledAccount, ReservableCurrency, SignedImbalance, WithdrawReasons,
 	},
-	IterableStorageDoubleMap, IterableStorageMap, Parameter, StorageMap,
+	storage::{unhashed, StoragePrefixedMap},
+	weights::Weight,
+	Blake2_128Concat, IterableStorageDoubleMap, IterableStorageMap, Parameter, ReversibleStorageHasher, StorageHasher,
+	StorageMap, Twox64Concat,
 };
 use frame_system::{ensure_root, ensure_signed};
-use prml_support::AssetIdAuthority;
+use prml_support::{fungibles::WithdrawConsequence, AssetIdAuthority, NamedReservableCurrency};
 use sp_runtime::traits::CheckedMul;
 use sp_std::prelude::*;
-use sp_std::{cmp, fmt::Debug, result};
+use sp_std::{cmp, collections::btree_set::BTreeSet, fmt::Debug, result};
 
 mod benchmarking;
+mod fungibles;
 mod imbalances;
 mod impls;
 mod mock;
@@ -183,6 +233,7 @@ mod weights;
 
 // Export GA types/traits
 pub use self::imbalances::{CheckedImbalance, NegativeImbalance, OffsetResult, PositiveImbalance};
+pub use self::impls::SplitDust;
 use frame_support::traits::OnUnbalanced;
 pub use types::*;
 use weights::WeightInfo;
@@ -205,6 +256,26 @@ pub trait Config: frame_system::Config {
 	/// The type that handles the imbalance of dust cleaning.
 	type OnDustImbalance: OnUnbalanced<NegativeImbalance<Self>>;
 
+	/// The maximum length of an asset symbol, in bytes.
+	type MaxSymbolLength: Get<u32>;
+
+	/// The maximum number of recipients in a single `mint_many` call.
+	type MaxMintBatch: Get<u32>;
+
+	/// The maximum length of a `transfer_with_memo` memo, in bytes.
+	type MaxMemoLength: Get<u32>;
+
+	/// The maximum number of distinct locks on an account's balance of a single asset, as enforced by
+	/// `try_set_lock`. The infallible lock setters exceed it with a warning rather than drop a lock.
+	type MaxLocks: Get<u32>;
+
+	/// Handler called whenever a new asset is created.
+	type OnNewAsset: OnNewAsset<Self::AssetId>;
+
+	/// Accounts that can never receive a transfer, e.g. module accounts where transferred funds would be lost.
+	/// Use `NoBlockedRecipients` to allow transfers to any account.
+	type BlockedRecipients: Contains<Self::AccountId>;
+
 	/// Weight information for extrinsics in this module.
 	type WeightInfo: WeightInfo;
 }
@@ -248,6 +319,30 @@ decl_error! {
 		DecimalTooLarge,
 		/// The integer for initial issuance is too large for conversion into u128.
 		InitialIssuanceTooLarge,
+		/// Reserved balance got overflowed after minting.
+		ReservedMintingOverflow,
+		/// The sum of the initial issuance and the endowed balances overflows
+		EndowmentOverflow,
+		/// The asset symbol is longer than `MaxSymbolLength`
+		SymbolTooLong,
+		/// The asset symbol contains non-ASCII bytes
+		InvalidSymbol,
+		/// More recipients than `MaxMintBatch` were given
+		TooManyRecipients,
+		/// The transfer memo is longer than `MaxMemoLength`
+		MemoTooLong,
+		/// Minting less than the existential deposit into an account which holds none of the asset
+		BelowExistentialDeposit,
+		/// The account already holds `MaxLocks` locks of the asset
+		TooManyLocks,
+		/// The asset symbol is already used by another asset
+		SymbolTaken,
+		/// The recipient is blocked from receiving transfers.
+		BlockedRecipient,
+		/// The asset is not sufficient and the recipient does not exist, so could not be kept alive.
+		NoProvider,
+		/// The reserved balance would be less than the portions of it held under named reserves.
+		ReservedBelowNamedReserves,
 	}
 }
 
@@ -255,6 +350,18 @@ decl_module! {
 	pub struct Module<T: Config> for enum Call where origin: T::Origin {
 		type Error = Error<T>;
 
+		/// The maximum length of an asset symbol, in bytes.
+		const MaxSymbolLength: u32 = T::MaxSymbolLength::get();
+
+		/// The maximum number of recipients in a single `mint_many` call.
+		const MaxMintBatch: u32 = T::MaxMintBatch::get();
+
+		/// The maximum length of a `transfer_with_memo` memo, in bytes.
+		const MaxMemoLength: u32 = T::MaxMemoLength::get();
+
+		/// The maximum number of distinct locks on an account's balance of a single asset.
+		const MaxLocks: u32 = T::MaxLocks::get();
+
 		fn deposit_event() = default;
 
 		/// Create a new kind of asset and nominates the owner of this asset.
@@ -278,10 +385,34 @@ decl_module! {
 			Self::create_asset(None, Some(owner), options, info)
 		}
 
+		/// Create a new kind of asset as `create` does, additionally crediting the free balance of each
+		/// `endowed` account and adding it to the total issuance.
+		/// A `Minted` event is emitted for each endowed account.
+		/// origin of this call must be root.
+		///
+		/// Weights:
+		/// O(E) where E is the number of endowed accounts.
+		/// Should not be called often.
+		#[weight = T::WeightInfo::create()
+			.saturating_add(T::WeightInfo::mint().saturating_mul(endowed.len() as Weight))]
+		fn create_with_endowment(
+			origin,
+			owner: T::AccountId,
+			options: AssetOptions<T::Balance, T::AccountId>,
+			info: AssetInfo,
+			endowed: Vec<(T::AccountId, T::Balance)>,
+		) -> DispatchResult {
+			if let Err(_) = ensure_signed(origin.clone()) {
+				ensure_root(origin)?;
+			}
+			Self::create_asset_with_endowment(None, Some(owner), options, info, endowed)
+		}
+
 		/// Transfer some liquid free balance to another account.
 		///
 		/// `transfer` will set the `FreeBalance` of the sender and receiver.
-		/// It will decrease the total issuance of the system by the `TransferFee`.
+		/// It will decrease the total issuance of the system by the asset's transfer fee, which is burned
+		/// from the sender.
 		/// If the sender's account is below the existential deposit as a result
 		/// of the transfer, the account will be reaped.
 		///
@@ -301,10 +432,38 @@ decl_module! {
 		}
 
 		/// Transfer all of the free balance of `asset_id` to another account.
+		/// The asset's transfer fee is kept back from the amount transferred.
 		#[weight = T::WeightInfo::transfer()]
 		pub fn transfer_all(origin, #[compact] asset_id: T::AssetId, to: T::AccountId) {
 			let origin = ensure_signed(origin)?;
-			Self::make_transfer_with_event(asset_id, &origin, &to, Self::free_balance(asset_id, &origin), ExistenceRequirement::AllowDeath)?;
+			let amount = Self::free_balance(asset_id, &origin).saturating_sub(Self::transfer_fee(asset_id));
+			Self::make_transfer_with_event(asset_id, &origin, &to, amount, ExistenceRequirement::AllowDeath)?;
+		}
+
+		/// Transfer as `transfer` does, attaching a memo of at most `MaxMemoLength` bytes.
+		/// The memo is only emitted in the `TransferredWithMemo` event, it is not stored.
+		#[weight = T::WeightInfo::transfer()]
+		pub fn transfer_with_memo(
+			origin,
+			#[compact] asset_id: T::AssetId,
+			to: T::AccountId,
+			#[compact] amount: T::Balance,
+			memo: Vec<u8>,
+		) {
+			let origin = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(memo.len() <= T::MaxMemoLength::get() as usize, Error::<T>::MemoTooLong);
+			Self::make_transfer_with_event(asset_id, &origin, &to, amount, ExistenceRequirement::AllowDeath)?;
+			Self::deposit_event(Event::<T>::TransferredWithMemo(asset_id, origin, to, amount, memo));
+		}
+
+		/// Transfer as `transfer` does, identifying the asset by its symbol rather than its id.
+		#[weight = T::WeightInfo::transfer()]
+		pub fn transfer_by_symbol(origin, symbol: Vec<u8>, to: T::AccountId, #[compact] amount: T::Balance) {
+			let origin = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let asset_id = Self::id_for_symbol(&symbol).ok_or(Error::<T>::AssetIdNotExist)?;
+			Self::make_transfer_with_event(asset_id, &origin, &to, amount, ExistenceRequirement::AllowDeath)?;
 		}
 
 		/// Updates permissions(mint/burn/change permission) for a given `asset_id` and an account.
@@ -335,6 +494,35 @@ decl_module! {
 			}
 		}
 
+		/// Transfers all permissions(mint/burn/change permission) for a given `asset_id` to `new_owner`.
+		///
+		/// The `origin` must have `update` permission.
+		///
+		/// weights:
+		/// O(1) limited number of read and writes
+		/// Expected to not be called frequently
+		#[weight = T::WeightInfo::update_permission()]
+		fn transfer_ownership(
+			origin,
+			#[compact] asset_id: T::AssetId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			if !Self::check_permission(asset_id, &origin, &PermissionType::Update) {
+				Err(Error::<T>::NoUpdatePermission)?
+			}
+
+			let permissions = PermissionLatest::new(new_owner.clone());
+			let versions: PermissionVersions<T::AccountId> = permissions.clone().into();
+			<Permissions<T>>::insert(asset_id, &versions);
+
+			Self::deposit_event(Event::<T>::PermissionUpdated(asset_id, permissions));
+			Self::deposit_event(Event::<T>::OwnershipTransferred(asset_id, origin, new_owner));
+
+			Ok(())
+		}
+
 		/// Updates asset info for a given `asset_id`.
 		///
 		/// The `origin` must have `update` permission.
@@ -354,6 +542,45 @@ decl_module! {
 				Err(Error::<T>::NoUpdatePermission)?
 			}
 
+			Self::ensure_valid_symbol(&info)?;
+			Self::ensure_symbol_available(asset_id, info.symbol())?;
+
+			let old_symbol = Self::asset_meta(asset_id).symbol().to_vec();
+			if old_symbol != info.symbol() {
+				if Self::id_for_symbol(&old_symbol) == Some(asset_id) {
+					<SymbolToId<T>>::remove(old_symbol);
+				}
+				Self::index_symbol(asset_id, info.symbol());
+			}
+			<AssetMeta<T>>::insert(asset_id, info.clone());
+
+			Self::deposit_event(Event::<T>::AssetInfoUpdated(asset_id, info));
+
+			Ok(())
+		}
+
+		/// Updates the existential deposit for a given `asset_id`.
+		///
+		/// The `origin` must be root or have `update` permission.
+		///
+		/// weights:
+		/// O(1) limited number of read and writes
+		/// Expected to not be called frequently
+		#[weight = T::WeightInfo::update_asset_info()]
+		fn set_existential_deposit(origin, #[compact] asset_id: T::AssetId, new_ed: u64) -> DispatchResult {
+			if let Ok(who) = ensure_signed(origin.clone()) {
+				ensure!(
+					Self::check_permission(asset_id, &who, &PermissionType::Update),
+					Error::<T>::NoUpdatePermission
+				);
+			} else {
+				ensure_root(origin)?;
+			}
+			ensure!(<TotalIssuance<T>>::contains_key(asset_id), Error::<T>::AssetIdNotExist);
+			ensure!(!new_ed.is_zero(), Error::<T>::ZeroExistentialDeposit);
+
+			let mut info = Self::asset_meta(asset_id);
+			info.set_existential_deposit(new_ed);
 			<AssetMeta<T>>::insert(asset_id, info.clone());
 
 			Self::deposit_event(Event::<T>::AssetInfoUpdated(asset_id, info));
@@ -363,14 +590,61 @@ decl_module! {
 
 		/// Mints an asset, increases its total issuance. Deposits the newly minted currency into target account
 		/// The origin must have `mint` permissions.
+		/// An account which holds none of the asset must be minted at least the existential deposit.
 		///
 		/// Weights:
 		/// O(1) limited number of read/writes
 		#[weight = T::WeightInfo::mint()]
 		fn mint(origin, #[compact] asset_id: T::AssetId, to: T::AccountId, amount: T::Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::mint_free(asset_id, &who, &to, &amount)?;
+			Self::mint_free(asset_id, &who, &to, &amount, false)?;
 			Self::deposit_event(Event::<T>::Minted(asset_id, to, amount));
+			Self::deposit_event(Event::<T>::IssuanceChanged(asset_id, Self::total_issuance(asset_id)));
+			Ok(())
+		}
+
+		/// Mints an asset to many accounts, increasing its total issuance by the sum of the amounts.
+		/// The origin must have `mint` permissions.
+		///
+		/// Weights:
+		/// O(R) where R is the number of recipients, bounded by `MaxMintBatch`.
+		/// Charged as a full `mint` per recipient, which overestimates the shared permission and
+		/// issuance checks, as `mint_many` has a benchmark but no generated weight yet.
+		#[weight = T::WeightInfo::mint().saturating_mul(recipients.len().max(1) as Weight)]
+		fn mint_many(
+			origin,
+			#[compact] asset_id: T::AssetId,
+			recipients: Vec<(T::AccountId, T::Balance)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				recipients.len() <= T::MaxMintBatch::get() as usize,
+				Error::<T>::TooManyRecipients
+			);
+			ensure!(
+				Self::check_permission(asset_id, &who, &PermissionType::Mint),
+				Error::<T>::NoMintPermission
+			);
+			// Check the summed total up front so that no recipient is credited if it overflows
+			recipients
+				.iter()
+				.try_fold(Self::total_issuance(asset_id), |total, (_, amount)| total.checked_add(amount))
+				.ok_or(Error::<T>::TotalMintingOverflow)?;
+			// Likewise check each recipient, which holds some of the asset once credited earlier in the batch
+			let existential_deposit = Self::asset_meta(asset_id).existential_deposit().saturated_into();
+			let sufficient = Self::is_sufficient(asset_id);
+			let mut credited = BTreeSet::new();
+			for (to, amount) in recipients.iter() {
+				let holds_some = credited.contains(to) || !Self::total_balance(asset_id, to).is_zero();
+				Self::ensure_can_mint(to, amount, existential_deposit, holds_some, sufficient)?;
+				credited.insert(to);
+			}
+
+			for (to, amount) in recipients {
+				Self::do_mint_free(asset_id, &to, &amount)?;
+				Self::deposit_event(Event::<T>::Minted(asset_id, to, amount));
+			}
+			Self::deposit_event(Event::<T>::IssuanceChanged(asset_id, Self::total_issuance(asset_id)));
 			Ok(())
 		}
 
@@ -384,6 +658,82 @@ decl_module! {
 			let who = ensure_signed(origin)?;
 			Self::burn_free(asset_id, &who, &target, &amount)?;
 			Self::deposit_event(Event::<T>::Burned(asset_id, target, amount));
+			Self::deposit_event(Event::<T>::IssuanceChanged(asset_id, Self::total_issuance(asset_id)));
+			Ok(())
+		}
+
+		/// Burns an asset from the origin's own free balance, decreases its total issuance.
+		/// No `burn` permission is required as only the origin's funds are affected.
+		///
+		/// Weights:
+		/// O(1) Limited number of reads/writes.
+		#[weight = T::WeightInfo::burn()]
+		pub fn burn_self(origin, #[compact] asset_id: T::AssetId, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let new_free_balance = Self::free_balance(asset_id, &who)
+				.checked_sub(&amount)
+				.ok_or(Error::<T>::InsufficientBalance)?;
+			let new_total_issuance = Self::total_issuance(asset_id)
+				.checked_sub(&amount)
+				.ok_or(Error::<T>::TotalBurningUnderflow)?;
+			Self::ensure_can_withdraw(asset_id, &who, amount, WithdrawReasons::all(), new_free_balance)?;
+
+			<TotalIssuance<T>>::insert(asset_id, new_total_issuance);
+			Self::set_free_balance(asset_id, &who, new_free_balance);
+			if Self::is_dust(asset_id, new_free_balance) {
+				Self::reclaim_free_balance(asset_id, &who, ExistenceRequirement::AllowDeath);
+			}
+
+			Self::deposit_event(Event::<T>::Burned(asset_id, who, amount));
+			Self::deposit_event(Event::<T>::IssuanceChanged(asset_id, Self::total_issuance(asset_id)));
+			Ok(())
+		}
+
+		/// Set the free and reserved balances of `who`, adjusting the total issuance by the difference.
+		/// A free balance below the dust threshold is set to zero. The reserved balance may not be set below
+		/// the sum of `who`'s named reserves.
+		/// Requires Root call.
+		///
+		/// Weights:
+		/// O(1) Limited read/writes
+		#[weight = T::WeightInfo::mint()]
+		fn set_balance(
+			origin,
+			#[compact] asset_id: T::AssetId,
+			who: T::AccountId,
+			#[compact] free: T::Balance,
+			#[compact] reserved: T::Balance,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(<TotalIssuance<T>>::contains_key(asset_id), Error::<T>::AssetIdNotExist);
+
+			let named_reserved = Self::named_reserves(asset_id, &who)
+				.into_iter()
+				.fold(T::Balance::zero(), |total, (_, reserved)| total.saturating_add(reserved));
+			ensure!(reserved >= named_reserved, Error::<T>::ReservedBelowNamedReserves);
+
+			let free = if Self::is_dust(asset_id, free) { Zero::zero() } else { free };
+			let new_total = free.checked_add(&reserved).ok_or(Error::<T>::TotalMintingOverflow)?;
+			let old_total = Self::total_balance(asset_id, &who);
+			let new_total_issuance = if new_total > old_total {
+				Self::total_issuance(asset_id)
+					.checked_add(&(new_total - old_total))
+					.ok_or(Error::<T>::TotalMintingOverflow)?
+			} else {
+				Self::total_issuance(asset_id)
+					.checked_sub(&(old_total - new_total))
+					.ok_or(Error::<T>::TotalBurningUnderflow)?
+			};
+
+			<TotalIssuance<T>>::insert(asset_id, new_total_issuance);
+			Self::set_free_balance(asset_id, &who, free);
+			Self::set_reserved_balance(asset_id, &who, reserved);
+
+			Self::deposit_event(Event::<T>::BalanceSet(asset_id, who, free, reserved));
+			if new_total != old_total {
+				Self::deposit_event(Event::<T>::IssuanceChanged(asset_id, new_total_issuance));
+			}
 			Ok(())
 		}
 
@@ -406,25 +756,34 @@ decl_module! {
 
 		/// On runtime upgrade, update account data for existing accounts and remove dust balances
 		fn on_runtime_upgrade() -> frame_support::weights::Weight {
-			if StorageVersion::get() == Releases::V0 as u32 {
-				StorageVersion::put(Releases::V1 as u32);
+			let version = StorageVersion::get();
+			if version >= Releases::V7 as u32 {
+				return Zero::zero();
+			}
+			StorageVersion::put(Releases::V7 as u32);
+
+			// Asset info is migrated first, as the V0 migration and the symbol index read it
+			if version < Releases::V6 as u32 {
+				migrate_asset_info::<T>(version);
+			}
+			if version < Releases::V5 as u32 {
+				migrate_symbol_index::<T>();
+			}
 
+			if version == Releases::V0 as u32 {
+				// Locks are migrated straight into the latest format
 				migrate_locks::<T>();
 
 				// For each (asset, account)
 				// 1) release reserved balance storage if 0
-				// 2) release free balance storage if < ED
+				// 2) release free balance storage if below the dust threshold
 				AssetMeta::<T>::iter().for_each(|(asset_id, asset_meta)| {
 					let mut total_dust_imbalance = NegativeImbalance::new(Zero::zero(), asset_id);
 					<FreeBalance<T>>::iter_prefix(asset_id)
 						.filter_map(|(account_id, balance)| {
-							if balance < asset_meta.existential_deposit().saturated_into() {
+							if balance < asset_meta.dust_threshold().saturated_into() {
 								Some(account_id)
 							} else {
-								// set provider = 1
-								if <frame_system::Module<T>>::providers(&account_id).is_zero() {
-									<frame_system::Module<T>>::inc_providers(&account_id);
-								}
 								None
 							}
 						})
@@ -436,13 +795,20 @@ decl_module! {
 						});
 
 					if total_dust_imbalance.peek() > Zero::zero() {
+						Self::note_dust_reclaimed(asset_id, total_dust_imbalance.peek());
 						T::OnDustImbalance::on_nonzero_unbalanced(total_dust_imbalance);
 					}
 				});
+			} else if version == Releases::V1 as u32 {
+				migrate_lock_expiry::<T>();
+			}
+			let weight = migrate_held_assets::<T>();
 
-				T::BlockWeights::get().max_block
+			// The migrations of older versions are not metered, so they take the whole block
+			if version < Releases::V6 as u32 {
+				weight.max(T::BlockWeights::get().max_block)
 			} else {
-				Zero::zero()
+				weight
 			}
 		}
 	}
@@ -456,11 +822,23 @@ enum Releases {
 	V0 = 0,
 	/// Storage version after Plug 3.0.0 is adopted.
 	V1 = 1,
+	/// Storage version after balance locks can expire.
+	V2 = 2,
+	/// Storage version after asset info has a dust threshold.
+	V3 = 3,
+	/// Storage version after asset info has a transfer fee.
+	V4 = 4,
+	/// Storage version after assets are indexed by symbol.
+	V5 = 5,
+	/// Storage version after asset info has a sufficient flag.
+	V6 = 6,
+	/// Storage version after accounts index the assets they hold.
+	V7 = 7,
 }
 
 impl Default for Releases {
 	fn default() -> Self {
-		Releases::V1
+		Releases::V7
 	}
 }
 
@@ -470,7 +848,9 @@ decl_storage! {
 		///
 		/// TWOX-NOTE: `AssetId` is trusted.
 		pub TotalIssuance get(fn total_issuance) build(|config: &GenesisConfig<T>| {
-			let issuance = config.initial_balance * (config.endowed_accounts.len() as u32).into();
+			let issuance = config.initial_balance
+				.checked_mul(&(config.endowed_accounts.len() as u32).into())
+				.expect("Genesis issuance of `initial_balance` for every endowed account overflows the balance type");
 			config.assets.iter().map(|id| (id.clone(), issuance)).collect::<Vec<_>>()
 		}): map hasher(twox_64_concat) T::AssetId => T::Balance;
 
@@ -495,12 +875,16 @@ decl_storage! {
 		pub Permissions get(fn get_permission) build(|config: &GenesisConfig<T>| {
 			config.permissions
 				.iter()
-				.map(|(asset, owner)| (*asset, PermissionsV1::new(owner.clone()).into())).collect::<Vec<_>>()
+				.map(|(asset, owner)| (*asset, PermissionLatest::new(owner.clone()).into())).collect::<Vec<_>>()
 		}): map hasher(twox_64_concat) T::AssetId => PermissionVersions<T::AccountId>;
 
+		/// Portions of an account's reserved balance held under a `ReserveId`.
+		pub NamedReserves get(fn named_reserves):
+			double_map hasher(twox_64_concat) T::AssetId, hasher(blake2_128_concat) T::AccountId => Vec<(ReserveId, T::Balance)>;
+
 		/// Any liquidity locks on some account balances.
 		pub Locks get(fn locks):
-			double_map hasher(twox_64_concat) T::AssetId, hasher(blake2_128_concat) T::AccountId => Vec<BalanceLock<T::Balance>>;
+			double_map hasher(twox_64_concat) T::AssetId, hasher(blake2_128_concat) T::AccountId => Vec<BalanceLock<T::Balance, T::BlockNumber>>;
 
 		/// The identity of the asset which is the one that is designated for the chain's staking system.
 		pub StakingAssetId get(fn staking_asset_id) config(): T::AssetId;
@@ -511,10 +895,31 @@ decl_storage! {
 		/// The info for assets
 		pub AssetMeta get(fn asset_meta) config(): map hasher(twox_64_concat) T::AssetId => AssetInfo;
 
+		/// The total amount of an asset ever reclaimed as dust.
+		///
+		/// TWOX-NOTE: `AssetId` is trusted.
+		pub TotalDustReclaimed get(fn total_dust_reclaimed):
+			map hasher(twox_64_concat) T::AssetId => T::Balance;
+
+		/// The asset with a given symbol. Assets with an empty symbol are not indexed.
+		pub SymbolToId: map hasher(blake2_128_concat) Vec<u8> => Option<T::AssetId>;
+
+		/// The assets in which an account has a free or reserved balance, and whether each holding provides for the
+		/// account. A holding provides for the account if the asset was sufficient when the holding began.
+		pub HeldAssets:
+			double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) T::AssetId => Option<bool>;
+
+		/// The number of assets held by an account which provide for it.
+		pub SufficientAssetsHeld get(fn sufficient_assets_held):
+			map hasher(blake2_128_concat) T::AccountId => u32;
+
+		/// Whether GenericAsset holds a provider reference on an account.
+		pub ProvidedAccounts: map hasher(blake2_128_concat) T::AccountId => bool;
+
 		/// Storage version of the pallet.
 		///
-		/// This is set to v1 for new networks.
-		StorageVersion build(|_: &GenesisConfig<T>| Releases::V0 as u32): u32;
+		/// This is set to the latest version for new networks, so no migration ever runs on them.
+		StorageVersion build(|_: &GenesisConfig<T>| Releases::default() as u32): u32;
 	}
 	add_extra_genesis {
 		config(assets): Vec<T::AssetId>;
@@ -524,35 +929,113 @@ decl_storage! {
 
 		build(|config: &GenesisConfig<T>| {
 			config.assets.iter().for_each(|asset_id| {
-				<AssetMeta<T>>::insert(asset_id, <AssetInfo>::default());
+				// Genesis assets are sufficient, so their endowed accounts exist
+				<AssetMeta<T>>::mutate(asset_id, |info| info.set_sufficient(true));
 				config.endowed_accounts.iter().for_each(|account_id| {
 					Module::<T>::set_free_balance(*asset_id, account_id, config.initial_balance);
 				});
 			});
+			<AssetMeta<T>>::iter().for_each(|(asset_id, info)| {
+				assert!(
+					Module::<T>::id_for_symbol(info.symbol()).is_none(),
+					"Genesis assets must have unique symbols"
+				);
+				Module::<T>::index_symbol(asset_id, info.symbol());
+			});
 		});
 	}
 }
 
+/// Existing assets have no transfer fee, and a dust threshold of their existential deposit if they predate it.
+/// They are all sufficient, as any asset could be held on its own before the flag was introduced.
+fn migrate_asset_info<T: Config>(version: u32) {
+	let sufficient = |mut info: AssetInfo| {
+		info.set_sufficient(true);
+		Some(info)
+	};
+	if version < Releases::V3 as u32 {
+		<AssetMeta<T>>::translate::<AssetInfoV0, _>(|_, info| sufficient(info.into()));
+	} else if version < Releases::V4 as u32 {
+		<AssetMeta<T>>::translate::<AssetInfoV3, _>(|_, info| sufficient(info.into()));
+	} else {
+		<AssetMeta<T>>::translate::<AssetInfoV4, _>(|_, info| sufficient(info.into()));
+	}
+}
+
+/// Index the symbols of existing assets. Where several assets share a symbol, the lowest asset id is indexed.
+fn migrate_symbol_index<T: Config>() {
+	<AssetMeta<T>>::iter().for_each(|(asset_id, info)| {
+		if info.symbol().is_empty() {
+			return;
+		}
+		<SymbolToId<T>>::mutate(info.symbol(), |indexed| {
+			if indexed.map_or(true, |indexed_id| asset_id < indexed_id) {
+				*indexed = Some(asset_id);
+			}
+		});
+	});
+}
+
+/// Index the assets held by existing accounts. GenericAsset provided for an account holding an asset whenever
+/// nothing else did, so an existing provider reference on a holder of a sufficient asset is adopted as its own.
+/// Returns the weight of the reads and writes made for every holding.
+fn migrate_held_assets<T: Config>() -> Weight {
+	// The balances are iterated in place, only storage under other prefixes is written
+	let holdings = <FreeBalance<T>>::iter()
+		.map(|(asset_id, who, _)| (asset_id, who))
+		.chain(<ReservedBalance<T>>::iter().map(|(asset_id, who, _)| (asset_id, who)));
+	let mut migrated: Weight = 0;
+	for (asset_id, who) in holdings {
+		migrated = migrated.saturating_add(1);
+		let held = <HeldAssets<T>>::get(&who, asset_id);
+		let sufficient = held == Some(true) || <Module<T>>::is_sufficient(asset_id);
+		if sufficient {
+			if held != Some(true) {
+				<SufficientAssetsHeld<T>>::mutate(&who, |count| *count = count.saturating_add(1));
+			}
+			if !<ProvidedAccounts<T>>::get(&who) {
+				if <frame_system::Module<T>>::providers(&who).is_zero() {
+					<frame_system::Module<T>>::inc_providers(&who);
+				}
+				<ProvidedAccounts<T>>::insert(&who, true);
+			}
+		}
+		<HeldAssets<T>>::insert(&who, asset_id, sufficient);
+	}
+	// Each holding reads its balance, index entry, sufficiency (3), sufficient count and provider state (2), and
+	// writes its index entry, the sufficient count, the provider flag and the account's provider count
+	T::DbWeight::get().reads_writes(8, 4).saturating_mul(migrated)
+}
+
+/// Existing locks never expire
+fn migrate_lock_expiry<T: Config>() {
+	<Locks<T>>::translate::<Vec<LegacyBalanceLock<T::Balance>>, _>(|_, _, locks| {
+		Some(locks.into_iter().map(Into::into).collect())
+	});
+}
+
 fn migrate_locks<T: Config>() {
 	#[allow(dead_code)]
 	mod old_storage {
 		use super::Config;
-		use crate::types::BalanceLock;
+		use crate::types::LegacyBalanceLock;
 		use sp_std::vec::Vec;
 
 		pub struct Module<T>(sp_std::marker::PhantomData<T>);
 		frame_support::decl_storage! {
 			trait Store for Module<T: Config> as GenericAsset {
 				pub Locks get(fn locks):
-					map hasher(blake2_128_concat) T::AccountId => Vec<BalanceLock<T::Balance>>;
+					map hasher(blake2_128_concat) T::AccountId => Vec<LegacyBalanceLock<T::Balance>>;
 			}
 		}
 	}
 
 	let staking_asset_id = <Module<T>>::staking_asset_id();
-	let all_locks = <old_storage::Locks<T>>::drain().collect::<Vec<(T::AccountId, Vec<BalanceLock<T::Balance>>)>>();
-	all_locks.iter().for_each(|(account_id, locks)| {
+	let all_locks =
+		<old_storage::Locks<T>>::drain().collect::<Vec<(T::AccountId, Vec<LegacyBalanceLock<T::Balance>>)>>();
+	all_locks.into_iter().for_each(|(account_id, locks)| {
 		if !locks.is_empty() {
+			let locks: Vec<BalanceLock<T::Balance, T::BlockNumber>> = locks.into_iter().map(Into::into).collect();
 			<Locks<T>>::insert(staking_asset_id, &account_id, locks);
 		}
 	});
@@ -572,14 +1055,28 @@ decl_event! {
 		Transferred(AssetId, AccountId, AccountId, Balance),
 		/// Asset permission updated (asset_id, new_permissions).
 		PermissionUpdated(AssetId, PermissionLatest<AccountId>),
+		/// Asset ownership transferred (asset_id, old_owner, new_owner).
+		OwnershipTransferred(AssetId, AccountId, AccountId),
 		/// Asset info updated (asset_id, asset_info).
 		AssetInfoUpdated(AssetId, AssetInfo),
 		/// New asset minted (asset_id, account, amount).
 		Minted(AssetId, AccountId, Balance),
 		/// Asset burned (asset_id, account, amount).
 		Burned(AssetId, AccountId, Balance),
+		/// Asset balance reserved (asset_id, account, amount).
+		Reserved(AssetId, AccountId, Balance),
+		/// Asset balance unreserved (asset_id, account, amount).
+		Unreserved(AssetId, AccountId, Balance),
 		/// Asset balance storage has been reclaimed due to falling below the existential deposit
 		DustReclaimed(AssetId, AccountId, Balance),
+		/// Asset balances set by root (asset_id, account, free, reserved).
+		BalanceSet(AssetId, AccountId, Balance, Balance),
+		/// Asset total issuance changed by minting or burning (asset_id, new_total_issuance).
+		IssuanceChanged(AssetId, Balance),
+		/// Asset transfer fee burned from the sender (asset_id, sender, fee).
+		FeeCharged(AssetId, AccountId, Balance),
+		/// Asset transfer succeeded with a memo attached (asset_id, from, to, amount, memo).
+		TransferredWithMemo(AssetId, AccountId, AccountId, Balance, Vec<u8>),
 	}
 }
 
@@ -599,29 +1096,112 @@ impl<T: Config> Module<T> {
 		<ReservedBalance<T>>::get(asset_id, who)
 	}
 
+	/// Check whether an account holds any of an asset kind.
+	/// A free balance which is dust awaiting reclamation is not counted.
+	pub fn asset_exists_for(asset_id: T::AssetId, who: &T::AccountId) -> bool {
+		!Self::reserved_balance(asset_id, who).is_zero() || !Self::is_dust(asset_id, Self::free_balance(asset_id, who))
+	}
+
+	/// The fee burned from the sender of each transfer of an asset kind.
+	pub fn transfer_fee(asset_id: T::AssetId) -> T::Balance {
+		Self::asset_meta(asset_id).transfer_fee().saturated_into()
+	}
+
+	/// Whether a free balance of an asset kind is below its dust threshold, and so would be reclaimed.
+	pub fn is_dust(asset_id: T::AssetId, balance: T::Balance) -> bool {
+		balance < Self::asset_meta(asset_id).dust_threshold().saturated_into()
+	}
+
+	/// Whether an asset kind is meant to be held on its own, i.e. it is a native (staking or spending) asset
+	/// or is marked sufficient.
+	pub fn is_sufficient(asset_id: T::AssetId) -> bool {
+		asset_id == Self::staking_asset_id()
+			|| asset_id == Self::spending_asset_id()
+			|| Self::asset_meta(asset_id).sufficient()
+	}
+
+	/// Get the most of an asset kind `who` can transfer, i.e. the free balance less the largest active lock.
+	/// If `keep_alive` is true, the existential deposit is also kept back.
+	pub fn transferable_balance(asset_id: T::AssetId, who: &T::AccountId, keep_alive: bool) -> T::Balance {
+		let transferable = Self::free_balance(asset_id, who).saturating_sub(Self::frozen_balance(asset_id, who));
+		if keep_alive {
+			let existential_deposit: T::Balance = Self::asset_meta(asset_id).existential_deposit().saturated_into();
+			transferable.saturating_sub(existential_deposit)
+		} else {
+			transferable
+		}
+	}
+
+	/// The amount of an account's balance that is frozen by locks.
+	/// Overlapping locks do not stack, so this is the largest active lock.
+	pub fn frozen_balance(asset_id: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Self::active_locks(asset_id, who)
+			.into_iter()
+			.map(|lock| lock.amount)
+			.max()
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// The free, reserved and frozen balances of an account, in that order.
+	pub fn balance_detailed(asset_id: T::AssetId, who: &T::AccountId) -> (T::Balance, T::Balance, T::Balance) {
+		(
+			Self::free_balance(asset_id, who),
+			Self::reserved_balance(asset_id, who),
+			Self::frozen_balance(asset_id, who),
+		)
+	}
+
 	/// Mint to an account's free balance, without event
+	/// Minting to a blocked recipient fails, as does minting less than the existential deposit into an
+	/// account which holds none of the asset, as it would be reclaimed as dust, unless
+	/// `allow_below_existential_deposit` is set. An asset which is not sufficient is only minted to
+	/// accounts which exist.
 	pub fn mint_free(
 		asset_id: T::AssetId,
 		who: &T::AccountId,
 		to: &T::AccountId,
 		amount: &T::Balance,
+		allow_below_existential_deposit: bool,
 	) -> DispatchResult {
-		if Self::check_permission(asset_id, who, &PermissionType::Mint) {
-			let original_free_balance = Self::free_balance(asset_id, &to);
-			let current_total_issuance = <TotalIssuance<T>>::get(asset_id);
-			let new_total_issuance = current_total_issuance
-				.checked_add(&amount)
-				.ok_or(Error::<T>::TotalMintingOverflow)?;
-			let value = original_free_balance
-				.checked_add(&amount)
-				.ok_or(Error::<T>::FreeMintingOverflow)?;
-
-			<TotalIssuance<T>>::insert(asset_id, new_total_issuance);
-			Self::set_free_balance(asset_id, &to, value);
-			Ok(())
-		} else {
+		if !Self::check_permission(asset_id, who, &PermissionType::Mint) {
 			Err(Error::<T>::NoMintPermission)?
 		}
+		let existential_deposit = if allow_below_existential_deposit {
+			Zero::zero()
+		} else {
+			Self::asset_meta(asset_id).existential_deposit().saturated_into()
+		};
+		let holds_some = !Self::total_balance(asset_id, to).is_zero();
+		Self::ensure_can_mint(to, amount, existential_deposit, holds_some, Self::is_sufficient(asset_id))?;
+		Self::do_mint_free(asset_id, to, amount)
+	}
+
+	/// Mint directly into an account's reserved balance, leaving its free balance untouched.
+	/// This will emit the `Minted` event.
+	pub fn mint_reserved(
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		ensure!(
+			Self::check_permission(asset_id, who, &PermissionType::Mint),
+			Error::<T>::NoMintPermission
+		);
+		Self::ensure_can_hold(to, Self::is_sufficient(asset_id), !Self::total_balance(asset_id, to).is_zero())?;
+		let new_total_issuance = Self::total_issuance(asset_id)
+			.checked_add(&amount)
+			.ok_or(Error::<T>::TotalMintingOverflow)?;
+		let new_reserved_balance = Self::reserved_balance(asset_id, to)
+			.checked_add(&amount)
+			.ok_or(Error::<T>::ReservedMintingOverflow)?;
+
+		<TotalIssuance<T>>::insert(asset_id, new_total_issuance);
+		Self::set_reserved_balance(asset_id, to, new_reserved_balance);
+
+		Self::deposit_event(Event::<T>::Minted(asset_id, to.clone(), amount));
+		Self::deposit_event(Event::<T>::IssuanceChanged(asset_id, Self::total_issuance(asset_id)));
+		Ok(())
 	}
 
 	/// Burn an account's free balance, without event
@@ -632,19 +1212,7 @@ impl<T: Config> Module<T> {
 		amount: &T::Balance,
 	) -> DispatchResult {
 		if Self::check_permission(asset_id, who, &PermissionType::Burn) {
-			let original_free_balance = Self::free_balance(asset_id, to);
-
-			let current_total_issuance = <TotalIssuance<T>>::get(asset_id);
-			let new_total_issuance = current_total_issuance
-				.checked_sub(amount)
-				.ok_or(Error::<T>::TotalBurningUnderflow)?;
-			let value = original_free_balance
-				.checked_sub(amount)
-				.ok_or(Error::<T>::FreeBurningUnderflow)?;
-
-			<TotalIssuance<T>>::insert(asset_id, new_total_issuance);
-			Self::set_free_balance(asset_id, &to, value);
-			Ok(())
+			Self::do_burn_free(asset_id, to, amount)
 		} else {
 			Err(Error::<T>::NoBurnPermission)?
 		}
@@ -668,15 +1236,10 @@ impl<T: Config> Module<T> {
 			!info.existential_deposit().is_zero(),
 			Error::<T>::ZeroExistentialDeposit
 		);
+		Self::ensure_valid_symbol(&info)?;
+		ensure!(Self::id_for_symbol(info.symbol()).is_none(), Error::<T>::SymbolTaken);
 
-		let decimal_factor: T::Balance = 10u128
-			.checked_pow(info.decimal_places().into())
-			.ok_or(Error::<T>::DecimalTooLarge)?
-			.unique_saturated_into();
-		// Assuming that Balance is u128 or less. Implemented in this way for practicality
-		let total_issuance: T::Balance = decimal_factor
-			.checked_mul(&options.initial_issuance)
-			.ok_or(Error::<T>::InitialIssuanceTooLarge)?;
+		let total_issuance = Self::initial_issuance(&options, &info)?;
 
 		let asset_id = if let Some(asset_id) = asset_id {
 			ensure!(!asset_id.is_zero(), Error::<T>::AssetIdExists);
@@ -694,11 +1257,55 @@ impl<T: Config> Module<T> {
 		let permissions: PermissionVersions<T::AccountId> = options.permissions.clone().into();
 
 		<TotalIssuance<T>>::insert(asset_id, &total_issuance);
-		Self::set_free_balance(asset_id, &account_id, total_issuance);
 		<Permissions<T>>::insert(asset_id, permissions);
+		Self::index_symbol(asset_id, info.symbol());
+		// The asset info decides whether the initial issuance provides for `account_id`
 		<AssetMeta<T>>::insert(asset_id, info);
+		Self::set_free_balance(asset_id, &account_id, total_issuance);
 
 		Self::deposit_event(Event::<T>::Created(asset_id, account_id, options));
+		T::OnNewAsset::on_new_asset(asset_id);
+
+		Ok(())
+	}
+
+	/// Creates an asset as `create_asset` does, then credits the free balance of each `endowed` account.
+	///
+	/// The endowments are checked up front, as `mint_free` checks a mint, so that no asset is created if the
+	/// total issuance would overflow or an endowment could not be minted.
+	/// This will emit the `Created` event, followed by a `Minted` event for each endowed account.
+	pub fn create_asset_with_endowment(
+		asset_id: Option<T::AssetId>,
+		from_account: Option<T::AccountId>,
+		options: AssetOptions<T::Balance, T::AccountId>,
+		info: AssetInfo,
+		endowed: Vec<(T::AccountId, T::Balance)>,
+	) -> DispatchResult {
+		endowed
+			.iter()
+			.try_fold(Self::initial_issuance(&options, &info)?, |total, (_, amount)| {
+				total.checked_add(amount)
+			})
+			.ok_or(Error::<T>::EndowmentOverflow)?;
+		// Only `from_account` holds any of the new asset before the endowments are credited
+		let existential_deposit = info.existential_deposit().saturated_into();
+		let mut credited = BTreeSet::new();
+		if !options.initial_issuance.is_zero() {
+			credited.insert(from_account.clone().unwrap_or_default());
+		}
+		for (who, amount) in endowed.iter() {
+			Self::ensure_can_mint(who, amount, existential_deposit, credited.contains(who), info.sufficient())?;
+			credited.insert(who.clone());
+		}
+
+		let created_asset_id = asset_id.unwrap_or_else(Self::next_asset_id);
+		Self::create_asset(asset_id, from_account, options, info)?;
+
+		for (who, amount) in endowed {
+			Self::do_mint_free(created_asset_id, &who, &amount)?;
+			Self::deposit_event(Event::<T>::Minted(created_asset_id, who, amount));
+		}
+		Self::deposit_event(Event::<T>::IssuanceChanged(created_asset_id, Self::total_issuance(created_asset_id)));
 
 		Ok(())
 	}
@@ -710,35 +1317,73 @@ impl<T: Config> Module<T> {
 		from: &T::AccountId,
 		to: &T::AccountId,
 		amount: T::Balance,
-		_req: ExistenceRequirement,
+		req: ExistenceRequirement,
 	) -> DispatchResult {
-
+		let new_to_balance = Self::ensure_can_receive(asset_id, to, amount)?;
 		let new_from_balance = Self::free_balance(asset_id, from)
 			.checked_sub(&amount)
 			.ok_or(Error::<T>::InsufficientBalance)?;
-		let new_to_balance = Self::free_balance(asset_id, to)
-			.checked_add(&amount)
-			.ok_or(Error::<T>::TransferOverflow)?;
 
 		Self::ensure_can_withdraw(asset_id, from, amount, WithdrawReasons::TRANSFER, new_from_balance)?;
 
 		if from == to {
 			return Ok(());
 		}
+		Self::ensure_can_hold(to, Self::is_sufficient(asset_id), !Self::total_balance(asset_id, to).is_zero())?;
 
 		Self::set_free_balance(asset_id, to, new_to_balance);
-		Self::set_free_balance(asset_id, from, new_from_balance);
+		Self::write_free_balance(asset_id, from, new_from_balance, req);
 
-		let existential_deposit = Self::asset_meta(asset_id).existential_deposit();
-		if new_from_balance < existential_deposit.saturated_into() {
-			Self::reclaim_free_balance(asset_id, from);
+		if Self::is_dust(asset_id, new_from_balance) {
+			Self::reclaim_free_balance(asset_id, from, req);
 		}
 
 		Ok(())
 	}
 
-	/// Transfer some liquid free balance from one account to another.
-	/// This will emit the `Transferred` event.
+	/// Return the free balance of `to` after receiving `amount`, if it may receive it.
+	fn ensure_can_receive(
+		asset_id: T::AssetId,
+		to: &T::AccountId,
+		amount: T::Balance,
+	) -> result::Result<T::Balance, DispatchError> {
+		ensure!(!T::BlockedRecipients::contains(to), Error::<T>::BlockedRecipient);
+		let new_to_balance = Self::free_balance(asset_id, to)
+			.checked_add(&amount)
+			.ok_or(Error::<T>::TransferOverflow)?;
+		Ok(new_to_balance)
+	}
+
+	/// Check that `amount` of an asset, `sufficient` or not, may be minted to `to`, which already holds some
+	/// of the asset if `holds_some`.
+	/// A blocked recipient is rejected, as is an amount below `existential_deposit` into an account
+	/// holding none of the asset, or an account which cannot hold the asset.
+	fn ensure_can_mint(
+		to: &T::AccountId,
+		amount: &T::Balance,
+		existential_deposit: T::Balance,
+		holds_some: bool,
+		sufficient: bool,
+	) -> DispatchResult {
+		ensure!(!T::BlockedRecipients::contains(to), Error::<T>::BlockedRecipient);
+		ensure!(holds_some || *amount >= existential_deposit, Error::<T>::BelowExistentialDeposit);
+		Self::ensure_can_hold(to, sufficient, holds_some)
+	}
+
+	/// Check that `who` may be credited an asset, `sufficient` or not, which it already holds some of if
+	/// `holds_some`. An asset which is not sufficient cannot keep an account alive, so `who` must exist to
+	/// start holding it.
+	fn ensure_can_hold(who: &T::AccountId, sufficient: bool, holds_some: bool) -> DispatchResult {
+		ensure!(
+			sufficient || holds_some || !<frame_system::Module<T>>::providers(who).is_zero(),
+			Error::<T>::NoProvider
+		);
+		Ok(())
+	}
+
+	/// Transfer some liquid free balance from one account to another, burning the asset's transfer fee
+	/// from the sender.
+	/// This will emit the `Transferred` event, and the `FeeCharged` event if a fee is burned.
 	pub fn make_transfer_with_event(
 		asset_id: T::AssetId,
 		from: &T::AccountId,
@@ -746,6 +1391,28 @@ impl<T: Config> Module<T> {
 		amount: T::Balance,
 		req: ExistenceRequirement,
 	) -> DispatchResult {
+		let fee = Self::transfer_fee(asset_id);
+		if !fee.is_zero() && from != to {
+			// Check the transfer can succeed and the sender can afford the fee on top of it before burning anything
+			Self::ensure_can_receive(asset_id, to, amount)?;
+			let total = amount.checked_add(&fee).ok_or(Error::<T>::TransferOverflow)?;
+			let new_from_balance = Self::free_balance(asset_id, from)
+				.checked_sub(&total)
+				.ok_or(Error::<T>::InsufficientBalance)?;
+			Self::ensure_can_withdraw(
+				asset_id,
+				from,
+				total,
+				WithdrawReasons::TRANSFER | WithdrawReasons::FEE,
+				new_from_balance,
+			)?;
+			Self::ensure_can_hold(to, Self::is_sufficient(asset_id), !Self::total_balance(asset_id, to).is_zero())?;
+
+			Self::do_burn_free(asset_id, from, &fee)?;
+			Self::deposit_event(Event::<T>::FeeCharged(asset_id, from.clone(), fee));
+			Self::deposit_event(Event::<T>::IssuanceChanged(asset_id, Self::total_issuance(asset_id)));
+		}
+
 		Self::make_transfer(asset_id, from, to, amount, req)?;
 
 		if from != to {
@@ -771,14 +1438,13 @@ impl<T: Config> Module<T> {
 		let new_free_balance = original_free_balance - amount;
 
 		// `free` balance should be freed if set to a dust amount
-		let existential_deposit = Self::asset_meta(asset_id).existential_deposit();
-		if new_free_balance < existential_deposit.saturated_into() {
-			Self::reclaim_free_balance(asset_id, who);
+		if Self::is_dust(asset_id, new_free_balance) {
+			Self::reclaim_free_balance(asset_id, who, ExistenceRequirement::AllowDeath);
 		}  else {
-			<FreeBalance<T>>::insert(asset_id, who, &new_free_balance);
+			Self::set_free_balance(asset_id, who, new_free_balance);
 		}
 
-		<ReservedBalance<T>>::insert(asset_id, who, &new_reserve_balance);
+		Self::set_reserved_balance(asset_id, who, new_reserve_balance);
 
 		Ok(())
 	}
@@ -787,8 +1453,20 @@ impl<T: Config> Module<T> {
 	///
 	/// As many assets up to `amount` will be moved as possible. If the reserve balance of `who`
 	/// is less than `amount`, then the remaining amount will be returned.
+	/// This will emit the `Unreserved` event with the amount actually moved, if any.
 	/// NOTE: This is different behavior than `reserve`.
 	pub fn unreserve(asset_id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> T::Balance {
+		let remaining = Self::unreserve_silent(asset_id, who, amount);
+		let actual = amount - remaining;
+		if !actual.is_zero() {
+			Self::deposit_event(Event::<T>::Unreserved(asset_id, who.clone(), actual));
+		}
+		remaining
+	}
+
+	/// Moves up to `amount` from reserved balance to free balance as `unreserve` does, without
+	/// emitting an event.
+	pub fn unreserve_silent(asset_id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> T::Balance {
 		let b = Self::reserved_balance(asset_id, who);
 		let actual = cmp::min(b, amount);
 		let original_free_balance = Self::free_balance(asset_id, who);
@@ -796,11 +1474,124 @@ impl<T: Config> Module<T> {
 		let new_reserved_balance = b - actual;
 
 		Self::set_reserved_balance(asset_id, who, new_reserved_balance);
-		<FreeBalance<T>>::insert(asset_id, &who, new_free_balance);
+		Self::set_free_balance(asset_id, who, new_free_balance);
 
 		amount - actual
 	}
 
+	/// Move `amount` from free balance to reserved balance.
+	/// This will emit the `Reserved` event.
+	pub fn reserve_with_event(asset_id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::reserve(asset_id, who, amount)?;
+		Self::deposit_event(Event::<T>::Reserved(asset_id, who.clone(), amount));
+		Ok(())
+	}
+
+	/// Get the portion of an account's reserved balance held under the reserve `id`.
+	pub fn reserved_balance_named(id: ReserveId, asset_id: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Self::named_reserves(asset_id, who)
+			.into_iter()
+			.find(|(reserve_id, _)| *reserve_id == id)
+			.map_or_else(Zero::zero, |(_, reserved)| reserved)
+	}
+
+	/// Move `amount` from free balance to the reserved balance held under the reserve `id`.
+	///
+	/// Fails in the same way as `reserve`, in which case no funds are moved.
+	pub fn reserve_named(
+		id: ReserveId,
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		Self::reserve(asset_id, who, amount)?;
+		Self::add_named_reserve(id, asset_id, who, amount);
+
+		Ok(())
+	}
+
+	/// Moves up to `amount` from the reserved balance held under the reserve `id` to free balance.
+	/// This function cannot fail.
+	///
+	/// Only the portion reserved under `id` is touched. If it is less than `amount`, then the
+	/// remaining amount will be returned.
+	pub fn unreserve_named(
+		id: ReserveId,
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> T::Balance {
+		if amount.is_zero() {
+			return Zero::zero();
+		}
+
+		Self::mutate_named_reserve(id, asset_id, who, |reserved| {
+			let to_change = cmp::min(*reserved, amount);
+			let actual = to_change - Self::unreserve_silent(asset_id, who, to_change);
+			*reserved -= actual;
+			amount - actual
+		})
+		.unwrap_or(amount)
+	}
+
+	/// Deducts up to `amount` from the reserved balance held under the reserve `id`.
+	/// This function cannot fail.
+	///
+	/// Only the portion reserved under `id` is touched. If it is less than `amount`, then
+	/// `Some(remaining)` will be returned.
+	/// NOTE: LOW-LEVEL: This will not attempt to maintain total issuance. It is expected that
+	/// the caller will do this.
+	pub fn slash_reserved_named(
+		id: ReserveId,
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> Option<T::Balance> {
+		let remaining = Self::mutate_named_reserve(id, asset_id, who, |reserved| {
+			let to_change = cmp::min(*reserved, amount);
+			let actual = to_change - Self::slash_reserved(asset_id, who, to_change).unwrap_or_else(Zero::zero);
+			*reserved -= actual;
+			amount - actual
+		})
+		.unwrap_or(amount);
+
+		if remaining.is_zero() {
+			None
+		} else {
+			Some(remaining)
+		}
+	}
+
+	/// Moves up to `amount` from the reserved balance of `payee` held under the reserve `id` to `beneficiary`.
+	/// With `BalanceStatus::Reserved` the funds are held under the same reserve `id` of `beneficiary`.
+	///
+	/// Only the portion reserved under `id` is touched. If it is less than `amount`, then the
+	/// remaining amount will be returned.
+	pub fn repatriate_reserved_named(
+		id: ReserveId,
+		asset_id: T::AssetId,
+		payee: &T::AccountId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+		status: BalanceStatus,
+	) -> Result<T::Balance, DispatchError> {
+		let to_change = cmp::min(Self::reserved_balance_named(id, asset_id, payee), amount);
+		if to_change.is_zero() {
+			return Ok(amount);
+		}
+		let actual = to_change - Self::repatriate_reserved(asset_id, payee, beneficiary, to_change, status)?;
+
+		Self::mutate_named_reserve(id, asset_id, payee, |reserved| *reserved -= actual);
+		if let BalanceStatus::Reserved = status {
+			Self::add_named_reserve(id, asset_id, beneficiary, actual);
+		}
+
+		Ok(amount - actual)
+	}
+
 	/// Deduct up to `amount` from the combined balance of `who`, preferring to deduct from the
 	/// free balance. This function cannot fail.
 	///
@@ -820,6 +1611,74 @@ impl<T: Config> Module<T> {
 		}
 	}
 
+	/// Deduct up to `amount` from the combined balance of `who`, preferring to deduct from the
+	/// free balance. This function cannot fail.
+	///
+	/// Returns a `NegativeImbalance` for the amount actually slashed and the amount which could not be
+	/// slashed. Total issuance is maintained once the imbalance is dropped or otherwise resolved.
+	pub fn slash_and_settle(
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> (NegativeImbalance<T>, T::Balance) {
+		let remaining = Self::slash(asset_id, who, amount).unwrap_or_else(Zero::zero);
+		(NegativeImbalance::new(amount - remaining, asset_id), remaining)
+	}
+
+	/// Deduct `amount` from the combined balances of `who` across `targets`, slashing each asset in
+	/// order until `amount` is covered. The same amount applies whichever asset it is taken from.
+	///
+	/// Returns the amount which could not be slashed from any of the assets.
+	/// The total issuance of each asset is reduced by the amount slashed from it.
+	pub fn slash_cascade(who: &T::AccountId, targets: Vec<T::AssetId>, amount: T::Balance) -> T::Balance {
+		let mut remaining = amount;
+		for asset_id in targets {
+			if remaining.is_zero() {
+				break;
+			}
+			// dropping the imbalance settles the total issuance of `asset_id`
+			let (_imbalance, left) = Self::slash_and_settle(asset_id, who, remaining);
+			remaining = left;
+		}
+		remaining
+	}
+
+	/// Add up to `amount` to the free balance of `who`, increasing the total issuance to match.
+	/// This function cannot fail.
+	///
+	/// The amount is clamped so that neither the free balance nor the total issuance exceeds
+	/// `Balance::max_value()`. Returns the amount actually added.
+	pub fn increase_balance(asset_id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> T::Balance {
+		let free_balance = Self::free_balance(asset_id, who);
+		let total_issuance = Self::total_issuance(asset_id);
+		let actual = amount
+			.min(T::Balance::max_value() - free_balance)
+			.min(T::Balance::max_value() - total_issuance);
+		if actual.is_zero() {
+			return actual;
+		}
+
+		<TotalIssuance<T>>::insert(asset_id, total_issuance + actual);
+		Self::set_free_balance(asset_id, who, free_balance + actual);
+		actual
+	}
+
+	/// Remove up to `amount` from the free balance of `who`, decreasing the total issuance to match.
+	/// This function cannot fail.
+	///
+	/// The amount is clamped to what `who` could transfer, so locked funds are never removed. The account is
+	/// not reaped if left with dust. Returns the amount actually removed.
+	pub fn decrease_balance(asset_id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> T::Balance {
+		let actual = amount.min(Self::transferable_balance(asset_id, who, false));
+		if actual.is_zero() {
+			return actual;
+		}
+
+		<TotalIssuance<T>>::mutate(asset_id, |issuance| *issuance = issuance.saturating_sub(actual));
+		Self::set_free_balance(asset_id, who, Self::free_balance(asset_id, who) - actual);
+		actual
+	}
+
 	/// Deducts up to `amount` from reserved balance of `who`. This function cannot fail.
 	///
 	/// As much funds up to `amount` will be deducted as possible. If the reserve balance of `who`
@@ -838,34 +1697,44 @@ impl<T: Config> Module<T> {
 		}
 	}
 
-	/// Move up to `amount` from the reserved balance of one account into the free balance of another.
+	/// Move up to `amount` from the reserved balance of one account into the free or reserved balance
+	/// of another, as given by `status`.
 	/// The entire reserve balance will be transferred if it is less than `amount`.
 	pub fn repatriate_reserved(
 		asset_id: T::AssetId,
 		payee: &T::AccountId,
 		beneficiary: &T::AccountId,
 		amount: T::Balance,
+		status: BalanceStatus,
 	) -> Result<T::Balance, DispatchError> {
 		if amount.is_zero() {
 			return Ok(Zero::zero());
 		}
 		let payee_reserve_balance = Self::reserved_balance(asset_id, payee);
 		let repatriated_amount = sp_std::cmp::min(payee_reserve_balance, amount);
-
-		let beneficiary_free_balance = Self::free_balance(asset_id, beneficiary);
-		let new_beneficiary_free_balance = beneficiary_free_balance + repatriated_amount;
 		let new_payee_reserve_balance = payee_reserve_balance - repatriated_amount;
-
-		// Intentionally allowing `beneficiary` to receive dust amounts
-		// `repatriate_reserved` is an internal function likely called by protocol operations
-		// this will allow an account to accumulate without being reaped too early
-		<FreeBalance<T>>::insert(asset_id, &beneficiary, &new_beneficiary_free_balance);
 		Self::set_reserved_balance(asset_id, payee, new_payee_reserve_balance);
 
+		match status {
+			BalanceStatus::Free => {
+				let beneficiary_free_balance = Self::free_balance(asset_id, beneficiary);
+				let new_beneficiary_free_balance = beneficiary_free_balance + repatriated_amount;
+				// Intentionally allowing `beneficiary` to receive dust amounts
+				// `repatriate_reserved` is an internal function likely called by protocol operations
+				// this will allow an account to accumulate without being reaped too early
+				Self::set_free_balance(asset_id, beneficiary, new_beneficiary_free_balance);
+			}
+			BalanceStatus::Reserved => {
+				// read after the payee update in case the payee is also the beneficiary
+				let beneficiary_reserve_balance = Self::reserved_balance(asset_id, beneficiary);
+				Self::set_reserved_balance(asset_id, beneficiary, beneficiary_reserve_balance + repatriated_amount);
+			}
+		}
+
 		Ok(amount - repatriated_amount)
 	}
 
-	/// Check permission to perform burn, mint or update.
+	/// Check permission to perform burn, mint, update or freeze.
 	///
 	/// # Arguments
 	/// * `asset_id`:  A `T::AssetId` type that contains the `asset_id`, which has the permission embedded.
@@ -898,6 +1767,13 @@ impl<T: Config> Module<T> {
 					..
 				},
 			) => account == *who,
+			(
+				PermissionType::Freeze,
+				PermissionLatest {
+					freeze: Owner::Address(account),
+					..
+				},
+			) => account == *who,
 			_ => false,
 		}
 	}
@@ -913,21 +1789,91 @@ impl<T: Config> Module<T> {
 		reasons: WithdrawReasons,
 		new_balance: T::Balance,
 	) -> DispatchResult {
-		if amount.is_zero() {
-			return Ok(());
+		match Self::blocking_lock(asset_id, who, amount, reasons, new_balance) {
+			Some(_) => Err(Error::<T>::LiquidityRestrictions)?,
+			None => Ok(()),
 		}
-		let locks = Self::locks(asset_id, who);
-		if locks.is_empty() {
-			return Ok(());
+	}
+
+	/// The id of the first lock stopping `who` withdrawing `amount` for the given reasons, leaving
+	/// `new_balance`, or `None` if the withdrawal is allowed.
+	/// This does not change any state, it explains a `LiquidityRestrictions` error from `ensure_can_withdraw`.
+	pub fn blocking_lock(
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reasons: WithdrawReasons,
+		new_balance: T::Balance,
+	) -> Option<LockIdentifier> {
+		if amount.is_zero() {
+			return None;
 		}
-		if locks
+		let now = <frame_system::Module<T>>::block_number();
+		Self::locks(asset_id, who)
 			.into_iter()
-			.all(|l| new_balance >= l.amount || !l.reasons.intersects(reasons))
-		{
-			Ok(())
+			.find(|l| l.is_active(&now) && new_balance < l.amount && l.reasons.intersects(reasons))
+			.map(|l| l.id)
+	}
+
+	/// Return the consequence of `who` withdrawing `amount` of free balance for the given reasons.
+	///
+	/// Unlike `ensure_can_withdraw`, this distinguishes a lack of funds, funds being locked and the
+	/// remaining balance being reclaimed as dust.
+	pub fn withdraw_consequence(
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reasons: WithdrawReasons,
+	) -> WithdrawConsequence<T::Balance> {
+		if amount.is_zero() {
+			return WithdrawConsequence::Success;
+		}
+		let new_balance = match Self::free_balance(asset_id, who).checked_sub(&amount) {
+			Some(new_balance) => new_balance,
+			None => return WithdrawConsequence::NoFunds,
+		};
+		if Self::ensure_can_withdraw(asset_id, who, amount, reasons, new_balance).is_err() {
+			return WithdrawConsequence::Frozen;
+		}
+		if !new_balance.is_zero() && Self::is_dust(asset_id, new_balance) {
+			WithdrawConsequence::ReducedToZero(new_balance)
 		} else {
-			Err(Error::<T>::LiquidityRestrictions)?
+			WithdrawConsequence::Success
+		}
+	}
+
+	/// Return up to `limit` holders of an asset kind with their free balances, starting after the raw storage
+	/// key `start_key`, or from the first holder if `None`.
+	/// The key to continue from is also returned, or `None` once all holders have been returned.
+	pub fn holders(
+		asset_id: T::AssetId,
+		start_key: Option<Vec<u8>>,
+		limit: u32,
+	) -> (Vec<(T::AccountId, T::Balance)>, Option<Vec<u8>>) {
+		let mut prefix = <FreeBalance<T> as StoragePrefixedMap<T::Balance>>::final_prefix().to_vec();
+		prefix.extend_from_slice(&Twox64Concat::hash(&asset_id.encode()));
+		let next_key = |key: &[u8]| sp_io::storage::next_key(key).filter(|next| next.starts_with(&prefix));
+
+		let mut previous_key = start_key
+			.filter(|key| key.starts_with(&prefix))
+			.unwrap_or_else(|| prefix.clone());
+		let mut holders = Vec::new();
+		while holders.len() < limit as usize {
+			match next_key(&previous_key) {
+				Some(key) => previous_key = key,
+				None => return (holders, None),
+			}
+			let mut raw_account = Blake2_128Concat::reverse(&previous_key[prefix.len()..]);
+			if let (Ok(who), Some(balance)) = (
+				T::AccountId::decode(&mut raw_account),
+				unhashed::get::<T::Balance>(&previous_key),
+			) {
+				holders.push((who, balance));
+			}
 		}
+
+		let more = next_key(&previous_key).is_some();
+		(holders, if more { Some(previous_key) } else { None })
 	}
 
 	/// Return registered asset metadata
@@ -935,15 +1881,71 @@ impl<T: Config> Module<T> {
 		AssetMeta::<T>::iter().collect()
 	}
 
+	/// The asset with the given symbol, if any
+	pub fn id_for_symbol(symbol: &[u8]) -> Option<T::AssetId> {
+		<SymbolToId<T>>::get(symbol)
+	}
+
 	/// Reclaim asset storage items for an account
 	/// Any dust imbalance from free balance is passed to a dust imbalance handler.
-	fn reclaim_free_balance(asset_id: T::AssetId, who: &T::AccountId) {
+	/// `DustReclaimed` is only emitted when there was dust to reclaim.
+	fn reclaim_free_balance(asset_id: T::AssetId, who: &T::AccountId, req: ExistenceRequirement) {
 		let amount = <FreeBalance<T>>::take(asset_id, who);
+		Self::update_holding(asset_id, who, req);
 		if amount > Zero::zero() {
+			Self::note_dust_reclaimed(asset_id, amount);
 			T::OnDustImbalance::on_nonzero_unbalanced(NegativeImbalance::new(amount, asset_id));
+			Self::deposit_event(Event::<T>::DustReclaimed(asset_id, who.clone(), amount));
 		}
+	}
 
-		Self::deposit_event(Event::<T>::DustReclaimed(asset_id, who.clone(), amount));
+	/// Add `amount` to the total dust ever reclaimed of an asset kind.
+	fn note_dust_reclaimed(asset_id: T::AssetId, amount: T::Balance) {
+		<TotalDustReclaimed<T>>::mutate(asset_id, |total| *total = total.saturating_add(amount));
+	}
+
+	/// Whether `who` has a free or reserved balance of any asset.
+	pub fn holds_any_asset(who: &T::AccountId) -> bool {
+		<HeldAssets<T>>::iter_prefix(who).next().is_some()
+	}
+
+	/// Keep the `HeldAssets` index of `who` in step with its balance storage of `asset_id`.
+	/// GenericAsset takes a provider reference on `who` once it holds a sufficient asset, and releases it once `who`
+	/// holds no sufficient asset, unless the change was made with `ExistenceRequirement::KeepAlive`.
+	fn update_holding(asset_id: T::AssetId, who: &T::AccountId, req: ExistenceRequirement) {
+		if <FreeBalance<T>>::contains_key(asset_id, who) || <ReservedBalance<T>>::contains_key(asset_id, who) {
+			if !<HeldAssets<T>>::contains_key(who, asset_id) {
+				let sufficient = Self::is_sufficient(asset_id);
+				if sufficient {
+					<SufficientAssetsHeld<T>>::mutate(who, |held| *held = held.saturating_add(1));
+				}
+				<HeldAssets<T>>::insert(who, asset_id, sufficient);
+			}
+			if !Self::sufficient_assets_held(who).is_zero() && !<ProvidedAccounts<T>>::get(who) {
+				<frame_system::Module<T>>::inc_providers(who);
+				<ProvidedAccounts<T>>::insert(who, true);
+			}
+		} else {
+			if <HeldAssets<T>>::take(who, asset_id) == Some(true) {
+				<SufficientAssetsHeld<T>>::mutate_exists(who, |held| {
+					*held = held.and_then(|count| count.checked_sub(1)).filter(|count| !count.is_zero())
+				});
+			}
+			if req == ExistenceRequirement::AllowDeath {
+				Self::reap_account_if_empty(who);
+			}
+		}
+	}
+
+	/// Release GenericAsset's provider reference on `who` once it holds no balance of any sufficient asset.
+	/// The system module keeps the account while other modules provide for it or hold consumer references on it.
+	fn reap_account_if_empty(who: &T::AccountId) {
+		if !<ProvidedAccounts<T>>::get(who) || !Self::sufficient_assets_held(who).is_zero() {
+			return;
+		}
+		if <frame_system::Module<T>>::dec_providers(who).is_ok() {
+			<ProvidedAccounts<T>>::remove(who);
+		}
 	}
 
 	/// NOTE: LOW-LEVEL: This will not attempt to maintain total issuance. It is expected that
@@ -955,22 +1957,136 @@ impl<T: Config> Module<T> {
 		} else {
 			<ReservedBalance<T>>::insert(asset_id, who, &reserved);
 		}
+		Self::update_holding(asset_id, who, ExistenceRequirement::AllowDeath);
 	}
 
 	/// NOTE: LOW-LEVEL: This will not attempt to maintain total issuance. It is expected that
 	/// the caller will do this.
 	fn set_free_balance(asset_id: T::AssetId, who: &T::AccountId, free: T::Balance) {
-		<FreeBalance<T>>::mutate(asset_id, who, |balance| {
-			// Tell the system module we are "providing" the account
-			// This is only done so that FRAME pallets from substrate think 
-			// this accounts "exists"
-			if <frame_system::Module<T>>::providers(&who).is_zero() {
-				<frame_system::Module<T>>::inc_providers(&who);
+		Self::write_free_balance(asset_id, who, free, ExistenceRequirement::AllowDeath)
+	}
+
+	/// Set the free balance as `set_free_balance` does, keeping `who` alive with `ExistenceRequirement::KeepAlive`.
+	fn write_free_balance(asset_id: T::AssetId, who: &T::AccountId, free: T::Balance, req: ExistenceRequirement) {
+		if free.is_zero() {
+			// `who` account's free balance storage should be freed
+			<FreeBalance<T>>::remove(asset_id, who);
+		} else {
+			<FreeBalance<T>>::insert(asset_id, who, &free);
+		}
+		Self::update_holding(asset_id, who, req);
+	}
+
+	/// Check the asset symbol is ASCII and no longer than `MaxSymbolLength`.
+	fn ensure_valid_symbol(info: &AssetInfo) -> DispatchResult {
+		ensure!(
+			info.symbol().len() <= T::MaxSymbolLength::get() as usize,
+			Error::<T>::SymbolTooLong
+		);
+		ensure!(info.symbol().is_ascii(), Error::<T>::InvalidSymbol);
+		Ok(())
+	}
+
+	/// Check the asset symbol is not used by an asset other than `asset_id`.
+	fn ensure_symbol_available(asset_id: T::AssetId, symbol: &[u8]) -> DispatchResult {
+		ensure!(
+			Self::id_for_symbol(symbol).map_or(true, |id| id == asset_id),
+			Error::<T>::SymbolTaken
+		);
+		Ok(())
+	}
+
+	/// Index `asset_id` under `symbol`, unless the symbol is empty.
+	fn index_symbol(asset_id: T::AssetId, symbol: &[u8]) {
+		if !symbol.is_empty() {
+			<SymbolToId<T>>::insert(symbol, asset_id);
+		}
+	}
+
+	/// The total issuance an asset is created with, scaled by its decimal places.
+	fn initial_issuance(
+		options: &AssetOptions<T::Balance, T::AccountId>,
+		info: &AssetInfo,
+	) -> Result<T::Balance, DispatchError> {
+		let decimal_factor: T::Balance = 10u128
+			.checked_pow(info.decimal_places().into())
+			.ok_or(Error::<T>::DecimalTooLarge)?
+			.unique_saturated_into();
+		// Assuming that Balance is u128 or less. Implemented in this way for practicality
+		let total_issuance = decimal_factor
+			.checked_mul(&options.initial_issuance)
+			.ok_or(Error::<T>::InitialIssuanceTooLarge)?;
+		Ok(total_issuance)
+	}
+
+	/// Mint to an account's free balance, without event or permission check
+	fn do_mint_free(asset_id: T::AssetId, to: &T::AccountId, amount: &T::Balance) -> DispatchResult {
+		let original_free_balance = Self::free_balance(asset_id, to);
+		let current_total_issuance = <TotalIssuance<T>>::get(asset_id);
+		let new_total_issuance = current_total_issuance
+			.checked_add(amount)
+			.ok_or(Error::<T>::TotalMintingOverflow)?;
+		let value = original_free_balance
+			.checked_add(amount)
+			.ok_or(Error::<T>::FreeMintingOverflow)?;
+
+		<TotalIssuance<T>>::insert(asset_id, new_total_issuance);
+		Self::set_free_balance(asset_id, to, value);
+		Ok(())
+	}
+
+	/// Burn an account's free balance, without event or permission check
+	fn do_burn_free(asset_id: T::AssetId, to: &T::AccountId, amount: &T::Balance) -> DispatchResult {
+		let original_free_balance = Self::free_balance(asset_id, to);
+		let current_total_issuance = <TotalIssuance<T>>::get(asset_id);
+		let new_total_issuance = current_total_issuance
+			.checked_sub(amount)
+			.ok_or(Error::<T>::TotalBurningUnderflow)?;
+		let value = original_free_balance
+			.checked_sub(amount)
+			.ok_or(Error::<T>::FreeBurningUnderflow)?;
+
+		<TotalIssuance<T>>::insert(asset_id, new_total_issuance);
+		Self::set_free_balance(asset_id, to, value);
+		Ok(())
+	}
+
+	/// Add `amount` to the reserve `id` of `who`, which must already be part of their reserved balance.
+	fn add_named_reserve(id: ReserveId, asset_id: T::AssetId, who: &T::AccountId, amount: T::Balance) {
+		<NamedReserves<T>>::mutate(asset_id, who, |reserves| {
+			match reserves.iter_mut().find(|(reserve_id, _)| *reserve_id == id) {
+				Some((_, reserved)) => *reserved += amount,
+				None => reserves.push((id, amount)),
 			}
-			*balance = free
 		});
 	}
 
+	/// Apply `f` to the reserve `id` of `who`, freeing its storage once it is emptied.
+	/// Returns `None` if `who` has nothing reserved under `id`.
+	fn mutate_named_reserve<R>(
+		id: ReserveId,
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		f: impl FnOnce(&mut T::Balance) -> R,
+	) -> Option<R> {
+		let mut reserves = Self::named_reserves(asset_id, who);
+		let index = reserves.iter().position(|(reserve_id, _)| *reserve_id == id)?;
+		let result = f(&mut reserves[index].1);
+
+		if reserves[index].1.is_zero() {
+			reserves.remove(index);
+		}
+		if reserves.is_empty() {
+			<NamedReserves<T>>::remove(asset_id, who);
+		} else {
+			<NamedReserves<T>>::insert(asset_id, who, reserves);
+		}
+
+		Some(result)
+	}
+
+	/// Set a lock, replacing any lock with the same id.
+	/// The lock is always set, even beyond `MaxLocks` locks, use `try_set_lock` to enforce the limit.
 	fn set_lock(
 		id: LockIdentifier,
 		asset_id: T::AssetId,
@@ -978,17 +2094,79 @@ impl<T: Config> Module<T> {
 		amount: T::Balance,
 		reasons: WithdrawReasons,
 	) {
-		let mut new_lock = Some(BalanceLock { id, amount, reasons });
-		let mut locks = <Module<T>>::locks(asset_id, who)
+		let lock = BalanceLock {
+			id,
+			amount,
+			reasons,
+			until: None,
+		};
+		Self::insert_lock(asset_id, who, lock);
+	}
+
+	/// Set a lock as `set_lock` does, failing with `TooManyLocks` if the lock id is new and `who`
+	/// already holds `MaxLocks` locks.
+	pub fn try_set_lock(
+		id: LockIdentifier,
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reasons: WithdrawReasons,
+	) -> DispatchResult {
+		let locks = Self::active_locks(asset_id, who);
+		ensure!(
+			locks.iter().any(|l| l.id == id) || locks.len() < T::MaxLocks::get() as usize,
+			Error::<T>::TooManyLocks
+		);
+		Self::set_lock(id, asset_id, who, amount, reasons);
+		Ok(())
+	}
+
+	/// Set a lock as `set_lock` does, which is released from block `until` onwards.
+	pub fn set_lock_until(
+		id: LockIdentifier,
+		asset_id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reasons: WithdrawReasons,
+		until: T::BlockNumber,
+	) {
+		let lock = BalanceLock {
+			id,
+			amount,
+			reasons,
+			until: Some(until),
+		};
+		Self::insert_lock(asset_id, who, lock);
+	}
+
+	/// Insert `new_lock`, replacing any lock with the same id.
+	fn insert_lock(asset_id: T::AssetId, who: &T::AccountId, new_lock: BalanceLock<T::Balance, T::BlockNumber>) {
+		let id = new_lock.id;
+		let mut new_lock = Some(new_lock);
+		let mut locks = Self::active_locks(asset_id, who)
 			.into_iter()
 			.filter_map(|l| if l.id == id { new_lock.take() } else { Some(l) })
 			.collect::<Vec<_>>();
 		if let Some(lock) = new_lock {
 			locks.push(lock)
 		}
+		Self::update_locks(asset_id, who, locks);
+	}
+
+	/// Store the locks of `who`. A lock is never dropped, as the balances pallet does a warning is logged instead
+	/// once `who` holds more than `MaxLocks` locks.
+	fn update_locks(asset_id: T::AssetId, who: &T::AccountId, locks: Vec<BalanceLock<T::Balance, T::BlockNumber>>) {
+		if locks.len() as u32 > T::MaxLocks::get() {
+			log::warn!(
+				target: "runtime::generic-asset",
+				"Warning: A user has more currency locks than expected. \
+				A runtime configuration adjustment may be needed."
+			);
+		}
 		<Locks<T>>::insert(asset_id, who, locks);
 	}
 
+	/// Extend a lock, or set it if there is no lock with the same id.
 	fn extend_lock(
 		id: LockIdentifier,
 		asset_id: T::AssetId,
@@ -996,15 +2174,22 @@ impl<T: Config> Module<T> {
 		amount: T::Balance,
 		reasons: WithdrawReasons,
 	) {
-		let mut new_lock = Some(BalanceLock { id, amount, reasons });
-		let mut locks = <Module<T>>::locks(asset_id, who)
+		let mut new_lock = Some(BalanceLock {
+			id,
+			amount,
+			reasons,
+			until: None,
+		});
+		let mut locks = Self::active_locks(asset_id, who)
 			.into_iter()
 			.filter_map(|l| {
 				if l.id == id {
+					// The extended lock no longer expires
 					new_lock.take().map(|nl| BalanceLock {
 						id: l.id,
 						amount: l.amount.max(nl.amount),
 						reasons: l.reasons | nl.reasons,
+						until: None,
 					})
 				} else {
 					Some(l)
@@ -1014,11 +2199,21 @@ impl<T: Config> Module<T> {
 		if let Some(lock) = new_lock {
 			locks.push(lock)
 		}
-		<Locks<T>>::insert(asset_id, who, locks);
+		Self::update_locks(asset_id, who, locks);
+	}
+
+	/// The locks of `who` which have not yet expired.
+	/// Expired locks are pruned from storage whenever the locks of an account are next written.
+	pub fn active_locks(asset_id: T::AssetId, who: &T::AccountId) -> Vec<BalanceLock<T::Balance, T::BlockNumber>> {
+		let now = <frame_system::Module<T>>::block_number();
+		Self::locks(asset_id, who)
+			.into_iter()
+			.filter(|l| l.is_active(&now))
+			.collect()
 	}
 
 	fn remove_lock(id: LockIdentifier, asset_id: T::AssetId, who: &T::AccountId) {
-		let mut locks = <Module<T>>::locks(asset_id, who);
+		let mut locks = Self::active_locks(asset_id, who);
 		locks.retain(|l| l.id != id);
 		if locks.is_empty() {
 			<Locks<T>>::remove(asset_id, who);
@@ -1028,6 +2223,24 @@ impl<T: Config> Module<T> {
 	}
 }
 
+/// Reclaim the dust left by an account reaped by the system module, so no empty generic asset storage is orphaned.
+/// Balances which are not dust are kept, GenericAsset provides for the account again once they next change.
+///
+/// While GenericAsset provides for an account it cannot be reaped, so this only visits the assets indexed for an
+/// account another module reaped out from under GenericAsset.
+impl<T: Config> OnKilledAccount<T::AccountId> for Module<T> {
+	fn on_killed_account(who: &T::AccountId) {
+		<ProvidedAccounts<T>>::remove(who);
+		let asset_ids = <HeldAssets<T>>::iter_prefix(who).map(|(asset_id, _)| asset_id).collect::<Vec<_>>();
+		for asset_id in asset_ids {
+			let free = Self::free_balance(asset_id, who);
+			if Self::reserved_balance(asset_id, who).is_zero() && Self::is_dust(asset_id, free) {
+				Self::reclaim_free_balance(asset_id, who, ExistenceRequirement::AllowDeath);
+			}
+		}
+	}
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
 pub struct AssetCurrency<T, U>(sp_std::marker::PhantomData<T>, sp_std::marker::PhantomData<U>);
 
@@ -1081,22 +2294,17 @@ where
 		who: &T::AccountId,
 		value: Self::Balance,
 		reasons: WithdrawReasons,
-		_req: ExistenceRequirement,
+		req: ExistenceRequirement,
 	) -> result::Result<Self::NegativeImbalance, DispatchError> {
 		let new_balance = Self::free_balance(who)
 			.checked_sub(&value)
 			.ok_or(Error::<T>::InsufficientBalance)?;
 		Self::ensure_can_withdraw(who, value, reasons, new_balance)?;
 
+		<Module<T>>::write_free_balance(U::asset_id(), who, new_balance, req);
 		// `free` balance should be freed if set to a dust amount
-		if new_balance < Self::minimum_balance() {
-			let amount = <FreeBalance<T>>::take(U::asset_id(), who);
-			if amount > Zero::zero() {
-				T::OnDustImbalance::on_nonzero_unbalanced(NegativeImbalance::new(amount, U::asset_id()));
-			}
-			<Module<T>>::deposit_event(Event::<T>::DustReclaimed(U::asset_id(), who.clone(), amount));
-		}  else {
-			<Module<T>>::set_free_balance(U::asset_id(), who, new_balance);
+		if <Module<T>>::is_dust(U::asset_id(), new_balance) {
+			<Module<T>>::reclaim_free_balance(U::asset_id(), who, req);
 		}
 
 		Ok(NegativeImbalance::new(value, U::asset_id()))
@@ -1137,17 +2345,19 @@ where
 		imbalance
 	}
 
+	/// Whether `value` could be slashed from the free balance of `who`, any lock restricting withdrawals
+	/// prevents it.
 	fn can_slash(who: &T::AccountId, value: Self::Balance) -> bool {
-		<Module<T>>::free_balance(U::asset_id(), &who) >= value
+		<Module<T>>::free_balance(U::asset_id(), &who)
+			.checked_sub(&value)
+			.map_or(false, |new_balance| {
+				<Module<T>>::ensure_can_withdraw(U::asset_id(), who, value, WithdrawReasons::all(), new_balance)
+					.is_ok()
+			})
 	}
 
 	fn slash(who: &T::AccountId, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
-		let remaining = <Module<T>>::slash(U::asset_id(), who, value);
-		if let Some(r) = remaining {
-			(NegativeImbalance::new(value - r, U::asset_id()), r)
-		} else {
-			(NegativeImbalance::new(value, U::asset_id()), Zero::zero())
-		}
+		<Module<T>>::slash_and_settle(U::asset_id(), who, value)
 	}
 
 	fn burn(mut amount: Self::Balance) -> Self::PositiveImbalance {
@@ -1194,7 +2404,7 @@ where
 	}
 
 	fn unreserve(who: &T::AccountId, value: Self::Balance) -> Self::Balance {
-		<Module<T>>::unreserve(U::asset_id(), who, value)
+		<Module<T>>::unreserve_silent(U::asset_id(), who, value)
 	}
 
 	fn slash_reserved(who: &T::AccountId, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
@@ -1206,9 +2416,48 @@ where
 		slashed: &T::AccountId,
 		beneficiary: &T::AccountId,
 		value: Self::Balance,
-		_status: BalanceStatus,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError> {
+		<Module<T>>::repatriate_reserved(U::asset_id(), slashed, beneficiary, value, status)
+	}
+}
+
+impl<T, U> NamedReservableCurrency<T::AccountId> for AssetCurrency<T, U>
+where
+	T: Config,
+	U: AssetIdAuthority<AssetId = T::AssetId>,
+{
+	type ReserveIdentifier = ReserveId;
+
+	fn slash_reserved_named(
+		id: &ReserveId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> (Self::NegativeImbalance, Self::Balance) {
+		let leftover = <Module<T>>::slash_reserved_named(*id, U::asset_id(), who, value).unwrap_or_else(Zero::zero);
+		(NegativeImbalance::new(value - leftover, U::asset_id()), leftover)
+	}
+
+	fn reserved_balance_named(id: &ReserveId, who: &T::AccountId) -> Self::Balance {
+		<Module<T>>::reserved_balance_named(*id, U::asset_id(), who)
+	}
+
+	fn reserve_named(id: &ReserveId, who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		<Module<T>>::reserve_named(*id, U::asset_id(), who, value)
+	}
+
+	fn unreserve_named(id: &ReserveId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		<Module<T>>::unreserve_named(*id, U::asset_id(), who, value)
+	}
+
+	fn repatriate_reserved_named(
+		id: &ReserveId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
 	) -> result::Result<Self::Balance, DispatchError> {
-		<Module<T>>::repatriate_reserved(U::asset_id(), slashed, beneficiary, value)
+		<Module<T>>::repatriate_reserved_named(*id, U::asset_id(), slashed, beneficiary, value, status)
 	}
 }
 
@@ -1237,7 +2486,7 @@ where
 	U: AssetIdAuthority<AssetId = T::AssetId>,
 {
 	type Moment = T::BlockNumber;
-	type MaxLocks = ();
+	type MaxLocks = T::MaxLocks;
 
 	fn set_lock(id: LockIdentifier, who: &T::AccountId, amount: T::Balance, reasons: WithdrawReasons) {
 		<Module<T>>::set_lock(id, U::asset_id(), who, amount, reasons)
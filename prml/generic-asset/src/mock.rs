@@ -29,7 +29,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 	ModuleId,
 };
-use sp_std::mem;
+use sp_std::{cell::RefCell, mem};
 
 // test accounts
 pub const ALICE: u64 = 1;
@@ -54,6 +54,8 @@ pub const INITIAL_BALANCE: u64 = 100;
 pub const ID_1: LockIdentifier = *b"1       ";
 // lock identifier
 pub const ID_2: LockIdentifier = *b"2       ";
+// lock identifier
+pub const ID_3: LockIdentifier = *b"3       ";
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -95,13 +97,18 @@ impl frame_system::Config for Test {
 	type PalletInfo = PalletInfo;
 	type AccountData = ();
 	type OnNewAccount = ();
-	type OnKilledAccount = ();
+	type OnKilledAccount = GenericAsset;
 	type SystemWeightInfo = ();
 	type SS58Prefix = ();
 }
 
 parameter_types! {
 	pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
+	pub const GenericAssetModuleId: ModuleId = ModuleId(*b"py/genas");
+	pub const MaxSymbolLength: u32 = 8;
+	pub const MaxMintBatch: u32 = 3;
+	pub const MaxMemoLength: u32 = 8;
+	pub const MaxLocks: u32 = 2;
 }
 pub struct TransferImbalanceToTreasury;
 impl OnUnbalanced<NegativeImbalance<Test>> for TransferImbalanceToTreasury {
@@ -117,11 +124,40 @@ impl OnUnbalanced<NegativeImbalance<Test>> for TransferImbalanceToTreasury {
 	}
 }
 
+thread_local! {
+	static NEW_ASSETS: RefCell<Vec<u32>> = RefCell::new(vec![]);
+}
+/// Records the ids of created assets
+pub struct RecordNewAssets;
+impl OnNewAsset<u32> for RecordNewAssets {
+	fn on_new_asset(asset_id: u32) {
+		NEW_ASSETS.with(|assets| assets.borrow_mut().push(asset_id));
+	}
+}
+/// The ids of assets created so far, in order
+pub(crate) fn new_assets() -> Vec<u32> {
+	NEW_ASSETS.with(|assets| assets.borrow().clone())
+}
+
+/// Blocks transfers to the generic asset module account
+pub struct BlockedRecipients;
+impl Contains<u64> for BlockedRecipients {
+	fn sorted_members() -> Vec<u64> {
+		vec![GenericAssetModuleId::get().into_account()]
+	}
+}
+
 impl Config for Test {
 	type Balance = u64;
 	type AssetId = u32;
 	type Event = Event;
 	type OnDustImbalance = TransferImbalanceToTreasury;
+	type MaxSymbolLength = MaxSymbolLength;
+	type MaxMintBatch = MaxMintBatch;
+	type MaxMemoLength = MaxMemoLength;
+	type MaxLocks = MaxLocks;
+	type OnNewAsset = RecordNewAssets;
+	type BlockedRecipients = BlockedRecipients;
 	type WeightInfo = ();
 }
 
@@ -133,6 +169,7 @@ pub(crate) fn new_test_ext(
 	permissions: Vec<(u32, u64)>,
 	next_asset_id: u32,
 ) -> sp_io::TestExternalities {
+	NEW_ASSETS.with(|assets| assets.borrow_mut().clear());
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 
 	prml_generic_asset::GenesisConfig::<Test> {
@@ -144,7 +181,7 @@ pub(crate) fn new_test_ext(
 		spending_asset_id: SPENDING_ASSET_ID,
 		permissions,
 		asset_meta: vec![
-			(TEST1_ASSET_ID, AssetInfo::new(b"TST1".to_vec(), 1, 3)),
+			(TEST1_ASSET_ID, AssetInfo::new(b"TEST1".to_vec(), 1, 3)),
 			(TEST2_ASSET_ID, AssetInfo::new(b"TST 2".to_vec(), 2, 5)),
 		],
 	}
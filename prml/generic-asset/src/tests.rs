@@ -23,14 +23,15 @@
 use super::*;
 use crate::mock::{
 	new_test_ext_with_balance, new_test_ext_with_default, new_test_ext_with_next_asset_id,
-	new_test_ext_with_permissions, Event as TestEvent, GenericAsset, NegativeImbalanceOf, Origin, PositiveImbalanceOf,
-	System, Test, TreasuryModuleId, ALICE, ASSET_ID, BOB, CHARLIE, ID_1, ID_2, INITIAL_BALANCE, INITIAL_ISSUANCE,
-	SPENDING_ASSET_ID, STAKING_ASSET_ID, TEST1_ASSET_ID, TEST2_ASSET_ID,
+	new_test_ext_with_permissions, Event as TestEvent, GenericAsset, GenericAssetModuleId, NegativeImbalanceOf, Origin,
+	PositiveImbalanceOf, System, Test, TreasuryModuleId, ALICE, ASSET_ID, BOB, CHARLIE, ID_1, ID_2, ID_3,
+	INITIAL_BALANCE, INITIAL_ISSUANCE, SPENDING_ASSET_ID, STAKING_ASSET_ID, TEST1_ASSET_ID, TEST2_ASSET_ID,
 };
 use crate::CheckedImbalance;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{Imbalance, OnRuntimeUpgrade},
+	weights::GetDispatchInfo,
 };
 use sp_runtime::traits::AccountIdConversion;
 
@@ -42,6 +43,14 @@ fn asset_options(permissions: PermissionLatest<u64>, decimal_place: u8) -> Asset
 	}
 }
 
+/// Create `accounts` in the system module, as another module would, so they may receive assets which are not
+/// sufficient.
+fn create_accounts(accounts: &[u64]) {
+	accounts.iter().for_each(|who| {
+		System::inc_providers(who);
+	});
+}
+
 #[test]
 fn issuing_asset_units_to_issuer_should_work() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
@@ -86,6 +95,7 @@ fn issuing_with_next_asset_id_overflow_should_fail() {
 #[test]
 fn querying_total_supply_should_work() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		create_accounts(&[BOB, CHARLIE]);
 		let permissions = PermissionLatest::new(ALICE);
 		let transfer_amount = 50;
 		let asset_info = AssetInfo::default();
@@ -142,6 +152,7 @@ fn querying_total_supply_should_work() {
 #[test]
 fn transferring_amount_should_work() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		create_accounts(&[BOB]);
 		let permissions = PermissionLatest::new(ALICE);
 		let transfer_ammount = 40;
 		let asset_info = AssetInfo::default();
@@ -201,6 +212,77 @@ fn transferring_amount_more_than_free_balance_should_fail() {
 	});
 }
 
+#[test]
+fn transfer_with_memo_should_work_as_transfer() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(GenericAsset::transfer_with_memo(
+			Origin::signed(ALICE),
+			STAKING_ASSET_ID,
+			BOB,
+			40,
+			b"deposit1".to_vec()
+		));
+		assert_ok!(GenericAsset::transfer(Origin::signed(ALICE), STAKING_ASSET_ID, CHARLIE, 40));
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE - 80);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 40);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &CHARLIE), 40);
+
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::TransferredWithMemo(
+			STAKING_ASSET_ID,
+			ALICE,
+			BOB,
+			40,
+			b"deposit1".to_vec(),
+		));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn transfer_with_memo_longer_than_max_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// The mock `MaxMemoLength` is 8
+		assert_noop!(
+			GenericAsset::transfer_with_memo(
+				Origin::signed(ALICE),
+				STAKING_ASSET_ID,
+				BOB,
+				40,
+				b"deposit12".to_vec()
+			),
+			Error::<Test>::MemoTooLong
+		);
+	});
+}
+
+#[test]
+fn transfer_by_symbol_should_work_as_transfer() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		create_accounts(&[BOB]);
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE);
+
+		assert_ok!(GenericAsset::transfer_by_symbol(Origin::signed(ALICE), b"TEST1".to_vec(), BOB, 40));
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &ALICE), INITIAL_BALANCE - 40);
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &BOB), 40);
+
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::Transferred(TEST1_ASSET_ID, ALICE, BOB, 40));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn transfer_by_unknown_symbol_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_noop!(
+			GenericAsset::transfer_by_symbol(Origin::signed(ALICE), b"NOPE".to_vec(), BOB, 40),
+			Error::<Test>::AssetIdNotExist
+		);
+	});
+}
+
 #[test]
 fn transferring_less_than_one_unit_should_fail() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
@@ -274,275 +356,1304 @@ fn transfer_with_allow_death_existential_requirement() {
 			ExistenceRequirement::AllowDeath
 		));
 
-		assert!(System::account_exists(&BOB));
+		// BOB no longer holds any asset
+		assert!(!System::account_exists(&BOB));
 		assert!(!<FreeBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
 	});
 }
 
 #[test]
-fn free_balance_storage_freed_on_transfer() {
+fn transfer_with_allow_death_keeps_account_with_consumers() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
 		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
-		GenericAsset::set_reserved_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+		assert_ok!(System::inc_consumers(&BOB));
+		assert_ok!(StakingAssetCurrency::<Test>::transfer(
+			&BOB,
+			&ALICE,
+			INITIAL_BALANCE,
+			ExistenceRequirement::AllowDeath
+		));
 
 		assert!(System::account_exists(&BOB));
+		assert!(!<FreeBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
+	});
+}
+
+#[test]
+fn transfer_with_allow_death_keeps_account_holding_sufficient_assets() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		<AssetMeta<Test>>::mutate(TEST1_ASSET_ID, |info| info.set_sufficient(true));
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &BOB, INITIAL_BALANCE);
+		<TotalIssuance<Test>>::insert(TEST1_ASSET_ID, INITIAL_BALANCE);
 		assert_ok!(GenericAsset::transfer(
 			Origin::signed(BOB),
 			STAKING_ASSET_ID,
 			ALICE,
 			INITIAL_BALANCE
 		));
-		assert!(System::account_exists(&BOB));
 
-		// free balance storage should be freed
-		assert!(!<FreeBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
+		// BOB holds none of the native asset, only the sufficient one
+		assert!(System::account_exists(&BOB));
 	});
 }
 
 #[test]
-fn reserve_balance_storage_freed_on_unreserve() {
-	new_test_ext_with_default().execute_with(|| {
-		let balance = 100;
-		GenericAsset::set_reserved_balance(STAKING_ASSET_ID, &BOB, balance);
-
-		assert!(GenericAsset::unreserve(
+fn transfer_with_allow_death_reaps_account_holding_only_insufficient_assets() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert!(!GenericAsset::is_sufficient(TEST1_ASSET_ID));
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &BOB, INITIAL_BALANCE);
+		<TotalIssuance<Test>>::insert(TEST1_ASSET_ID, INITIAL_BALANCE);
+		assert_eq!(GenericAsset::sufficient_assets_held(&BOB), 1);
+		assert_ok!(GenericAsset::transfer(
+			Origin::signed(BOB),
 			STAKING_ASSET_ID,
-			&BOB,
-			balance
-		).is_zero());
+			ALICE,
+			INITIAL_BALANCE
+		));
 
-		assert!(!<ReservedBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
+		// The asset which is not sufficient cannot keep BOB alive, its balance is kept all the same
+		assert!(!System::account_exists(&BOB));
+		assert!(!<ProvidedAccounts<Test>>::get(&BOB));
+		assert_eq!(GenericAsset::sufficient_assets_held(&BOB), 0);
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &BOB), INITIAL_BALANCE);
 	});
 }
 
 #[test]
-fn lock_storage_is_freed_when_empty() {
+fn transfer_of_insufficient_asset_to_new_account_should_fail() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
-		let lock_1 = BalanceLock {
-			id: ID_1,
-			amount: 3u64,
-			reasons: WithdrawReasons::TRANSACTION_PAYMENT,
-		};
-		let alice_locks = vec![lock_1];
-		<Locks<Test>>::insert(STAKING_ASSET_ID, ALICE, &alice_locks);
-
-		GenericAsset::remove_lock(ID_1, STAKING_ASSET_ID, &ALICE);
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE);
+		assert_noop!(
+			GenericAsset::transfer(Origin::signed(ALICE), TEST1_ASSET_ID, BOB, 40),
+			Error::<Test>::NoProvider
+		);
 
-		// lock storage released
-		assert!(!Locks::<Test>::contains_key(STAKING_ASSET_ID, &ALICE));
+		// BOB exists once it holds a sufficient asset
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+		assert_ok!(GenericAsset::transfer(Origin::signed(ALICE), TEST1_ASSET_ID, BOB, 40));
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &BOB), 40);
 	});
 }
 
 #[test]
-fn balance_falls_below_a_non_default_existential_deposit() {
+fn transfer_with_allow_death_only_releases_the_generic_asset_provider() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
-		// Create an asset with ED = 11
-		let existential_deposit = 11;
-		let asset_info = AssetInfo::new(b"TST1".to_vec(), 1, existential_deposit);
-		assert_ok!(GenericAsset::create(
-			Origin::root(),
-			ALICE,
-			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
-			asset_info.clone()
-		));
-		GenericAsset::set_free_balance(ASSET_ID, &BOB, INITIAL_BALANCE);
-		assert!(System::account_exists(&BOB));
+		// Another module provides for BOB before it receives any asset
+		System::inc_providers(&BOB);
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+		assert_eq!(System::providers(&BOB), 2);
 
-		// Transfer BOB's balance down to ED (should not be reaped yet)
 		assert_ok!(GenericAsset::transfer(
 			Origin::signed(BOB),
-			ASSET_ID,
+			STAKING_ASSET_ID,
 			ALICE,
-			INITIAL_BALANCE - asset_info.existential_deposit()
+			INITIAL_BALANCE
 		));
-		assert!(System::account_exists(&BOB));
-		assert!(<FreeBalance<Test>>::contains_key(ASSET_ID, &BOB));
+		assert!(!GenericAsset::holds_any_asset(&BOB));
+		assert_eq!(System::providers(&BOB), 1);
 
-		// Transfer BOB's balance down to ED - 1, it should be reaped
-		assert_ok!(GenericAsset::transfer(Origin::signed(BOB), ASSET_ID, ALICE, 1));
+		// Reaping again does not touch the other module's reference
+		GenericAsset::reap_account_if_empty(&BOB);
+		assert_eq!(System::providers(&BOB), 1);
+		assert!(System::account_exists(&BOB));
+	});
+}
 
+#[test]
+fn burning_the_last_asset_reaps_the_account() {
+	new_test_ext_with_permissions(vec![(STAKING_ASSET_ID, ALICE)]).execute_with(|| {
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+		<TotalIssuance<Test>>::insert(STAKING_ASSET_ID, INITIAL_BALANCE);
 		assert!(System::account_exists(&BOB));
-		assert!(!<FreeBalance<Test>>::contains_key(ASSET_ID, &BOB));
+
+		assert_ok!(GenericAsset::burn(Origin::signed(ALICE), STAKING_ASSET_ID, BOB, INITIAL_BALANCE));
+		assert!(!GenericAsset::holds_any_asset(&BOB));
+		assert!(!<ProvidedAccounts<Test>>::get(&BOB));
+		assert!(!System::account_exists(&BOB));
 	});
 }
 
 #[test]
-fn minimum_balance_is_existential_deposit() {
-	new_test_ext_with_permissions(vec![(STAKING_ASSET_ID, ALICE), (SPENDING_ASSET_ID, ALICE)]).execute_with(|| {
-		let stk_min = 11u64;
-		let spd_min = 17u64;
-		let staking_asset_info = AssetInfo::new(b"STK".to_vec(), 1, stk_min);
-		let spending_asset_info = AssetInfo::new(b"SPD".to_vec(), 2, spd_min);
-		assert_ok!(GenericAsset::create_asset(
-			Some(STAKING_ASSET_ID),
-			Some(ALICE),
-			asset_options(PermissionLatest::new(ALICE), staking_asset_info.decimal_places()),
-			staking_asset_info
-		));
-		assert_ok!(GenericAsset::create_asset(
-			Some(SPENDING_ASSET_ID),
-			Some(ALICE),
-			asset_options(PermissionLatest::new(ALICE), spending_asset_info.decimal_places()),
-			spending_asset_info
-		));
-		assert_eq!(StakingAssetCurrency::<Test>::minimum_balance(), stk_min);
-		assert_eq!(SpendingAssetCurrency::<Test>::minimum_balance(), spd_min);
+fn slashing_the_last_asset_reaps_the_account() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, 70);
+		GenericAsset::set_reserved_balance(STAKING_ASSET_ID, &BOB, 30);
+		assert!(System::account_exists(&BOB));
+
+		let (imbalance, remaining) = StakingAssetCurrency::<Test>::slash(&BOB, INITIAL_BALANCE);
+		assert_eq!(imbalance.peek(), INITIAL_BALANCE);
+		assert_eq!(remaining, 0);
+		assert!(!GenericAsset::holds_any_asset(&BOB));
+		assert!(!<ProvidedAccounts<Test>>::get(&BOB));
+		assert!(!System::account_exists(&BOB));
 	});
 }
 
 #[test]
-fn on_dust_imbalance_hook_invoked() {
-	new_test_ext_with_default().execute_with(|| {
-		let existential_deposit = 5;
-		let asset_info = AssetInfo::new(b"TST1".to_vec(), 1, existential_deposit);
-		assert_ok!(GenericAsset::create(
-			Origin::root(),
-			BOB,
-			// this will issue INITIAL_ISSUANCE / 1 tokens of ASSET_ID
-			asset_options(PermissionLatest::new(BOB), asset_info.decimal_places()),
-			asset_info.clone()
-		));
+fn killing_an_account_only_reclaims_its_dust() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, 10);
+		GenericAsset::set_reserved_balance(STAKING_ASSET_ID, &BOB, 5);
+		GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &BOB, 3, WithdrawReasons::TRANSFER);
+		// Below the TEST1 existential deposit of 3
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &BOB, 2);
+
+		// Another module drops the provider reference GenericAsset holds, so the system module reaps BOB
+		assert_ok!(System::dec_providers(&BOB));
+		assert!(!System::account_exists(&BOB));
 
-		// Transfer all tokens til we're 1 below ED
-		assert_ok!(GenericAsset::transfer(
-			Origin::signed(BOB),
-			ASSET_ID,
-			ALICE,
-			INITIAL_ISSUANCE - asset_info.existential_deposit() + 1
-		));
+		// BOB's funds are kept
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 10);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &BOB), 5);
+		assert!(<Locks<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
 
-		// Our test hook transfers dust to the treasury account
-		// Treasury account should get the dust (ED - 1)
+		// Only the dust is reclaimed
+		assert!(!<FreeBalance<Test>>::contains_key(TEST1_ASSET_ID, &BOB));
 		let treasury_account_id = TreasuryModuleId::get().into_account();
-		assert_eq!(
-			GenericAsset::free_balance(ASSET_ID, &treasury_account_id),
-			asset_info.existential_deposit() - 1
-		);
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &treasury_account_id), 2);
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::DustReclaimed(TEST1_ASSET_ID, BOB, 2));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+		assert_eq!(GenericAsset::total_dust_reclaimed(STAKING_ASSET_ID), 0);
 
-		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE);
+		// GenericAsset provides for BOB again with the next change to its balances
+		assert_ok!(GenericAsset::transfer(Origin::signed(BOB), STAKING_ASSET_ID, ALICE, 1));
+		assert!(System::account_exists(&BOB));
 	});
 }
 
 #[test]
-fn on_runtime_upgrade() {
+fn native_assets_are_always_sufficient() {
+	new_test_ext_with_default().execute_with(|| {
+		assert!(!GenericAsset::asset_meta(STAKING_ASSET_ID).sufficient());
+		assert!(GenericAsset::is_sufficient(STAKING_ASSET_ID));
+		assert!(GenericAsset::is_sufficient(SPENDING_ASSET_ID));
+		assert!(!GenericAsset::is_sufficient(TEST2_ASSET_ID));
+	});
+}
+
+#[test]
+fn free_balance_storage_freed_on_transfer() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
-		let asset_info_1 = AssetInfo::new(b"TST1".to_vec(), 1, 11);
-		let asset_info_2 = AssetInfo::new(b"TST2".to_vec(), 4, 7);
-		assert_ok!(GenericAsset::create(
-			Origin::root(),
-			BOB,
-			asset_options(PermissionLatest::new(BOB), asset_info_1.decimal_places()),
-			asset_info_1.clone()
-		));
-		assert_ok!(GenericAsset::create(
-			Origin::root(),
-			BOB,
-			asset_options(PermissionLatest::new(BOB), asset_info_2.decimal_places()),
-			asset_info_2.clone()
-		));
-		// Transfer Alice some dust (asset 1)
-		// It should be freed during the migration
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+		GenericAsset::set_reserved_balance(STAKING_ASSET_ID, &BOB, INITIAL_BALANCE);
+
+		assert!(System::account_exists(&BOB));
 		assert_ok!(GenericAsset::transfer(
 			Origin::signed(BOB),
-			ASSET_ID,
+			STAKING_ASSET_ID,
 			ALICE,
-			asset_info_1.existential_deposit() - 1,
+			INITIAL_BALANCE
 		));
-		// Reduce BOB's (asset 1) balance below ED
-		// It should be freed during the migration
-		GenericAsset::set_free_balance(ASSET_ID, &BOB, asset_info_1.existential_deposit() - 1);
-
-		// Set a balance directly, its 'provided' counter = 0
-		<FreeBalance<Test>>::insert(ASSET_ID, &CHARLIE, 100);
-		assert!(!System::account_exists(&CHARLIE));
-
-		// On runtime upgrade should be able to fix the account store
-		let _ = GenericAsset::on_runtime_upgrade();
-
-		// Test accounts are restored now
-		assert!(System::account_exists(&ALICE));
 		assert!(System::account_exists(&BOB));
-		assert!(System::account_exists(&CHARLIE));
-
-		// Test assets of Alice are as before
-		assert_eq!(<FreeBalance<Test>>::get(&STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE);
-		// Test Alice's dust asset 1 free balance is freed
-		assert!(!<FreeBalance<Test>>::contains_key(&ASSET_ID, &ALICE));
-
-		// Test BOB's dust asset 1 free balance is freed
-		assert!(!<FreeBalance<Test>>::contains_key(ASSET_ID, BOB));
-
-		// Test asset 2 free balance is unchanged
-		assert_eq!(<FreeBalance<Test>>::get(&(ASSET_ID + 1), &BOB), INITIAL_ISSUANCE);
-
-		// Our test hook transfers dust to the treasury account
-		// Treasury account should get the dust (ED - 1)
-		let treasury_account_id = TreasuryModuleId::get().into_account();
-		assert_eq!(
-			GenericAsset::free_balance(ASSET_ID, &treasury_account_id),
-			(asset_info_1.existential_deposit() - 1) * 2 // sum of Alice & Bob's dust
-		);
 
-		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE);
+		// free balance storage should be freed
+		assert!(!<FreeBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
 	});
 }
 
 #[test]
-fn migrate_locks_on_runtime_upgrade() {
-	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
-		#[allow(dead_code)]
-		mod old_storage {
-			use super::Config;
-			use crate::types::BalanceLock;
-
+fn reserve_balance_storage_freed_on_unreserve() {
+	new_test_ext_with_default().execute_with(|| {
+		let balance = 100;
+		GenericAsset::set_reserved_balance(STAKING_ASSET_ID, &BOB, balance);
+
+		assert!(GenericAsset::unreserve(
+			STAKING_ASSET_ID,
+			&BOB,
+			balance
+		).is_zero());
+
+		assert!(!<ReservedBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
+	});
+}
+
+#[test]
+fn named_reserves_should_not_interfere() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let staking: ReserveId = *b"staking ";
+		let market: ReserveId = *b"market  ";
+
+		assert_ok!(GenericAsset::reserve_named(staking, STAKING_ASSET_ID, &ALICE, 30));
+		assert_ok!(GenericAsset::reserve_named(market, STAKING_ASSET_ID, &ALICE, 20));
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 50);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE - 50);
+
+		// Unreserving more than was reserved under `market` only releases the `market` portion
+		assert_eq!(GenericAsset::unreserve_named(market, STAKING_ASSET_ID, &ALICE, 50), 30);
+		assert_eq!(GenericAsset::reserved_balance_named(market, STAKING_ASSET_ID, &ALICE), 0);
+		assert_eq!(GenericAsset::reserved_balance_named(staking, STAKING_ASSET_ID, &ALICE), 30);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 30);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE - 30);
+
+		// Slashing `market` has nothing to take
+		assert_eq!(GenericAsset::slash_reserved_named(market, STAKING_ASSET_ID, &ALICE, 10), Some(10));
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 30);
+
+		assert_eq!(GenericAsset::slash_reserved_named(staking, STAKING_ASSET_ID, &ALICE, 10), None);
+		assert_eq!(GenericAsset::reserved_balance_named(staking, STAKING_ASSET_ID, &ALICE), 20);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 20);
+	});
+}
+
+#[test]
+fn named_reserve_storage_freed_when_empty() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let staking: ReserveId = *b"staking ";
+		let market: ReserveId = *b"market  ";
+
+		assert_ok!(GenericAsset::reserve_named(staking, STAKING_ASSET_ID, &ALICE, 30));
+		assert_ok!(GenericAsset::reserve_named(market, STAKING_ASSET_ID, &ALICE, 20));
+
+		assert!(GenericAsset::unreserve_named(staking, STAKING_ASSET_ID, &ALICE, 30).is_zero());
+		assert_eq!(GenericAsset::named_reserves(STAKING_ASSET_ID, &ALICE), vec![(market, 20)]);
+
+		assert_eq!(GenericAsset::slash_reserved_named(market, STAKING_ASSET_ID, &ALICE, 20), None);
+		assert!(!<NamedReserves<Test>>::contains_key(STAKING_ASSET_ID, &ALICE));
+	});
+}
+
+#[test]
+fn reserve_named_more_than_free_balance_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let staking: ReserveId = *b"staking ";
+
+		assert_noop!(
+			GenericAsset::reserve_named(staking, STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE + 1),
+			Error::<Test>::InsufficientBalance,
+		);
+		assert!(!<NamedReserves<Test>>::contains_key(STAKING_ASSET_ID, &ALICE));
+	});
+}
+
+/// A downstream pallet which only knows about `NamedReservableCurrency`
+struct Market<C>(sp_std::marker::PhantomData<C>);
+
+impl<C: NamedReservableCurrency<u64, ReserveIdentifier = ReserveId>> Market<C> {
+	const ID: ReserveId = *b"market  ";
+
+	fn bid(who: &u64, amount: C::Balance) -> DispatchResult {
+		C::reserve_named(&Self::ID, who, amount)
+	}
+
+	fn cancel(who: &u64) -> C::Balance {
+		C::unreserve_all_named(&Self::ID, who)
+	}
+
+	fn settle(buyer: &u64, seller: &u64) -> DispatchResult {
+		C::repatriate_all_reserved_named(&Self::ID, buyer, seller, BalanceStatus::Free)
+	}
+
+	fn punish(who: &u64) -> C::NegativeImbalance {
+		C::slash_all_reserved_named(&Self::ID, who)
+	}
+}
+
+type StakingMarket = Market<StakingAssetCurrency<Test>>;
+
+#[test]
+fn named_reservable_currency_bid_and_cancel() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let staking: ReserveId = *b"staking ";
+		assert_ok!(GenericAsset::reserve_named(staking, STAKING_ASSET_ID, &ALICE, 10));
+
+		assert_ok!(StakingMarket::bid(&ALICE, 30));
+		assert_eq!(StakingAssetCurrency::<Test>::reserved_balance_named(&StakingMarket::ID, &ALICE), 30);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 40);
+		assert_noop!(StakingMarket::bid(&ALICE, INITIAL_BALANCE), Error::<Test>::InsufficientBalance);
+
+		// Only the market's reserve is released
+		assert_eq!(StakingMarket::cancel(&ALICE), 30);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 10);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE - 10);
+	});
+}
+
+#[test]
+fn named_reservable_currency_ensure_reserved_named() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let id = StakingMarket::ID;
+		assert_ok!(StakingAssetCurrency::<Test>::ensure_reserved_named(&id, &ALICE, 40));
+		assert_eq!(GenericAsset::reserved_balance_named(id, STAKING_ASSET_ID, &ALICE), 40);
+
+		assert_ok!(StakingAssetCurrency::<Test>::ensure_reserved_named(&id, &ALICE, 10));
+		assert_eq!(GenericAsset::reserved_balance_named(id, STAKING_ASSET_ID, &ALICE), 10);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE - 10);
+	});
+}
+
+#[test]
+fn named_reservable_currency_settle_and_punish() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_ok!(StakingMarket::bid(&ALICE, 30));
+		assert_ok!(StakingMarket::settle(&ALICE, &BOB));
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 30);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 0);
+		assert!(!<NamedReserves<Test>>::contains_key(STAKING_ASSET_ID, &ALICE));
+
+		assert_ok!(StakingMarket::bid(&BOB, 20));
+		let imbalance = StakingMarket::punish(&BOB);
+		assert_eq!(imbalance.peek(), 20);
+		drop(imbalance);
+		assert_eq!(GenericAsset::total_balance(STAKING_ASSET_ID, &BOB), 10);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE - 20);
+	});
+}
+
+#[test]
+fn repatriate_reserved_named_to_reserved_keeps_the_reserve_id() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let id = StakingMarket::ID;
+		let staking: ReserveId = *b"staking ";
+		assert_ok!(GenericAsset::reserve_named(staking, STAKING_ASSET_ID, &ALICE, 10));
+		assert_ok!(StakingMarket::bid(&ALICE, 30));
+
+		assert_eq!(
+			StakingAssetCurrency::<Test>::repatriate_reserved_named(&id, &ALICE, &BOB, 50, BalanceStatus::Reserved),
+			Ok(20)
+		);
+		assert_eq!(GenericAsset::reserved_balance_named(id, STAKING_ASSET_ID, &BOB), 30);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &BOB), 30);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 10);
+		assert_eq!(GenericAsset::named_reserves(STAKING_ASSET_ID, &ALICE), vec![(staking, 10)]);
+
+		// Repatriating to oneself as reserved leaves the reserve unchanged
+		assert_eq!(
+			StakingAssetCurrency::<Test>::repatriate_reserved_named(&id, &BOB, &BOB, 30, BalanceStatus::Reserved),
+			Ok(0)
+		);
+		assert_eq!(GenericAsset::reserved_balance_named(id, STAKING_ASSET_ID, &BOB), 30);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &BOB), 30);
+	});
+}
+
+#[test]
+fn transferable_balance_accounts_for_the_largest_lock() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// TEST1_ASSET_ID has an existential deposit of 3
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE);
+		assert_eq!(GenericAsset::transferable_balance(TEST1_ASSET_ID, &ALICE, false), INITIAL_BALANCE);
+		assert_eq!(GenericAsset::transferable_balance(TEST1_ASSET_ID, &ALICE, true), INITIAL_BALANCE - 3);
+
+		// Overlapping locks do not stack, only the largest applies
+		GenericAsset::set_lock(ID_1, TEST1_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER);
+		GenericAsset::set_lock(ID_2, TEST1_ASSET_ID, &ALICE, 50, WithdrawReasons::RESERVE);
+		assert_eq!(GenericAsset::transferable_balance(TEST1_ASSET_ID, &ALICE, false), INITIAL_BALANCE - 50);
+		assert_eq!(GenericAsset::transferable_balance(TEST1_ASSET_ID, &ALICE, true), INITIAL_BALANCE - 53);
+
+		GenericAsset::remove_lock(ID_2, TEST1_ASSET_ID, &ALICE);
+		assert_eq!(GenericAsset::transferable_balance(TEST1_ASSET_ID, &ALICE, false), INITIAL_BALANCE - 30);
+
+		// Saturates at zero when the locks exceed the free balance
+		GenericAsset::set_lock(ID_2, TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE + 1, WithdrawReasons::RESERVE);
+		assert_eq!(GenericAsset::transferable_balance(TEST1_ASSET_ID, &ALICE, false), 0);
+		assert_eq!(GenericAsset::transferable_balance(TEST1_ASSET_ID, &ALICE, true), 0);
+	});
+}
+
+#[test]
+fn can_slash_and_can_reserve_respect_locks() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert!(StakingAssetCurrency::<Test>::can_slash(&ALICE, INITIAL_BALANCE));
+		assert!(!StakingAssetCurrency::<Test>::can_slash(&ALICE, INITIAL_BALANCE + 1));
+
+		GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER);
+		// the free balance is sufficient but the lock restricts it
+		assert!(!StakingAssetCurrency::<Test>::can_slash(&ALICE, INITIAL_BALANCE));
+		assert!(StakingAssetCurrency::<Test>::can_slash(&ALICE, INITIAL_BALANCE - 30));
+		// a transfer lock does not restrict reserving
+		assert!(StakingAssetCurrency::<Test>::can_reserve(&ALICE, INITIAL_BALANCE));
+
+		GenericAsset::set_lock(ID_2, STAKING_ASSET_ID, &ALICE, 50, WithdrawReasons::RESERVE);
+		assert!(!StakingAssetCurrency::<Test>::can_reserve(&ALICE, INITIAL_BALANCE));
+		assert!(StakingAssetCurrency::<Test>::can_reserve(&ALICE, INITIAL_BALANCE - 50));
+	});
+}
+
+#[test]
+fn dust_reclaimed_is_only_emitted_for_dust() {
+	new_test_ext_with_balance(TEST1_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		let dust_reclaimed = || {
+			System::events()
+				.into_iter()
+				.map(|record| record.event)
+				.filter(|event| matches!(event, TestEvent::prml_generic_asset(RawEvent::DustReclaimed(..))))
+				.collect::<Vec<_>>()
+		};
+
+		// the free balance is exactly zero, there is no dust to reclaim
+		assert_ok!(GenericAsset::make_transfer(
+			TEST1_ASSET_ID,
+			&ALICE,
+			&BOB,
+			INITIAL_BALANCE,
+			ExistenceRequirement::AllowDeath
+		));
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &ALICE), 0);
+		assert_eq!(dust_reclaimed(), vec![]);
+
+		// TEST1_ASSET_ID has an existential deposit of 3
+		assert_ok!(GenericAsset::make_transfer(
+			TEST1_ASSET_ID,
+			&BOB,
+			&ALICE,
+			INITIAL_BALANCE - 2,
+			ExistenceRequirement::AllowDeath
+		));
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::DustReclaimed(TEST1_ASSET_ID, BOB, 2));
+		assert_eq!(dust_reclaimed(), vec![expected_event]);
+	});
+}
+
+#[test]
+fn blocking_lock_reports_the_lock_that_binds() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::RESERVE);
+		GenericAsset::set_lock(ID_2, STAKING_ASSET_ID, &ALICE, 60, WithdrawReasons::TRANSFER);
+
+		// only the transfer lock binds a transfer leaving 50
+		assert_eq!(
+			GenericAsset::blocking_lock(STAKING_ASSET_ID, &ALICE, 50, WithdrawReasons::TRANSFER, 50),
+			Some(ID_2)
+		);
+		assert_noop!(
+			GenericAsset::ensure_can_withdraw(STAKING_ASSET_ID, &ALICE, 50, WithdrawReasons::TRANSFER, 50),
+			Error::<Test>::LiquidityRestrictions
+		);
+		assert_eq!(GenericAsset::blocking_lock(STAKING_ASSET_ID, &ALICE, 50, WithdrawReasons::RESERVE, 50), None);
+
+		// only the reserve lock binds a reserve leaving 20
+		assert_eq!(
+			GenericAsset::blocking_lock(STAKING_ASSET_ID, &ALICE, 80, WithdrawReasons::RESERVE, 20),
+			Some(ID_1)
+		);
+
+		// a withdrawal leaving enough for every lock is allowed
+		assert_eq!(GenericAsset::blocking_lock(STAKING_ASSET_ID, &ALICE, 40, WithdrawReasons::all(), 60), None);
+		assert_ok!(GenericAsset::ensure_can_withdraw(STAKING_ASSET_ID, &ALICE, 40, WithdrawReasons::all(), 60));
+	});
+}
+
+#[test]
+fn transfers_to_blocked_recipients_are_rejected() {
+	new_test_ext_with_balance(TEST1_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let module_account = GenericAssetModuleId::get().into_account();
+
+		assert_noop!(
+			GenericAsset::transfer(Origin::signed(ALICE), TEST1_ASSET_ID, module_account, 10),
+			Error::<Test>::BlockedRecipient
+		);
+		assert_noop!(
+			GenericAsset::make_transfer(
+				TEST1_ASSET_ID,
+				&ALICE,
+				&module_account,
+				10,
+				ExistenceRequirement::KeepAlive
+			),
+			Error::<Test>::BlockedRecipient
+		);
+
+		assert_ok!(GenericAsset::transfer(Origin::signed(ALICE), TEST1_ASSET_ID, BOB, 10));
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &BOB), 10);
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &module_account), 0);
+	});
+}
+
+#[test]
+fn total_dust_reclaimed_accumulates_per_asset() {
+	new_test_ext_with_balance(TEST1_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_eq!(GenericAsset::total_dust_reclaimed(TEST1_ASSET_ID), 0);
+
+		// TEST1_ASSET_ID has an existential deposit of 3, leaving 2 as dust
+		assert_ok!(GenericAsset::make_transfer(
+			TEST1_ASSET_ID,
+			&ALICE,
+			&BOB,
+			INITIAL_BALANCE - 2,
+			ExistenceRequirement::AllowDeath
+		));
+		assert_eq!(GenericAsset::total_dust_reclaimed(TEST1_ASSET_ID), 2);
+
+		// leaving 1 as dust
+		assert_ok!(GenericAsset::make_transfer(
+			TEST1_ASSET_ID,
+			&BOB,
+			&CHARLIE,
+			INITIAL_BALANCE - 3,
+			ExistenceRequirement::AllowDeath
+		));
+		assert_eq!(GenericAsset::total_dust_reclaimed(TEST1_ASSET_ID), 3);
+		assert_eq!(GenericAsset::total_dust_reclaimed(TEST2_ASSET_ID), 0);
+	});
+}
+
+#[test]
+fn balance_detailed_reports_the_largest_active_lock_as_frozen() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(GenericAsset::reserve(STAKING_ASSET_ID, &ALICE, 20));
+		assert_eq!(GenericAsset::balance_detailed(STAKING_ASSET_ID, &ALICE), (INITIAL_BALANCE - 20, 20, 0));
+
+		// Overlapping locks do not stack
+		GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER);
+		GenericAsset::set_lock_until(ID_2, STAKING_ASSET_ID, &ALICE, 50, WithdrawReasons::RESERVE, 5);
+		assert_eq!(GenericAsset::balance_detailed(STAKING_ASSET_ID, &ALICE), (INITIAL_BALANCE - 20, 20, 50));
+
+		// Expired locks are not frozen
+		System::set_block_number(5);
+		assert_eq!(GenericAsset::balance_detailed(STAKING_ASSET_ID, &ALICE), (INITIAL_BALANCE - 20, 20, 30));
+	});
+}
+
+#[test]
+fn slash_cascade_slashes_assets_in_order() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_ok!(GenericAsset::do_mint_free(SPENDING_ASSET_ID, &ALICE, &50));
+		let spending_issuance = GenericAsset::total_issuance(SPENDING_ASSET_ID);
+
+		// The staking asset covers part of the amount, the rest comes from the spending asset
+		assert_eq!(
+			GenericAsset::slash_cascade(&ALICE, vec![STAKING_ASSET_ID, SPENDING_ASSET_ID], INITIAL_BALANCE + 20),
+			0
+		);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), 0);
+		assert_eq!(GenericAsset::free_balance(SPENDING_ASSET_ID, &ALICE), 30);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), 0);
+		assert_eq!(GenericAsset::total_issuance(SPENDING_ASSET_ID), spending_issuance - 20);
+
+		// Only part of the amount can be covered
+		assert_eq!(GenericAsset::slash_cascade(&ALICE, vec![STAKING_ASSET_ID, SPENDING_ASSET_ID], 50), 20);
+		assert_eq!(GenericAsset::free_balance(SPENDING_ASSET_ID, &ALICE), 0);
+		assert_eq!(GenericAsset::total_issuance(SPENDING_ASSET_ID), spending_issuance - 50);
+	});
+}
+
+#[test]
+fn increase_balance_clamps_at_max_value() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_eq!(GenericAsset::increase_balance(STAKING_ASSET_ID, &BOB, 50), 50);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 50);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE + 50);
+
+		// Clamped by the total issuance
+		<TotalIssuance<Test>>::insert(STAKING_ASSET_ID, u64::max_value() - 10);
+		assert_eq!(GenericAsset::increase_balance(STAKING_ASSET_ID, &BOB, 50), 10);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 60);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), u64::max_value());
+		assert_eq!(GenericAsset::increase_balance(STAKING_ASSET_ID, &BOB, 50), 0);
+
+		// Clamped by the free balance
+		GenericAsset::set_free_balance(SPENDING_ASSET_ID, &BOB, u64::max_value() - 5);
+		assert_eq!(GenericAsset::increase_balance(SPENDING_ASSET_ID, &BOB, 50), 5);
+		assert_eq!(GenericAsset::free_balance(SPENDING_ASSET_ID, &BOB), u64::max_value());
+		assert_eq!(GenericAsset::total_issuance(SPENDING_ASSET_ID), 5);
+	});
+}
+
+#[test]
+fn decrease_balance_clamps_at_zero() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_eq!(GenericAsset::decrease_balance(STAKING_ASSET_ID, &ALICE, 30), 30);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE - 30);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE - 30);
+
+		assert_eq!(GenericAsset::decrease_balance(STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE), INITIAL_BALANCE - 30);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), 0);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), 0);
+		assert_eq!(GenericAsset::decrease_balance(STAKING_ASSET_ID, &ALICE, 1), 0);
+	});
+}
+
+#[test]
+fn decrease_balance_leaves_locked_funds() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER);
+
+		assert_eq!(GenericAsset::decrease_balance(STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE), INITIAL_BALANCE - 30);
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), 30);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), 30);
+	});
+}
+
+#[test]
+fn mint_many_weight_grows_with_recipients() {
+	let weight = |n: u64| {
+		let recipients = (0..n).map(|who| (who, 1)).collect();
+		Call::<Test>::mint_many(ASSET_ID, recipients).get_dispatch_info().weight
+	};
+	let mint = <() as WeightInfo>::mint();
+	assert_eq!(weight(0), mint);
+	assert_eq!(weight(1), mint);
+	assert_eq!(weight(3), mint * 3);
+}
+
+#[test]
+fn holders_are_returned_in_pages() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &BOB, 20);
+		GenericAsset::set_free_balance(STAKING_ASSET_ID, &CHARLIE, 30);
+		// Holders of another asset are not included
+		GenericAsset::set_free_balance(SPENDING_ASSET_ID, &BOB, 40);
+
+		let (first_page, next_key) = GenericAsset::holders(STAKING_ASSET_ID, None, 2);
+		assert_eq!(first_page.len(), 2);
+		assert!(next_key.is_some());
+
+		let (second_page, next_key) = GenericAsset::holders(STAKING_ASSET_ID, next_key, 2);
+		assert_eq!(second_page.len(), 1);
+		assert_eq!(next_key, None);
+
+		let mut holders = [first_page, second_page].concat();
+		holders.sort();
+		assert_eq!(holders, vec![(ALICE, INITIAL_BALANCE), (BOB, 20), (CHARLIE, 30)]);
+
+		// An exact final page has no continuation key
+		assert_eq!(GenericAsset::holders(STAKING_ASSET_ID, None, 3).1, None);
+		assert_eq!(GenericAsset::holders(TEST1_ASSET_ID, None, 3), (vec![], None));
+	});
+}
+
+#[test]
+fn lock_storage_is_freed_when_empty() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let lock_1 = BalanceLock {
+			id: ID_1,
+			amount: 3u64,
+			reasons: WithdrawReasons::TRANSACTION_PAYMENT,
+			until: None,
+		};
+		let alice_locks = vec![lock_1];
+		<Locks<Test>>::insert(STAKING_ASSET_ID, ALICE, &alice_locks);
+
+		GenericAsset::remove_lock(ID_1, STAKING_ASSET_ID, &ALICE);
+
+		// lock storage released
+		assert!(!Locks::<Test>::contains_key(STAKING_ASSET_ID, &ALICE));
+	});
+}
+
+#[test]
+fn balance_falls_below_a_non_default_existential_deposit() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// Create a sufficient asset with ED = 11, so it keeps BOB alive
+		let existential_deposit = 11;
+		let mut asset_info = AssetInfo::new(b"TST1".to_vec(), 1, existential_deposit);
+		asset_info.set_sufficient(true);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info.clone()
+		));
+		GenericAsset::set_free_balance(ASSET_ID, &BOB, INITIAL_BALANCE);
+		assert!(System::account_exists(&BOB));
+
+		// Transfer BOB's balance down to ED (should not be reaped yet)
+		assert_ok!(GenericAsset::transfer(
+			Origin::signed(BOB),
+			ASSET_ID,
+			ALICE,
+			INITIAL_BALANCE - asset_info.existential_deposit()
+		));
+		assert!(System::account_exists(&BOB));
+		assert!(<FreeBalance<Test>>::contains_key(ASSET_ID, &BOB));
+
+		// Transfer BOB's balance down to ED - 1, it should be reaped
+		assert_ok!(GenericAsset::transfer(Origin::signed(BOB), ASSET_ID, ALICE, 1));
+
+		assert!(!System::account_exists(&BOB));
+		assert!(!<FreeBalance<Test>>::contains_key(ASSET_ID, &BOB));
+	});
+}
+
+#[test]
+fn balance_falls_below_a_dust_threshold_above_the_existential_deposit() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// Create an asset with ED = 11 and a dust threshold of 20
+		let mut asset_info = AssetInfo::new(b"TST1".to_vec(), 1, 11);
+		asset_info.set_dust_threshold(20);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info.clone()
+		));
+		GenericAsset::set_free_balance(ASSET_ID, &BOB, INITIAL_BALANCE);
+
+		// BOB's balance is left at the threshold, so is kept
+		assert_ok!(GenericAsset::transfer(Origin::signed(BOB), ASSET_ID, ALICE, INITIAL_BALANCE - 20));
+		assert!(GenericAsset::asset_exists_for(ASSET_ID, &BOB));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 20);
+
+		// Above the ED but below the threshold, BOB's balance is reclaimed as dust
+		assert_ok!(GenericAsset::transfer(Origin::signed(BOB), ASSET_ID, ALICE, 5));
+		assert!(!<FreeBalance<Test>>::contains_key(ASSET_ID, &BOB));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &TreasuryModuleId::get().into_account()), 15);
+	});
+}
+
+#[test]
+fn currency_withdraw_reclaims_below_the_dust_threshold() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		<AssetMeta<Test>>::mutate(STAKING_ASSET_ID, |info| info.set_dust_threshold(20));
+
+		// Above the ED but below the threshold, the rest of ALICE's balance is reclaimed as dust
+		let imbalance = StakingAssetCurrency::<Test>::withdraw(
+			&ALICE,
+			INITIAL_BALANCE - 15,
+			WithdrawReasons::TRANSFER,
+			ExistenceRequirement::AllowDeath,
+		)
+		.unwrap();
+		assert_eq!(imbalance.peek(), INITIAL_BALANCE - 15);
+
+		assert!(!<FreeBalance<Test>>::contains_key(STAKING_ASSET_ID, &ALICE));
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &TreasuryModuleId::get().into_account()), 15);
+		assert_eq!(GenericAsset::total_dust_reclaimed(STAKING_ASSET_ID), 15);
+	});
+}
+
+#[test]
+fn dust_threshold_is_never_below_the_existential_deposit() {
+	let mut asset_info = AssetInfo::new(b"TST1".to_vec(), 1, 11);
+	assert_eq!(asset_info.dust_threshold(), 11);
+
+	asset_info.set_dust_threshold(5);
+	assert_eq!(asset_info.dust_threshold(), 11);
+}
+
+#[test]
+fn set_balance_adjusts_total_issuance() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(GenericAsset::set_balance(Origin::root(), STAKING_ASSET_ID, BOB, 70, 30));
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), 70);
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &BOB), 30);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE + 100);
+		assert!(System::account_exists(&BOB));
+
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::BalanceSet(STAKING_ASSET_ID, BOB, 70, 30));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+
+		assert_ok!(GenericAsset::set_balance(Origin::root(), STAKING_ASSET_ID, ALICE, 40, 0));
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE), 40);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), 140);
+
+		assert_ok!(GenericAsset::set_balance(Origin::root(), STAKING_ASSET_ID, BOB, 0, 0));
+		assert!(!<FreeBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
+		assert!(!<ReservedBalance<Test>>::contains_key(STAKING_ASSET_ID, &BOB));
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), 40);
+		assert!(!System::account_exists(&BOB));
+	});
+}
+
+#[test]
+fn set_balance_below_the_dust_threshold_sets_zero() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// TEST1_ASSET_ID has an existential deposit of 3
+		<TotalIssuance<Test>>::insert(TEST1_ASSET_ID, 0);
+
+		assert_ok!(GenericAsset::set_balance(Origin::root(), TEST1_ASSET_ID, BOB, 2, 5));
+		assert!(!<FreeBalance<Test>>::contains_key(TEST1_ASSET_ID, &BOB));
+		assert_eq!(GenericAsset::reserved_balance(TEST1_ASSET_ID, &BOB), 5);
+		assert_eq!(GenericAsset::total_issuance(TEST1_ASSET_ID), 5);
+	});
+}
+
+#[test]
+fn set_balance_below_the_named_reserves_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let staking: ReserveId = *b"staking ";
+		let market: ReserveId = *b"market  ";
+		assert_ok!(GenericAsset::reserve_named(staking, STAKING_ASSET_ID, &ALICE, 30));
+		assert_ok!(GenericAsset::reserve_named(market, STAKING_ASSET_ID, &ALICE, 20));
+
+		assert_noop!(
+			GenericAsset::set_balance(Origin::root(), STAKING_ASSET_ID, ALICE, 50, 49),
+			Error::<Test>::ReservedBelowNamedReserves
+		);
+
+		assert_ok!(GenericAsset::set_balance(Origin::root(), STAKING_ASSET_ID, ALICE, 50, 50));
+		assert_eq!(GenericAsset::reserved_balance(STAKING_ASSET_ID, &ALICE), 50);
+		assert_eq!(GenericAsset::reserved_balance_named(staking, STAKING_ASSET_ID, &ALICE), 30);
+		assert_eq!(GenericAsset::reserved_balance_named(market, STAKING_ASSET_ID, &ALICE), 20);
+	});
+}
+
+#[test]
+fn set_balance_requires_root() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_noop!(
+			GenericAsset::set_balance(Origin::signed(ALICE), STAKING_ASSET_ID, ALICE, 1_000, 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			GenericAsset::set_balance(Origin::root(), ASSET_ID, ALICE, 1_000, 0),
+			Error::<Test>::AssetIdNotExist
+		);
+	});
+}
+
+#[test]
+fn transfer_burns_the_transfer_fee_from_the_sender() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		create_accounts(&[BOB, CHARLIE]);
+		let mut asset_info = AssetInfo::new(b"TST1".to_vec(), 1, 1);
+		asset_info.set_transfer_fee(5);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
+		));
+
+		assert_ok!(GenericAsset::transfer(Origin::signed(ALICE), ASSET_ID, BOB, 100));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &ALICE), INITIAL_ISSUANCE - 105);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 100);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE - 5);
+
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::FeeCharged(ASSET_ID, ALICE, 5));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+
+		// BOB cannot afford the fee on top of the whole balance
+		assert_noop!(
+			GenericAsset::transfer(Origin::signed(BOB), ASSET_ID, ALICE, 100),
+			Error::<Test>::InsufficientBalance
+		);
+
+		// `transfer_all` keeps the fee back
+		assert_ok!(GenericAsset::transfer_all(Origin::signed(BOB), ASSET_ID, CHARLIE));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 0);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &CHARLIE), 95);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE - 10);
+	});
+}
+
+#[test]
+fn failed_transfer_does_not_burn_the_transfer_fee() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		let mut asset_info = AssetInfo::new(b"TST1".to_vec(), 1, 1);
+		asset_info.set_transfer_fee(5);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
+		));
+
+		// The sender can afford the fee, but the recipient is blocked
+		let module_account = GenericAssetModuleId::get().into_account();
+		assert_noop!(
+			GenericAsset::transfer(Origin::signed(ALICE), ASSET_ID, module_account, 100),
+			Error::<Test>::BlockedRecipient
+		);
+
+		// The recipient does not exist and the asset is not sufficient
+		assert_noop!(
+			GenericAsset::transfer(Origin::signed(ALICE), ASSET_ID, CHARLIE, 100),
+			Error::<Test>::NoProvider
+		);
+
+		// The recipient's balance would overflow
+		GenericAsset::set_free_balance(ASSET_ID, &BOB, u64::max_value());
+		assert_noop!(
+			GenericAsset::transfer(Origin::signed(ALICE), ASSET_ID, BOB, 100),
+			Error::<Test>::TransferOverflow
+		);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &ALICE), INITIAL_ISSUANCE);
+	});
+}
+
+#[test]
+fn transfer_without_a_transfer_fee_does_not_burn() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_ok!(GenericAsset::transfer(Origin::signed(ALICE), STAKING_ASSET_ID, BOB, INITIAL_BALANCE));
+		assert_eq!(GenericAsset::free_balance(STAKING_ASSET_ID, &BOB), INITIAL_BALANCE);
+		assert_eq!(GenericAsset::total_issuance(STAKING_ASSET_ID), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn minimum_balance_is_existential_deposit() {
+	new_test_ext_with_permissions(vec![(STAKING_ASSET_ID, ALICE), (SPENDING_ASSET_ID, ALICE)]).execute_with(|| {
+		let stk_min = 11u64;
+		let spd_min = 17u64;
+		let staking_asset_info = AssetInfo::new(b"STK".to_vec(), 1, stk_min);
+		let spending_asset_info = AssetInfo::new(b"SPD".to_vec(), 2, spd_min);
+		assert_ok!(GenericAsset::create_asset(
+			Some(STAKING_ASSET_ID),
+			Some(ALICE),
+			asset_options(PermissionLatest::new(ALICE), staking_asset_info.decimal_places()),
+			staking_asset_info
+		));
+		assert_ok!(GenericAsset::create_asset(
+			Some(SPENDING_ASSET_ID),
+			Some(ALICE),
+			asset_options(PermissionLatest::new(ALICE), spending_asset_info.decimal_places()),
+			spending_asset_info
+		));
+		assert_eq!(StakingAssetCurrency::<Test>::minimum_balance(), stk_min);
+		assert_eq!(SpendingAssetCurrency::<Test>::minimum_balance(), spd_min);
+	});
+}
+
+#[test]
+fn on_dust_imbalance_hook_invoked() {
+	new_test_ext_with_default().execute_with(|| {
+		create_accounts(&[ALICE]);
+		let existential_deposit = 5;
+		let asset_info = AssetInfo::new(b"TST1".to_vec(), 1, existential_deposit);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			BOB,
+			// this will issue INITIAL_ISSUANCE / 1 tokens of ASSET_ID
+			asset_options(PermissionLatest::new(BOB), asset_info.decimal_places()),
+			asset_info.clone()
+		));
+
+		// Transfer all tokens til we're 1 below ED
+		assert_ok!(GenericAsset::transfer(
+			Origin::signed(BOB),
+			ASSET_ID,
+			ALICE,
+			INITIAL_ISSUANCE - asset_info.existential_deposit() + 1
+		));
+
+		// Our test hook transfers dust to the treasury account
+		// Treasury account should get the dust (ED - 1)
+		let treasury_account_id = TreasuryModuleId::get().into_account();
+		assert_eq!(
+			GenericAsset::free_balance(ASSET_ID, &treasury_account_id),
+			asset_info.existential_deposit() - 1
+		);
+
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE);
+	});
+}
+
+#[test]
+fn raised_existential_deposit_reclaims_dust_on_next_transfer() {
+	new_test_ext_with_default().execute_with(|| {
+		create_accounts(&[ALICE, CHARLIE]);
+		let asset_info = AssetInfo::new(b"TST1".to_vec(), 1, 5);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			BOB,
+			asset_options(PermissionLatest::new(BOB), asset_info.decimal_places()),
+			asset_info
+		));
+		assert_ok!(GenericAsset::transfer(Origin::signed(BOB), ASSET_ID, ALICE, 50));
+
+		// Raise the ED above ALICE's balance, the existing balance is untouched
+		assert_ok!(GenericAsset::set_existential_deposit(Origin::root(), ASSET_ID, 100));
+		assert_eq!(GenericAsset::asset_meta(ASSET_ID).existential_deposit(), 100);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &ALICE), 50);
+
+		// ALICE's remainder is now dust and is reclaimed by the treasury
+		assert_ok!(GenericAsset::transfer(Origin::signed(ALICE), ASSET_ID, CHARLIE, 10));
+		assert!(!<FreeBalance<Test>>::contains_key(ASSET_ID, &ALICE));
+		let treasury_account_id = TreasuryModuleId::get().into_account();
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &treasury_account_id), 40);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE);
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_check_permission_and_value() {
+	new_test_ext_with_default().execute_with(|| {
+		let asset_info = AssetInfo::default();
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			BOB,
+			asset_options(PermissionLatest::new(BOB), asset_info.decimal_places()),
+			asset_info
+		));
+
+		assert_noop!(
+			GenericAsset::set_existential_deposit(Origin::signed(ALICE), ASSET_ID, 10),
+			Error::<Test>::NoUpdatePermission,
+		);
+		assert_noop!(
+			GenericAsset::set_existential_deposit(Origin::signed(BOB), ASSET_ID, 0),
+			Error::<Test>::ZeroExistentialDeposit,
+		);
+		assert_noop!(
+			GenericAsset::set_existential_deposit(Origin::root(), ASSET_ID + 1, 10),
+			Error::<Test>::AssetIdNotExist,
+		);
+		assert_ok!(GenericAsset::set_existential_deposit(Origin::signed(BOB), ASSET_ID, 10));
+		assert_eq!(GenericAsset::asset_meta(ASSET_ID).existential_deposit(), 10);
+	});
+}
+
+#[test]
+fn on_runtime_upgrade() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		StorageVersion::put(Releases::V0 as u32);
+		let asset_info_1 = AssetInfo::new(b"TST1".to_vec(), 1, 11);
+		let asset_info_2 = AssetInfo::new(b"TST2".to_vec(), 4, 7);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			BOB,
+			asset_options(PermissionLatest::new(BOB), asset_info_1.decimal_places()),
+			asset_info_1.clone()
+		));
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			BOB,
+			asset_options(PermissionLatest::new(BOB), asset_info_2.decimal_places()),
+			asset_info_2.clone()
+		));
+		// Transfer Alice some dust (asset 1)
+		// It should be freed during the migration
+		assert_ok!(GenericAsset::transfer(
+			Origin::signed(BOB),
+			ASSET_ID,
+			ALICE,
+			asset_info_1.existential_deposit() - 1,
+		));
+		// Reduce BOB's (asset 1) balance below ED
+		// It should be freed during the migration
+		GenericAsset::set_free_balance(ASSET_ID, &BOB, asset_info_1.existential_deposit() - 1);
+
+		// Set a balance directly, its 'provided' counter = 0
+		<FreeBalance<Test>>::insert(ASSET_ID, &CHARLIE, 100);
+		assert!(!System::account_exists(&CHARLIE));
+
+		// On runtime upgrade should be able to fix the account store
+		let _ = GenericAsset::on_runtime_upgrade();
+
+		// Test accounts are restored now
+		assert!(System::account_exists(&ALICE));
+		assert!(System::account_exists(&BOB));
+		assert!(System::account_exists(&CHARLIE));
+
+		// Test assets of Alice are as before
+		assert_eq!(<FreeBalance<Test>>::get(&STAKING_ASSET_ID, &ALICE), INITIAL_BALANCE);
+		// Test Alice's dust asset 1 free balance is freed
+		assert!(!<FreeBalance<Test>>::contains_key(&ASSET_ID, &ALICE));
+
+		// Test BOB's dust asset 1 free balance is freed
+		assert!(!<FreeBalance<Test>>::contains_key(ASSET_ID, BOB));
+
+		// Test asset 2 free balance is unchanged
+		assert_eq!(<FreeBalance<Test>>::get(&(ASSET_ID + 1), &BOB), INITIAL_ISSUANCE);
+
+		// Our test hook transfers dust to the treasury account
+		// Treasury account should get the dust (ED - 1)
+		let treasury_account_id = TreasuryModuleId::get().into_account();
+		assert_eq!(
+			GenericAsset::free_balance(ASSET_ID, &treasury_account_id),
+			(asset_info_1.existential_deposit() - 1) * 2 // sum of Alice & Bob's dust
+		);
+
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE);
+	});
+}
+
+#[test]
+fn new_networks_start_at_the_latest_storage_version() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_eq!(StorageVersion::get(), Releases::default() as u32);
+
+		// Dust is left alone, as no migration runs
+		<FreeBalance<Test>>::insert(TEST1_ASSET_ID, &CHARLIE, 1);
+		assert!(GenericAsset::on_runtime_upgrade().is_zero());
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &CHARLIE), 1);
+	});
+}
+
+#[test]
+fn on_runtime_upgrade_only_purges_dust_once() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let treasury_account_id = TreasuryModuleId::get().into_account();
+		StorageVersion::put(Releases::V0 as u32);
+		// TEST1_ASSET_ID has an existential deposit of 3
+		<FreeBalance<Test>>::insert(TEST1_ASSET_ID, &CHARLIE, 1);
+
+		assert!(!GenericAsset::on_runtime_upgrade().is_zero());
+		assert!(!<FreeBalance<Test>>::contains_key(TEST1_ASSET_ID, &CHARLIE));
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &treasury_account_id), 1);
+
+		// A second upgrade is a no-op
+		<FreeBalance<Test>>::insert(TEST1_ASSET_ID, &CHARLIE, 1);
+		assert!(GenericAsset::on_runtime_upgrade().is_zero());
+		assert_eq!(StorageVersion::get(), Releases::default() as u32);
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &CHARLIE), 1);
+		assert_eq!(GenericAsset::free_balance(TEST1_ASSET_ID, &treasury_account_id), 1);
+	});
+}
+
+#[test]
+fn migrate_locks_on_runtime_upgrade() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		#[allow(dead_code)]
+		mod old_storage {
+			use super::Config;
+			use crate::types::LegacyBalanceLock;
+
+			pub struct Module<T>(sp_std::marker::PhantomData<T>);
+			frame_support::decl_storage! {
+				trait Store for Module<T: Config> as GenericAsset {
+					pub Locks get(fn locks):
+						map hasher(blake2_128_concat) u64 => Vec<LegacyBalanceLock<u64>>;
+				}
+			}
+		}
+
+		StorageVersion::put(Releases::V0 as u32);
+		assert!(!<Locks<Test>>::contains_key(STAKING_ASSET_ID, ALICE));
+		assert!(!<Locks<Test>>::contains_key(STAKING_ASSET_ID, BOB));
+
+		let lock_1 = LegacyBalanceLock {
+			id: ID_1,
+			amount: 3u64,
+			reasons: WithdrawReasons::TRANSACTION_PAYMENT,
+		};
+		let lock_2 = LegacyBalanceLock {
+			id: ID_1,
+			amount: 5u64,
+			reasons: WithdrawReasons::TRANSFER,
+		};
+		let lock_3 = LegacyBalanceLock {
+			id: ID_2,
+			amount: 7u64,
+			reasons: WithdrawReasons::TIP,
+		};
+		let alice_locks = vec![lock_1, lock_2, lock_3];
+		old_storage::Locks::insert(ALICE, alice_locks.clone());
+
+		let lock_4 = LegacyBalanceLock {
+			id: ID_2,
+			amount: 11u64,
+			reasons: WithdrawReasons::FEE,
+		};
+		let bob_locks = vec![lock_4];
+		old_storage::Locks::insert(BOB, bob_locks.clone());
+
+		let _ = GenericAsset::on_runtime_upgrade();
+
+		// Old lock storage is now freed
+		assert!(!old_storage::Locks::contains_key(ALICE));
+		assert!(!old_storage::Locks::contains_key(BOB));
+
+		assert_eq!(<Module<Test>>::staking_asset_id(), STAKING_ASSET_ID);
+		assert_eq!(<Locks<Test>>::iter().count(), 2);
+		// Migrated locks never expire
+		let alice_locks: Vec<BalanceLock<u64, u64>> = alice_locks.into_iter().map(Into::into).collect();
+		let bob_locks: Vec<BalanceLock<u64, u64>> = bob_locks.into_iter().map(Into::into).collect();
+		assert_eq!(<Locks<Test>>::get(STAKING_ASSET_ID, ALICE), alice_locks);
+		assert_eq!(<Locks<Test>>::get(STAKING_ASSET_ID, BOB), bob_locks);
+	});
+}
+
+#[test]
+fn migrate_symbol_index_on_runtime_upgrade() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		StorageVersion::put(Releases::V4 as u32);
+		<SymbolToId<Test>>::remove_all();
+		// Before the index, symbols were not unique
+		<AssetMeta<Test>>::insert(ASSET_ID + 1, AssetInfo::new(b"TST 2".to_vec(), 2, 5));
+
+		let _ = GenericAsset::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get(), Releases::V7 as u32);
+		assert_eq!(GenericAsset::id_for_symbol(b"TEST1"), Some(TEST1_ASSET_ID));
+		// The lowest asset id is indexed for a shared symbol
+		assert_eq!(GenericAsset::id_for_symbol(b"TST 2"), Some(ASSET_ID + 1));
+		assert_eq!(<SymbolToId<Test>>::iter().count(), 2);
+
+		// Running the upgrade again does nothing
+		assert!(GenericAsset::on_runtime_upgrade().is_zero());
+	});
+}
+
+#[test]
+fn migrate_asset_info_marks_existing_assets_sufficient() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		StorageVersion::put(Releases::V5 as u32);
+		let mut asset_info = AssetInfo::new(b"TST 3".to_vec(), 2, 5);
+		asset_info.set_transfer_fee(2);
+		<AssetMeta<Test>>::insert(ASSET_ID, asset_info.clone());
+
+		let _ = GenericAsset::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get(), Releases::V7 as u32);
+		asset_info.set_sufficient(true);
+		assert_eq!(GenericAsset::asset_meta(ASSET_ID), asset_info);
+		assert!(GenericAsset::asset_meta(TEST1_ASSET_ID).sufficient());
+	});
+}
+
+#[test]
+fn migrate_held_assets_on_runtime_upgrade() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		StorageVersion::put(Releases::V6 as u32);
+		<AssetMeta<Test>>::mutate(TEST1_ASSET_ID, |info| info.set_sufficient(true));
+		// Balances stored before the index, one provided for by GenericAsset and one not
+		<FreeBalance<Test>>::insert(TEST1_ASSET_ID, &BOB, 10);
+		System::inc_providers(&BOB);
+		<ReservedBalance<Test>>::insert(TEST1_ASSET_ID, &CHARLIE, 10);
+		<FreeBalance<Test>>::insert(TEST2_ASSET_ID, &CHARLIE, 10);
+		assert!(!System::account_exists(&CHARLIE));
+
+		let _ = GenericAsset::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get(), Releases::V7 as u32);
+		assert!(GenericAsset::holds_any_asset(&BOB));
+		assert!(GenericAsset::holds_any_asset(&CHARLIE));
+		// Only the sufficient asset provides for CHARLIE
+		assert_eq!(GenericAsset::sufficient_assets_held(&CHARLIE), 1);
+		// BOB's existing reference is adopted, CHARLIE is provided for
+		assert_eq!(System::providers(&BOB), 1);
+		assert_eq!(System::providers(&CHARLIE), 1);
+	});
+}
+
+#[test]
+fn migrate_lock_expiry_on_runtime_upgrade() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		#[allow(dead_code)]
+		mod old_storage {
+			use super::Config;
+			use crate::types::LegacyBalanceLock;
+
 			pub struct Module<T>(sp_std::marker::PhantomData<T>);
 			frame_support::decl_storage! {
 				trait Store for Module<T: Config> as GenericAsset {
 					pub Locks get(fn locks):
-						map hasher(blake2_128_concat) u64 => Vec<BalanceLock<u64>>;
+						double_map hasher(twox_64_concat) u64, hasher(blake2_128_concat) u64 => Vec<LegacyBalanceLock<u64>>;
 				}
 			}
 		}
 
-		assert!(!<Locks<Test>>::contains_key(STAKING_ASSET_ID, ALICE));
-		assert!(!<Locks<Test>>::contains_key(STAKING_ASSET_ID, BOB));
-
-		let lock_1 = BalanceLock {
+		StorageVersion::put(Releases::V1 as u32);
+		let lock = LegacyBalanceLock {
 			id: ID_1,
 			amount: 3u64,
-			reasons: WithdrawReasons::TRANSACTION_PAYMENT,
-		};
-		let lock_2 = BalanceLock {
-			id: ID_1,
-			amount: 5u64,
 			reasons: WithdrawReasons::TRANSFER,
 		};
-		let lock_3 = BalanceLock {
-			id: ID_2,
-			amount: 7u64,
-			reasons: WithdrawReasons::TIP,
-		};
-		let alice_locks = vec![lock_1, lock_2, lock_3];
-		old_storage::Locks::insert(ALICE, alice_locks.clone());
-
-		let lock_4 = BalanceLock {
-			id: ID_2,
-			amount: 11u64,
-			reasons: WithdrawReasons::FEE,
-		};
-		let bob_locks = vec![lock_4];
-		old_storage::Locks::insert(BOB, bob_locks.clone());
+		old_storage::Locks::insert(STAKING_ASSET_ID, ALICE, vec![lock]);
 
 		let _ = GenericAsset::on_runtime_upgrade();
 
-		// Old lock storage is now freed
-		assert!(!old_storage::Locks::contains_key(ALICE));
-		assert!(!old_storage::Locks::contains_key(BOB));
-
-		assert_eq!(<Module<Test>>::staking_asset_id(), STAKING_ASSET_ID);
-		assert_eq!(<Locks<Test>>::iter().count(), 2);
-		assert_eq!(<Locks<Test>>::get(STAKING_ASSET_ID, ALICE), alice_locks);
-		assert_eq!(<Locks<Test>>::get(STAKING_ASSET_ID, BOB), bob_locks);
+		assert_eq!(StorageVersion::get(), Releases::V7 as u32);
+		assert_eq!(
+			<Locks<Test>>::get(STAKING_ASSET_ID, ALICE),
+			vec![BalanceLock {
+				id: ID_1,
+				amount: 3u64,
+				reasons: WithdrawReasons::TRANSFER,
+				until: None,
+			}]
+		);
 	});
 }
 
@@ -554,16 +1665,19 @@ fn ensure_can_withdraw() {
 			id: ID_1,
 			amount: 3u64,
 			reasons: WithdrawReasons::TRANSACTION_PAYMENT,
+			until: None,
 		};
 		let lock_2 = BalanceLock {
 			id: ID_1,
 			amount: 5u64,
 			reasons: WithdrawReasons::TRANSFER,
+			until: None,
 		};
 		let lock_3 = BalanceLock {
 			id: ID_2,
 			amount: 7u64,
 			reasons: WithdrawReasons::TIP,
+			until: None,
 		};
 		let alice_locks = vec![lock_1.clone(), lock_2.clone(), lock_3.clone()];
 		<Locks<Test>>::insert(STAKING_ASSET_ID, ALICE, alice_locks.clone());
@@ -623,6 +1737,152 @@ fn ensure_can_withdraw() {
 	});
 }
 
+#[test]
+fn lock_with_expiry_is_released_at_its_until_block() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		GenericAsset::set_lock_until(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER, 5);
+
+		System::set_block_number(4);
+		assert_noop!(
+			GenericAsset::ensure_can_withdraw(STAKING_ASSET_ID, &ALICE, 1, WithdrawReasons::TRANSFER, 29),
+			Error::<Test>::LiquidityRestrictions
+		);
+
+		System::set_block_number(5);
+		assert_ok!(GenericAsset::ensure_can_withdraw(
+			STAKING_ASSET_ID,
+			&ALICE,
+			1,
+			WithdrawReasons::TRANSFER,
+			0
+		));
+		assert!(GenericAsset::active_locks(STAKING_ASSET_ID, &ALICE).is_empty());
+	});
+}
+
+#[test]
+fn expired_locks_are_pruned_when_locks_are_updated() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		GenericAsset::set_lock_until(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER, 5);
+		GenericAsset::set_lock(ID_2, STAKING_ASSET_ID, &ALICE, 10, WithdrawReasons::FEE);
+		assert_eq!(GenericAsset::locks(STAKING_ASSET_ID, &ALICE).len(), 2);
+
+		// The expired lock is kept in storage until the account's locks are next written
+		System::set_block_number(5);
+		assert_eq!(GenericAsset::locks(STAKING_ASSET_ID, &ALICE).len(), 2);
+
+		GenericAsset::remove_lock(ID_2, STAKING_ASSET_ID, &ALICE);
+		assert!(!<Locks<Test>>::contains_key(STAKING_ASSET_ID, &ALICE));
+	});
+}
+
+#[test]
+fn new_lock_ids_are_refused_beyond_max_locks() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// MaxLocks is 2 in the mock
+		assert_ok!(GenericAsset::try_set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 10, WithdrawReasons::all()));
+		assert_ok!(GenericAsset::try_set_lock(ID_2, STAKING_ASSET_ID, &ALICE, 20, WithdrawReasons::all()));
+		assert_noop!(
+			GenericAsset::try_set_lock(ID_3, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::all()),
+			Error::<Test>::TooManyLocks
+		);
+
+		// Existing locks can still be updated
+		assert_ok!(GenericAsset::try_set_lock(ID_2, STAKING_ASSET_ID, &ALICE, 40, WithdrawReasons::all()));
+		GenericAsset::extend_lock(ID_1, STAKING_ASSET_ID, &ALICE, 50, WithdrawReasons::all());
+		assert_eq!(GenericAsset::frozen_balance(STAKING_ASSET_ID, &ALICE), 50);
+
+		// Freeing a slot allows a new lock id
+		GenericAsset::remove_lock(ID_1, STAKING_ASSET_ID, &ALICE);
+		assert_ok!(GenericAsset::try_set_lock(ID_3, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::all()));
+	});
+}
+
+#[test]
+fn infallible_lock_setters_exceed_max_locks() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// MaxLocks is 2 in the mock
+		GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, 10, WithdrawReasons::all());
+		GenericAsset::set_lock(ID_2, STAKING_ASSET_ID, &ALICE, 20, WithdrawReasons::all());
+
+		// A lock set at the limit is kept and still restricts withdrawals
+		<StakingAssetCurrency<Test> as LockableCurrency<_>>::set_lock(ID_3, &ALICE, 60, WithdrawReasons::all());
+		assert_eq!(GenericAsset::locks(STAKING_ASSET_ID, &ALICE).len(), 3);
+		assert_eq!(GenericAsset::frozen_balance(STAKING_ASSET_ID, &ALICE), 60);
+		assert_noop!(
+			GenericAsset::transfer(Origin::signed(ALICE), STAKING_ASSET_ID, BOB, INITIAL_BALANCE - 50),
+			Error::<Test>::LiquidityRestrictions
+		);
+
+		// Likewise for the other infallible paths
+		const ID_4: LockIdentifier = *b"4       ";
+		const ID_5: LockIdentifier = *b"5       ";
+		GenericAsset::extend_lock(ID_4, STAKING_ASSET_ID, &ALICE, 70, WithdrawReasons::all());
+		GenericAsset::set_lock_until(ID_5, STAKING_ASSET_ID, &ALICE, 80, WithdrawReasons::all(), 10);
+		assert_eq!(GenericAsset::locks(STAKING_ASSET_ID, &ALICE).len(), 5);
+		assert_eq!(GenericAsset::frozen_balance(STAKING_ASSET_ID, &ALICE), 80);
+
+		// `try_set_lock` still refuses a new lock id
+		assert_noop!(
+			GenericAsset::try_set_lock(*b"6       ", STAKING_ASSET_ID, &ALICE, 10, WithdrawReasons::all()),
+			Error::<Test>::TooManyLocks
+		);
+	});
+}
+
+#[test]
+fn expired_locks_do_not_count_towards_max_locks() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		GenericAsset::set_lock_until(ID_1, STAKING_ASSET_ID, &ALICE, 30, WithdrawReasons::TRANSFER, 5);
+		GenericAsset::set_lock(ID_2, STAKING_ASSET_ID, &ALICE, 10, WithdrawReasons::FEE);
+		assert_noop!(
+			GenericAsset::try_set_lock(ID_3, STAKING_ASSET_ID, &ALICE, 20, WithdrawReasons::all()),
+			Error::<Test>::TooManyLocks
+		);
+
+		System::set_block_number(5);
+		assert_ok!(GenericAsset::try_set_lock(ID_3, STAKING_ASSET_ID, &ALICE, 20, WithdrawReasons::all()));
+		assert_eq!(GenericAsset::locks(STAKING_ASSET_ID, &ALICE).len(), 2);
+	});
+}
+
+#[test]
+fn withdraw_consequence() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		// TEST1_ASSET_ID has an existential deposit of 3
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE);
+		GenericAsset::set_lock(ID_1, TEST1_ASSET_ID, &ALICE, 10, WithdrawReasons::TRANSFER);
+
+		assert_eq!(
+			GenericAsset::withdraw_consequence(TEST1_ASSET_ID, &ALICE, 0, WithdrawReasons::all()),
+			WithdrawConsequence::Success
+		);
+		assert_eq!(
+			GenericAsset::withdraw_consequence(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE + 1, WithdrawReasons::all()),
+			WithdrawConsequence::NoFunds
+		);
+		assert_eq!(
+			GenericAsset::withdraw_consequence(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE - 9, WithdrawReasons::TRANSFER),
+			WithdrawConsequence::Frozen
+		);
+		assert_eq!(
+			GenericAsset::withdraw_consequence(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE - 2, WithdrawReasons::FEE),
+			WithdrawConsequence::ReducedToZero(2)
+		);
+		assert_eq!(
+			GenericAsset::withdraw_consequence(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE, WithdrawReasons::FEE),
+			WithdrawConsequence::Success
+		);
+		assert_eq!(
+			GenericAsset::withdraw_consequence(TEST1_ASSET_ID, &ALICE, INITIAL_BALANCE - 10, WithdrawReasons::TRANSFER),
+			WithdrawConsequence::Success
+		);
+	});
+}
+
 // Given
 // - Next asset id as `asset_id` = 1000.
 // - Sufficient free balance.
@@ -951,6 +2211,30 @@ fn slash_should_return_slash_reserved_amount() {
 	});
 }
 
+// Given
+// - slash amount > free_balance + reserved_balance.
+// When
+// - After calling slash_and_settle function.
+// Then
+// - Should return an imbalance for the slashed amount and the remainder.
+// - Total issuance should only change once the imbalance is dropped.
+#[test]
+fn slash_and_settle_should_maintain_total_issuance_once_resolved() {
+	new_test_ext_with_balance(ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		GenericAsset::set_reserved_balance(ASSET_ID, &ALICE, 20);
+		<TotalIssuance<Test>>::insert(ASSET_ID, INITIAL_BALANCE + 20);
+
+		let (imbalance, remaining) = GenericAsset::slash_and_settle(ASSET_ID, &ALICE, 150);
+		assert_eq!(imbalance.peek(), INITIAL_BALANCE + 20);
+		assert_eq!(remaining, 30);
+		assert_eq!(GenericAsset::total_balance(ASSET_ID, &ALICE), 0);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_BALANCE + 20);
+
+		drop(imbalance);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), 0);
+	});
+}
+
 // Given
 // - slashed_amount > reserved_balance.
 // When
@@ -992,7 +2276,7 @@ fn slash_reserved_should_return_none() {
 fn repatriate_reserved_return_amount_subtracted_by_slash_amount() {
 	new_test_ext_with_default().execute_with(|| {
 		GenericAsset::set_reserved_balance(ASSET_ID, &ALICE, 100);
-		assert_ok!(GenericAsset::repatriate_reserved(ASSET_ID, &ALICE, &ALICE, 130), 30);
+		assert_ok!(GenericAsset::repatriate_reserved(ASSET_ID, &ALICE, &ALICE, 130, BalanceStatus::Free), 30);
 		assert_eq!(GenericAsset::free_balance(ASSET_ID, &ALICE), 100);
 	});
 }
@@ -1008,12 +2292,93 @@ fn repatriate_reserved_return_amount_subtracted_by_slash_amount() {
 fn repatriate_reserved_return_none() {
 	new_test_ext_with_default().execute_with(|| {
 		GenericAsset::set_reserved_balance(ASSET_ID, &ALICE, 100);
-		assert_ok!(GenericAsset::repatriate_reserved(ASSET_ID, &ALICE, &ALICE, 90), 0);
+		assert_ok!(GenericAsset::repatriate_reserved(ASSET_ID, &ALICE, &ALICE, 90, BalanceStatus::Free), 0);
 		assert_eq!(GenericAsset::reserved_balance(ASSET_ID, &ALICE), 10);
 		assert_eq!(GenericAsset::free_balance(ASSET_ID, &ALICE), 90);
 	});
 }
 
+// Given
+// - reserved_balance = 100.
+// - repatriate_reserved_amount < reserved_balance.
+// When
+// - After calling repatriate_reserved with a `Free` status.
+// Then
+// - The beneficiary's free balance should be increased.
+#[test]
+fn repatriate_reserved_to_free_balance() {
+	new_test_ext_with_default().execute_with(|| {
+		GenericAsset::set_reserved_balance(ASSET_ID, &ALICE, 100);
+		assert_ok!(
+			GenericAsset::repatriate_reserved(ASSET_ID, &ALICE, &BOB, 90, BalanceStatus::Free),
+			0
+		);
+		assert_eq!(GenericAsset::reserved_balance(ASSET_ID, &ALICE), 10);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 90);
+		assert_eq!(GenericAsset::reserved_balance(ASSET_ID, &BOB), 0);
+	});
+}
+
+// Given
+// - reserved_balance = 100.
+// - repatriate_reserved_amount > reserved_balance.
+// When
+// - After calling repatriate_reserved with a `Reserved` status.
+// Then
+// - The beneficiary's reserved balance should be increased and the remainder returned.
+#[test]
+fn repatriate_reserved_to_reserved_balance() {
+	new_test_ext_with_default().execute_with(|| {
+		GenericAsset::set_reserved_balance(ASSET_ID, &ALICE, 100);
+		GenericAsset::set_reserved_balance(ASSET_ID, &BOB, 5);
+		assert_ok!(
+			GenericAsset::repatriate_reserved(ASSET_ID, &ALICE, &BOB, 130, BalanceStatus::Reserved),
+			30
+		);
+		assert_eq!(GenericAsset::reserved_balance(ASSET_ID, &ALICE), 0);
+		assert_eq!(GenericAsset::reserved_balance(ASSET_ID, &BOB), 105);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 0);
+	});
+}
+
+// Given
+// - reserved_balance = 100.
+// When
+// - After calling repatriate_reserved with a `Reserved` status to the same account.
+// Then
+// - The reserved balance should be unchanged.
+#[test]
+fn repatriate_reserved_to_own_reserved_balance() {
+	new_test_ext_with_default().execute_with(|| {
+		GenericAsset::set_reserved_balance(ASSET_ID, &ALICE, 100);
+		assert_ok!(
+			GenericAsset::repatriate_reserved(ASSET_ID, &ALICE, &ALICE, 60, BalanceStatus::Reserved),
+			0
+		);
+		assert_eq!(GenericAsset::reserved_balance(ASSET_ID, &ALICE), 100);
+	});
+}
+
+#[test]
+fn currency_repatriate_reserved_respects_status() {
+	new_test_ext_with_default().execute_with(|| {
+		let _ = SpendingAssetCurrency::<Test>::deposit_creating(&ALICE, 100);
+		assert_ok!(SpendingAssetCurrency::<Test>::reserve(&ALICE, 100));
+
+		assert_ok!(
+			SpendingAssetCurrency::<Test>::repatriate_reserved(&ALICE, &BOB, 40, BalanceStatus::Free),
+			0
+		);
+		assert_ok!(
+			SpendingAssetCurrency::<Test>::repatriate_reserved(&ALICE, &BOB, 40, BalanceStatus::Reserved),
+			0
+		);
+		assert_eq!(SpendingAssetCurrency::<Test>::reserved_balance(&ALICE), 20);
+		assert_eq!(SpendingAssetCurrency::<Test>::free_balance(&BOB), 40);
+		assert_eq!(SpendingAssetCurrency::<Test>::reserved_balance(&BOB), 40);
+	});
+}
+
 // Given
 // - An asset with all permissions
 // When
@@ -1108,6 +2473,7 @@ fn mint_without_permission_should_throw_error() {
 #[test]
 fn mint_should_increase_asset() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		create_accounts(&[BOB]);
 		let permissions = PermissionLatest::new(ALICE);
 		let amount = 100;
 		let asset_info = AssetInfo::default();
@@ -1126,6 +2492,40 @@ fn mint_should_increase_asset() {
 	});
 }
 
+// Given
+// - ALICE has permissions.
+// - The asset has an existential deposit of 5.
+// When
+// - After minting to an account which holds none of the asset.
+// Then
+// - Should fail below the existential deposit unless it is explicitly allowed.
+#[test]
+fn mint_below_existential_deposit_to_new_account_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		create_accounts(&[BOB, CHARLIE]);
+		let asset_info = AssetInfo::new(b"TST".to_vec(), 4, 5);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
+		));
+
+		assert_noop!(
+			GenericAsset::mint(Origin::signed(ALICE), ASSET_ID, BOB, 4),
+			Error::<Test>::BelowExistentialDeposit,
+		);
+		assert_ok!(GenericAsset::mint_free(ASSET_ID, &ALICE, &CHARLIE, &4, true));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &CHARLIE), 4);
+
+		assert_ok!(GenericAsset::mint(Origin::signed(ALICE), ASSET_ID, BOB, 5));
+		// BOB now holds the asset so smaller amounts may be minted
+		assert_ok!(GenericAsset::mint(Origin::signed(ALICE), ASSET_ID, BOB, 1));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 6);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE + 10);
+	});
+}
+
 // Given
 // - Origin is signed.
 // - Origin does not have burning permission.
@@ -1156,6 +2556,7 @@ fn burn_should_throw_permission_error() {
 #[test]
 fn burn_should_burn_an_asset() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		create_accounts(&[BOB]);
 		let permissions = PermissionLatest::new(ALICE);
 		let mint_amount = 100;
 		let burn_amount = 40;
@@ -1179,6 +2580,73 @@ fn burn_should_burn_an_asset() {
 	});
 }
 
+// Given
+// - Origin is signed.
+// - Origin does not have burning permission.
+// When
+// - After calling burn_self function
+// Then
+// - Should decrease `origin`'s free_balance and the total issuance.
+// - Should raise a `Burned` event.
+#[test]
+fn burn_self_should_burn_without_permission() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		let burn_amount = 40;
+
+		assert!(!GenericAsset::check_permission(STAKING_ASSET_ID, &ALICE, &PermissionType::Burn));
+		assert_ok!(GenericAsset::burn_self(Origin::signed(ALICE), STAKING_ASSET_ID, burn_amount));
+		assert_eq!(
+			GenericAsset::free_balance(STAKING_ASSET_ID, &ALICE),
+			INITIAL_BALANCE - burn_amount
+		);
+		assert_eq!(
+			GenericAsset::total_issuance(STAKING_ASSET_ID),
+			INITIAL_BALANCE - burn_amount
+		);
+
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::Burned(STAKING_ASSET_ID, ALICE, burn_amount));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn burn_self_more_than_free_balance_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_noop!(
+			GenericAsset::burn_self(Origin::signed(ALICE), STAKING_ASSET_ID, INITIAL_BALANCE + 1),
+			Error::<Test>::InsufficientBalance,
+		);
+	});
+}
+
+#[test]
+fn burn_self_to_below_existential_deposit_should_reclaim_dust() {
+	new_test_ext_with_balance(TEST1_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		// TEST1_ASSET_ID has an existential deposit of 3
+		assert_ok!(GenericAsset::burn_self(Origin::signed(ALICE), TEST1_ASSET_ID, INITIAL_BALANCE - 2));
+
+		assert!(!<FreeBalance<Test>>::contains_key(TEST1_ASSET_ID, &ALICE));
+		assert_eq!(GenericAsset::total_issuance(TEST1_ASSET_ID), 2);
+		assert_eq!(GenericAsset::total_dust_reclaimed(TEST1_ASSET_ID), 2);
+		assert!(!System::account_exists(&ALICE));
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::DustReclaimed(TEST1_ASSET_ID, ALICE, 2));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn burn_self_locked_funds_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		GenericAsset::set_lock(ID_1, STAKING_ASSET_ID, &ALICE, INITIAL_BALANCE, WithdrawReasons::all());
+		assert_noop!(
+			GenericAsset::burn_self(Origin::signed(ALICE), STAKING_ASSET_ID, 1),
+			Error::<Test>::LiquidityRestrictions,
+		);
+	});
+}
+
 // Given
 // - `default_permissions` with all privileges.
 // - All permissions for origin.
@@ -1251,12 +2719,14 @@ fn update_permission_should_change_permission() {
 			update: Owner::Address(ALICE),
 			mint: Owner::None,
 			burn: Owner::None,
+			freeze: Owner::None,
 		};
 
 		let new_permission = PermissionLatest {
 			update: Owner::Address(ALICE),
 			mint: Owner::Address(ALICE),
 			burn: Owner::None,
+			freeze: Owner::None,
 		};
 		let asset_info = AssetInfo::default();
 
@@ -1291,6 +2761,7 @@ fn update_permission_should_throw_error_when_lack_of_permissions() {
 			update: Owner::Address(ALICE),
 			mint: Owner::Address(ALICE),
 			burn: Owner::None,
+			freeze: Owner::None,
 		};
 		let asset_info = AssetInfo::default();
 
@@ -1307,6 +2778,53 @@ fn update_permission_should_throw_error_when_lack_of_permissions() {
 	});
 }
 
+#[test]
+fn freeze_only_permission_can_freeze_but_not_mint() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let permissions = PermissionLatest {
+			update: Owner::None,
+			mint: Owner::None,
+			burn: Owner::None,
+			freeze: Owner::Address(BOB),
+		};
+		let asset_info = AssetInfo::default();
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(permissions, asset_info.decimal_places()),
+			asset_info
+		));
+		assert!(GenericAsset::check_permission(ASSET_ID, &BOB, &PermissionType::Freeze));
+		assert!(!GenericAsset::check_permission(ASSET_ID, &BOB, &PermissionType::Mint));
+		assert!(!GenericAsset::check_permission(ASSET_ID, &ALICE, &PermissionType::Freeze));
+		assert_noop!(
+			GenericAsset::mint(Origin::signed(BOB), ASSET_ID, BOB, 10),
+			Error::<Test>::NoMintPermission,
+		);
+	});
+}
+
+#[test]
+fn v1_permissions_migrate_without_freeze_owner() {
+	new_test_ext_with_default().execute_with(|| {
+		let v1 = PermissionsV1 {
+			update: Owner::Address(ALICE),
+			mint: Owner::Address(ALICE),
+			burn: Owner::Address(ALICE),
+		};
+		let encoded = PermissionVersions::V1(v1).encode();
+		let decoded = PermissionVersions::<u64>::decode(&mut &encoded[..]).unwrap();
+		let permissions: PermissionLatest<u64> = decoded.into();
+		assert_eq!(permissions.mint, Owner::Address(ALICE));
+		assert_eq!(permissions.freeze, Owner::None);
+
+		<Permissions<Test>>::insert(ASSET_ID, PermissionVersions::V1(PermissionsV1::new(ALICE)));
+		assert!(GenericAsset::check_permission(ASSET_ID, &ALICE, &PermissionType::Burn));
+		assert!(!GenericAsset::check_permission(ASSET_ID, &ALICE, &PermissionType::Freeze));
+	});
+}
+
 // Given
 // - `asset_id` provided.
 // - `from_account` is present.
@@ -1324,7 +2842,7 @@ fn create_asset_works_with_given_asset_id_and_from_account() {
 	new_test_ext_with_next_asset_id(1001).execute_with(|| {
 		let from_account: Option<<Test as frame_system::Config>::AccountId> = Some(ALICE);
 		let permissions = PermissionLatest::new(ALICE);
-		let expected_permission = PermissionVersions::V1(permissions.clone());
+		let expected_permission = PermissionVersions::V2(permissions.clone());
 		let asset_info = AssetInfo::default();
 
 		assert_ok!(GenericAsset::create_asset(
@@ -1341,6 +2859,43 @@ fn create_asset_works_with_given_asset_id_and_from_account() {
 	});
 }
 
+#[test]
+fn create_asset_calls_on_new_asset_once_per_asset() {
+	new_test_ext_with_next_asset_id(1001).execute_with(|| {
+		let permissions = PermissionLatest::new(ALICE);
+		let asset_info = AssetInfo::default();
+		assert_eq!(mock::new_assets(), vec![]);
+
+		assert_ok!(GenericAsset::create_asset(
+			Some(ASSET_ID),
+			Some(ALICE),
+			asset_options(permissions.clone(), asset_info.decimal_places()),
+			asset_info.clone()
+		));
+		assert_eq!(mock::new_assets(), vec![ASSET_ID]);
+
+		assert_ok!(GenericAsset::create_asset(
+			None,
+			Some(ALICE),
+			asset_options(permissions.clone(), asset_info.decimal_places()),
+			asset_info.clone()
+		));
+		assert_eq!(mock::new_assets(), vec![ASSET_ID, 1001]);
+
+		// a failed creation does not call the hook
+		assert_noop!(
+			GenericAsset::create_asset(
+				Some(ASSET_ID),
+				Some(ALICE),
+				asset_options(permissions, asset_info.decimal_places()),
+				asset_info
+			),
+			Error::<Test>::AssetIdExists,
+		);
+		assert_eq!(mock::new_assets(), vec![ASSET_ID, 1001]);
+	});
+}
+
 // Given
 // - `asset_id` is an id for user generated assets.
 // - Whatever other params.
@@ -1501,142 +3056,449 @@ fn create_asset_should_create_a_user_asset() {
 			asset_info
 		));
 
-		// Test for side effects.
-		assert_eq!(<FreeBalance<Test>>::get(&reserved_asset_id, &ALICE), 0);
-		assert_eq!(<FreeBalance<Test>>::get(&ASSET_ID, &0), INITIAL_ISSUANCE);
-		assert_eq!(<TotalIssuance<Test>>::get(ASSET_ID), INITIAL_ISSUANCE);
+		// Test for side effects.
+		assert_eq!(<FreeBalance<Test>>::get(&reserved_asset_id, &ALICE), 0);
+		assert_eq!(<FreeBalance<Test>>::get(&ASSET_ID, &0), INITIAL_ISSUANCE);
+		assert_eq!(<TotalIssuance<Test>>::get(ASSET_ID), INITIAL_ISSUANCE);
+	});
+}
+
+#[test]
+fn create_asset_with_big_decimal_place_should_fail() {
+	new_test_ext_with_default().execute_with(|| {
+		let from_account: Option<<Test as frame_system::Config>::AccountId> = None;
+		let permissions = PermissionLatest::new(ALICE);
+		let asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 40, 7);
+
+		assert_noop!(
+			GenericAsset::create_asset(
+				None,
+				from_account,
+				asset_options(permissions, asset_info.decimal_places()),
+				asset_info
+			),
+			Error::<Test>::DecimalTooLarge
+		);
+	});
+}
+
+#[test]
+fn create_asset_with_too_big_issuance_should_fail() {
+	new_test_ext_with_default().execute_with(|| {
+		let from_account: Option<<Test as frame_system::Config>::AccountId> = None;
+		let permissions = PermissionLatest::new(ALICE);
+		let asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 38, 7);
+
+		assert_noop!(
+			GenericAsset::create_asset(
+				None,
+				from_account,
+				AssetOptions {
+					initial_issuance: u64::MAX,
+					permissions,
+				},
+				asset_info,
+			),
+			Error::<Test>::InitialIssuanceTooLarge
+		);
+	});
+}
+
+#[test]
+fn create_with_endowment_should_credit_endowed_accounts() {
+	new_test_ext_with_default().execute_with(|| {
+		System::set_block_number(1);
+		create_accounts(&[BOB, CHARLIE]);
+		let permissions = PermissionLatest::new(ALICE);
+		let asset_info = AssetInfo::default();
+		let options = asset_options(permissions, asset_info.decimal_places());
+
+		assert_ok!(GenericAsset::create_with_endowment(
+			Origin::root(),
+			ALICE,
+			options.clone(),
+			asset_info,
+			vec![(BOB, 100), (CHARLIE, 200), (ALICE, 300)],
+		));
+
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &ALICE), INITIAL_ISSUANCE + 300);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 100);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &CHARLIE), 200);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE + 600);
+
+		let expected_events = vec![
+			TestEvent::prml_generic_asset(RawEvent::Created(ASSET_ID, ALICE, options)),
+			TestEvent::prml_generic_asset(RawEvent::Minted(ASSET_ID, BOB, 100)),
+			TestEvent::prml_generic_asset(RawEvent::Minted(ASSET_ID, CHARLIE, 200)),
+			TestEvent::prml_generic_asset(RawEvent::Minted(ASSET_ID, ALICE, 300)),
+		];
+		let events: Vec<TestEvent> = System::events().into_iter().map(|r| r.event).collect();
+		assert!(expected_events.iter().all(|e| events.contains(e)));
+	});
+}
+
+#[test]
+fn create_with_endowment_overflowing_total_issuance_should_fail() {
+	new_test_ext_with_default().execute_with(|| {
+		let permissions = PermissionLatest::new(ALICE);
+		let asset_info = AssetInfo::default();
+
+		assert_noop!(
+			GenericAsset::create_with_endowment(
+				Origin::root(),
+				ALICE,
+				asset_options(permissions, asset_info.decimal_places()),
+				asset_info,
+				vec![(BOB, u64::MAX - INITIAL_ISSUANCE), (CHARLIE, 1)],
+			),
+			Error::<Test>::EndowmentOverflow
+		);
+		assert_eq!(GenericAsset::next_asset_id(), ASSET_ID);
+	});
+}
+
+#[test]
+fn create_with_endowment_should_check_each_endowment_like_mint() {
+	new_test_ext_with_default().execute_with(|| {
+		create_accounts(&[BOB]);
+		let asset_info = AssetInfo::new(b"TST".to_vec(), 4, 5);
+
+		assert_noop!(
+			GenericAsset::create_with_endowment(
+				Origin::root(),
+				ALICE,
+				asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+				asset_info.clone(),
+				vec![(BOB, 100), (CHARLIE, 4)],
+			),
+			Error::<Test>::BelowExistentialDeposit
+		);
+		assert_noop!(
+			GenericAsset::create_with_endowment(
+				Origin::root(),
+				ALICE,
+				asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+				asset_info.clone(),
+				vec![(BOB, 100), (GenericAssetModuleId::get().into_account(), 100)],
+			),
+			Error::<Test>::BlockedRecipient
+		);
+		// CHARLIE does not exist and the asset is not sufficient
+		assert_noop!(
+			GenericAsset::create_with_endowment(
+				Origin::root(),
+				ALICE,
+				asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+				asset_info.clone(),
+				vec![(BOB, 100), (CHARLIE, 100)],
+			),
+			Error::<Test>::NoProvider
+		);
+		assert_eq!(GenericAsset::next_asset_id(), ASSET_ID);
+
+		// ALICE holds the initial issuance, so may be endowed less than the existential deposit
+		assert_ok!(GenericAsset::create_with_endowment(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info,
+			vec![(ALICE, 1)],
+		));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &ALICE), INITIAL_ISSUANCE + 1);
+	});
+}
+
+#[test]
+fn create_asset_should_add_decimal_places_minimum() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let web3_asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 0, 7);
+		let permissions = PermissionLatest::new(ALICE);
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(permissions, web3_asset_info.decimal_places()),
+			web3_asset_info.clone()
+		));
+
+		assert_eq!(<AssetMeta<Test>>::get(ASSET_ID), web3_asset_info);
+		assert_eq!(GenericAsset::total_issuance(&ASSET_ID), INITIAL_ISSUANCE);
+	});
+}
+
+#[test]
+fn create_asset_should_work_with_max_decimal_places() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let web3_asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 18, 7);
+		let permissions = PermissionLatest::new(ALICE);
+		let initial_issuance: u64 = 10_000_000_000_000_000_000;
+		let decimal_factor = 10u128.saturating_pow(web3_asset_info.decimal_places().into());
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			AssetOptions {
+				initial_issuance: (initial_issuance / decimal_factor as u64),
+				permissions,
+			},
+			web3_asset_info.clone(),
+		));
+
+		assert_eq!(<AssetMeta<Test>>::get(ASSET_ID), web3_asset_info);
+		assert_eq!(GenericAsset::total_issuance(&ASSET_ID), initial_issuance);
+	});
+}
+
+#[test]
+fn update_permission_should_raise_event() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+
+		let permissions = PermissionLatest::new(ALICE);
+		let asset_info = AssetInfo::default();
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(permissions.clone(), asset_info.decimal_places()),
+			asset_info
+		));
+		assert_ok!(GenericAsset::update_permission(
+			Origin::signed(ALICE),
+			ASSET_ID,
+			permissions.clone()
+		));
+
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::PermissionUpdated(ASSET_ID, permissions));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn transfer_ownership_should_move_all_permissions() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		let asset_info = AssetInfo::default();
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
+		));
+		assert_ok!(GenericAsset::transfer_ownership(Origin::signed(ALICE), ASSET_ID, BOB));
+
+		let expected: PermissionVersions<_> = PermissionLatest::new(BOB).into();
+		assert_eq!(GenericAsset::get_permission(ASSET_ID), expected);
+		assert!(!GenericAsset::check_permission(ASSET_ID, &ALICE, &PermissionType::Update));
+
+		let expected_event =
+			TestEvent::prml_generic_asset(RawEvent::PermissionUpdated(ASSET_ID, PermissionLatest::new(BOB)));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::OwnershipTransferred(ASSET_ID, ALICE, BOB));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn transfer_ownership_without_update_permission_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		let asset_info = AssetInfo::default();
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
+		));
+		assert_noop!(
+			GenericAsset::transfer_ownership(Origin::signed(BOB), ASSET_ID, BOB),
+			Error::<Test>::NoUpdatePermission,
+		);
+	});
+}
+
+#[test]
+fn mint_should_raise_event() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		create_accounts(&[BOB]);
+
+		let permissions = PermissionLatest::new(ALICE);
+		let amount = 100;
+		let asset_info = AssetInfo::default();
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(permissions, asset_info.decimal_places()),
+			asset_info
+		));
+		assert_ok!(GenericAsset::mint(Origin::signed(ALICE), ASSET_ID, BOB, amount));
+
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::Minted(ASSET_ID, BOB, amount));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+		let expected_event =
+			TestEvent::prml_generic_asset(RawEvent::IssuanceChanged(ASSET_ID, INITIAL_ISSUANCE + amount));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
 	});
 }
 
 #[test]
-fn create_asset_with_big_decimal_place_should_fail() {
-	new_test_ext_with_default().execute_with(|| {
-		let from_account: Option<<Test as frame_system::Config>::AccountId> = None;
-		let permissions = PermissionLatest::new(ALICE);
-		let asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 40, 7);
+fn mint_many_should_credit_each_recipient() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+		create_accounts(&[BOB, CHARLIE]);
+		let asset_info = AssetInfo::default();
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
+		));
 
-		assert_noop!(
-			GenericAsset::create_asset(
-				None,
-				from_account,
-				asset_options(permissions, asset_info.decimal_places()),
-				asset_info
-			),
-			Error::<Test>::DecimalTooLarge
-		);
-	});
-}
+		assert_ok!(GenericAsset::mint_many(
+			Origin::signed(ALICE),
+			ASSET_ID,
+			vec![(BOB, 100), (CHARLIE, 200)]
+		));
 
-#[test]
-fn create_asset_with_too_big_issuance_should_fail() {
-	new_test_ext_with_default().execute_with(|| {
-		let from_account: Option<<Test as frame_system::Config>::AccountId> = None;
-		let permissions = PermissionLatest::new(ALICE);
-		let asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 38, 7);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 100);
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &CHARLIE), 200);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE + 300);
 
-		assert_noop!(
-			GenericAsset::create_asset(
-				None,
-				from_account,
-				AssetOptions {
-					initial_issuance: u64::MAX,
-					permissions,
-				},
-				asset_info,
-			),
-			Error::<Test>::InitialIssuanceTooLarge
-		);
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::Minted(ASSET_ID, BOB, 100));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::Minted(ASSET_ID, CHARLIE, 200));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
 	});
 }
 
 #[test]
-fn create_asset_should_add_decimal_places_minimum() {
+fn mint_many_with_an_overflowing_total_should_fail() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
-		let web3_asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 0, 7);
-		let permissions = PermissionLatest::new(ALICE);
-
+		let asset_info = AssetInfo::default();
 		assert_ok!(GenericAsset::create(
 			Origin::root(),
 			ALICE,
-			asset_options(permissions, web3_asset_info.decimal_places()),
-			web3_asset_info.clone()
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
 		));
 
-		assert_eq!(<AssetMeta<Test>>::get(ASSET_ID), web3_asset_info);
-		assert_eq!(GenericAsset::total_issuance(&ASSET_ID), INITIAL_ISSUANCE);
+		// Each amount fits on its own, but the sum overflows the total issuance
+		assert_noop!(
+			GenericAsset::mint_many(
+				Origin::signed(ALICE),
+				ASSET_ID,
+				vec![(BOB, u64::MAX / 2), (CHARLIE, u64::MAX / 2)]
+			),
+			Error::<Test>::TotalMintingOverflow
+		);
 	});
 }
 
 #[test]
-fn create_asset_should_work_with_max_decimal_places() {
+fn mint_many_should_check_permission_and_batch_size() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
-		let web3_asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 18, 7);
-		let permissions = PermissionLatest::new(ALICE);
-		let initial_issuance: u64 = 10_000_000_000_000_000_000;
-		let decimal_factor = 10u128.saturating_pow(web3_asset_info.decimal_places().into());
-
+		let asset_info = AssetInfo::default();
 		assert_ok!(GenericAsset::create(
 			Origin::root(),
 			ALICE,
-			AssetOptions {
-				initial_issuance: (initial_issuance / decimal_factor as u64),
-				permissions,
-			},
-			web3_asset_info.clone(),
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info
 		));
 
-		assert_eq!(<AssetMeta<Test>>::get(ASSET_ID), web3_asset_info);
-		assert_eq!(GenericAsset::total_issuance(&ASSET_ID), initial_issuance);
+		assert_noop!(
+			GenericAsset::mint_many(Origin::signed(BOB), ASSET_ID, vec![(BOB, 100)]),
+			Error::<Test>::NoMintPermission
+		);
+		// The mock `MaxMintBatch` is 3
+		assert_noop!(
+			GenericAsset::mint_many(
+				Origin::signed(ALICE),
+				ASSET_ID,
+				vec![(ALICE, 1), (BOB, 1), (CHARLIE, 1), (4, 1)]
+			),
+			Error::<Test>::TooManyRecipients
+		);
 	});
 }
 
 #[test]
-fn update_permission_should_raise_event() {
+fn mint_many_should_check_each_recipient_like_mint() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
-		System::set_block_number(1);
-
-		let permissions = PermissionLatest::new(ALICE);
-		let asset_info = AssetInfo::default();
-
+		create_accounts(&[BOB]);
+		let asset_info = AssetInfo::new(b"TST".to_vec(), 4, 5);
 		assert_ok!(GenericAsset::create(
 			Origin::root(),
 			ALICE,
-			asset_options(permissions.clone(), asset_info.decimal_places()),
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
 			asset_info
 		));
-		assert_ok!(GenericAsset::update_permission(
+
+		// CHARLIE holds none of the asset, so 4 is below the existential deposit of 5
+		assert_noop!(
+			GenericAsset::mint_many(Origin::signed(ALICE), ASSET_ID, vec![(BOB, 100), (CHARLIE, 4)]),
+			Error::<Test>::BelowExistentialDeposit
+		);
+		let module_account = GenericAssetModuleId::get().into_account();
+		assert_noop!(
+			GenericAsset::mint_many(Origin::signed(ALICE), ASSET_ID, vec![(BOB, 100), (module_account, 100)]),
+			Error::<Test>::BlockedRecipient
+		);
+		// CHARLIE does not exist and the asset is not sufficient
+		assert_noop!(
+			GenericAsset::mint_many(Origin::signed(ALICE), ASSET_ID, vec![(BOB, 100), (CHARLIE, 100)]),
+			Error::<Test>::NoProvider
+		);
+
+		// A recipient credited earlier in the batch may receive less than the existential deposit
+		assert_ok!(GenericAsset::mint_many(
 			Origin::signed(ALICE),
 			ASSET_ID,
-			permissions.clone()
+			vec![(BOB, 5), (BOB, 1)]
 		));
-
-		let expected_event = TestEvent::prml_generic_asset(RawEvent::PermissionUpdated(ASSET_ID, permissions));
-		assert!(System::events().iter().any(|record| record.event == expected_event));
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 6);
 	});
 }
 
 #[test]
-fn mint_should_raise_event() {
+fn mint_reserved_should_credit_reserved_balance() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
 		System::set_block_number(1);
 
-		let permissions = PermissionLatest::new(ALICE);
 		let amount = 100;
-		let asset_info = AssetInfo::default();
+		let mut asset_info = AssetInfo::default();
+		asset_info.set_sufficient(true);
 
 		assert_ok!(GenericAsset::create(
 			Origin::root(),
 			ALICE,
-			asset_options(permissions, asset_info.decimal_places()),
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
 			asset_info
 		));
-		assert_ok!(GenericAsset::mint(Origin::signed(ALICE), ASSET_ID, BOB, amount));
+		assert_ok!(GenericAsset::mint_reserved(ASSET_ID, &ALICE, &BOB, amount));
+
+		assert_eq!(GenericAsset::free_balance(ASSET_ID, &BOB), 0);
+		assert_eq!(GenericAsset::reserved_balance(ASSET_ID, &BOB), amount);
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), INITIAL_ISSUANCE + amount);
+		assert!(System::account_exists(&BOB));
 
 		let expected_event = TestEvent::prml_generic_asset(RawEvent::Minted(ASSET_ID, BOB, amount));
 		assert!(System::events().iter().any(|record| record.event == expected_event));
 	});
 }
 
+#[test]
+fn mint_reserved_without_permission_should_fail() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert_noop!(
+			GenericAsset::mint_reserved(STAKING_ASSET_ID, &ALICE, &BOB, 100),
+			Error::<Test>::NoMintPermission,
+		);
+	});
+}
+
 #[test]
 fn burn_should_raise_event() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
@@ -1656,18 +3518,69 @@ fn burn_should_raise_event() {
 
 		let expected_event = TestEvent::prml_generic_asset(RawEvent::Burned(ASSET_ID, ALICE, amount));
 		assert!(System::events().iter().any(|record| record.event == expected_event));
+		let expected_event =
+			TestEvent::prml_generic_asset(RawEvent::IssuanceChanged(ASSET_ID, INITIAL_ISSUANCE - amount));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn reserve_with_event_and_unreserve_should_raise_events() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(GenericAsset::reserve_with_event(STAKING_ASSET_ID, &ALICE, 40));
+		let expected_event = TestEvent::prml_generic_asset(RawEvent::Reserved(STAKING_ASSET_ID, ALICE, 40));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+
+		// Only the amount actually moved is reported, which is `amount - leftover`
+		let leftover = GenericAsset::unreserve(STAKING_ASSET_ID, &ALICE, 50);
+		assert_eq!(leftover, 10);
+		let expected_event =
+			TestEvent::prml_generic_asset(RawEvent::Unreserved(STAKING_ASSET_ID, ALICE, 50 - leftover));
+		assert!(System::events().iter().any(|record| record.event == expected_event));
+
+		// Nothing is moved so nothing is reported
+		let events = System::events().len();
+		assert_eq!(GenericAsset::unreserve(STAKING_ASSET_ID, &ALICE, 50), 50);
+		assert_eq!(System::events().len(), events);
+	});
+}
+
+#[test]
+fn reserve_and_unreserve_silent_should_not_raise_events() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(GenericAsset::reserve(STAKING_ASSET_ID, &ALICE, 40));
+		assert!(GenericAsset::unreserve_silent(STAKING_ASSET_ID, &ALICE, 40).is_zero());
+		assert!(System::events().is_empty());
 	});
 }
 
 #[test]
 fn can_set_asset_owner_permissions_in_genesis() {
 	new_test_ext_with_permissions(vec![(ASSET_ID, ALICE)]).execute_with(|| {
-		let expected: PermissionVersions<_> = PermissionsV1::new(ALICE).into();
+		let expected: PermissionVersions<_> = PermissionLatest::new(ALICE).into();
 		let actual = GenericAsset::get_permission(ASSET_ID);
 		assert_eq!(expected, actual);
 	});
 }
 
+#[test]
+#[should_panic(expected = "Genesis issuance of `initial_balance` for every endowed account overflows the balance type")]
+fn genesis_issuance_overflow_panics() {
+	// u64::max_value() * 2 used to wrap around to u64::max_value() - 1
+	mock::new_test_ext(vec![ASSET_ID], vec![ALICE, BOB], u64::max_value(), vec![], ASSET_ID);
+}
+
+#[test]
+fn genesis_issuance_is_initial_balance_for_each_endowed_account() {
+	mock::new_test_ext(vec![ASSET_ID], vec![ALICE, BOB], u64::max_value() / 2, vec![], ASSET_ID).execute_with(|| {
+		assert_eq!(GenericAsset::total_issuance(ASSET_ID), u64::max_value() - 1);
+	});
+}
+
 #[test]
 fn zero_asset_id_should_updated_after_negative_imbalance_operations() {
 	let asset_id = 16000;
@@ -1920,13 +3833,31 @@ fn total_issuance_should_update_after_negative_imbalance_dropped() {
 	});
 }
 
+#[test]
+fn asset_exists_for_should_ignore_dust() {
+	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
+		assert!(GenericAsset::asset_exists_for(STAKING_ASSET_ID, &ALICE));
+		assert!(!GenericAsset::asset_exists_for(STAKING_ASSET_ID, &BOB));
+
+		// TEST1_ASSET_ID has an existential deposit of 3
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &BOB, 2);
+		assert!(!GenericAsset::asset_exists_for(TEST1_ASSET_ID, &BOB));
+		GenericAsset::set_free_balance(TEST1_ASSET_ID, &BOB, 3);
+		assert!(GenericAsset::asset_exists_for(TEST1_ASSET_ID, &BOB));
+
+		// A reserved balance alone is enough
+		GenericAsset::set_reserved_balance(TEST1_ASSET_ID, &CHARLIE, 1);
+		assert!(GenericAsset::asset_exists_for(TEST1_ASSET_ID, &CHARLIE));
+	});
+}
+
 #[test]
 fn query_pre_existing_asset_info() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
 		assert_eq!(
 			GenericAsset::registered_assets(),
 			vec![
-				(TEST1_ASSET_ID, AssetInfo::new(b"TST1".to_vec(), 1, 3)),
+				(TEST1_ASSET_ID, AssetInfo::new(b"TEST1".to_vec(), 1, 3)),
 				(TEST2_ASSET_ID, AssetInfo::new(b"TST 2".to_vec(), 2, 5)),
 				(STAKING_ASSET_ID, AssetInfo::default()),
 			]
@@ -1991,6 +3922,146 @@ fn owner_update_asset_info() {
 	});
 }
 
+#[test]
+fn create_asset_with_symbol_at_max_length_should_work() {
+	new_test_ext_with_default().execute_with(|| {
+		// The mock `MaxSymbolLength` is 8
+		let asset_info = AssetInfo::new(b"ABCDEFGH".to_vec(), 3, 7);
+
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), asset_info.decimal_places()),
+			asset_info.clone()
+		));
+		assert_eq!(<AssetMeta<Test>>::get(ASSET_ID), asset_info);
+	});
+}
+
+#[test]
+fn create_asset_with_invalid_symbol_should_fail() {
+	new_test_ext_with_default().execute_with(|| {
+		let too_long = AssetInfo::new(b"ABCDEFGHI".to_vec(), 3, 7);
+		assert_noop!(
+			GenericAsset::create(
+				Origin::root(),
+				ALICE,
+				asset_options(PermissionLatest::new(ALICE), too_long.decimal_places()),
+				too_long
+			),
+			Error::<Test>::SymbolTooLong
+		);
+
+		let non_ascii = AssetInfo::new(b"WEB\xFF".to_vec(), 3, 7);
+		assert_noop!(
+			GenericAsset::create(
+				Origin::root(),
+				ALICE,
+				asset_options(PermissionLatest::new(ALICE), non_ascii.decimal_places()),
+				non_ascii
+			),
+			Error::<Test>::InvalidSymbol
+		);
+	});
+}
+
+#[test]
+fn update_asset_info_with_invalid_symbol_should_fail() {
+	new_test_ext_with_default().execute_with(|| {
+		let web3_asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 3, 7);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), web3_asset_info.decimal_places()),
+			web3_asset_info
+		));
+
+		assert_noop!(
+			GenericAsset::update_asset_info(
+				Origin::signed(ALICE),
+				ASSET_ID,
+				AssetInfo::new(b"ABCDEFGHI".to_vec(), 3, 7)
+			),
+			Error::<Test>::SymbolTooLong
+		);
+		assert_noop!(
+			GenericAsset::update_asset_info(Origin::signed(ALICE), ASSET_ID, AssetInfo::new(b"WEB\xFF".to_vec(), 3, 7)),
+			Error::<Test>::InvalidSymbol
+		);
+	});
+}
+
+#[test]
+fn asset_symbols_are_unique() {
+	new_test_ext_with_default().execute_with(|| {
+		assert_eq!(GenericAsset::id_for_symbol(b"TEST1"), Some(TEST1_ASSET_ID));
+
+		let web3_asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 3, 7);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), web3_asset_info.decimal_places()),
+			web3_asset_info.clone()
+		));
+		assert_eq!(GenericAsset::id_for_symbol(b"WEB3.0"), Some(ASSET_ID));
+
+		assert_noop!(
+			GenericAsset::create(
+				Origin::root(),
+				BOB,
+				asset_options(PermissionLatest::new(BOB), web3_asset_info.decimal_places()),
+				web3_asset_info.clone()
+			),
+			Error::<Test>::SymbolTaken
+		);
+		assert_noop!(
+			GenericAsset::update_asset_info(Origin::signed(ALICE), ASSET_ID, AssetInfo::new(b"TEST1".to_vec(), 3, 7)),
+			Error::<Test>::SymbolTaken
+		);
+
+		// An asset can keep its own symbol
+		assert_ok!(GenericAsset::update_asset_info(
+			Origin::signed(ALICE),
+			ASSET_ID,
+			AssetInfo::new(b"WEB3.0".to_vec(), 5, 11)
+		));
+		assert_eq!(GenericAsset::id_for_symbol(b"WEB3.0"), Some(ASSET_ID));
+
+		// Empty symbols are not indexed
+		assert_eq!(GenericAsset::id_for_symbol(b""), None);
+	});
+}
+
+#[test]
+fn renaming_an_asset_frees_its_old_symbol() {
+	new_test_ext_with_default().execute_with(|| {
+		let web3_asset_info = AssetInfo::new(b"WEB3.0".to_vec(), 3, 7);
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			ALICE,
+			asset_options(PermissionLatest::new(ALICE), web3_asset_info.decimal_places()),
+			web3_asset_info.clone()
+		));
+
+		assert_ok!(GenericAsset::update_asset_info(
+			Origin::signed(ALICE),
+			ASSET_ID,
+			AssetInfo::new(b"WEB3.1".to_vec(), 3, 7)
+		));
+		assert_eq!(GenericAsset::id_for_symbol(b"WEB3.0"), None);
+		assert_eq!(GenericAsset::id_for_symbol(b"WEB3.1"), Some(ASSET_ID));
+
+		// The old symbol can be taken by a new asset
+		assert_ok!(GenericAsset::create(
+			Origin::root(),
+			BOB,
+			asset_options(PermissionLatest::new(BOB), web3_asset_info.decimal_places()),
+			web3_asset_info
+		));
+		assert_eq!(GenericAsset::id_for_symbol(b"WEB3.0"), Some(ASSET_ID + 1));
+	});
+}
+
 #[test]
 fn non_owner_permitted_update_asset_info() {
 	new_test_ext_with_balance(STAKING_ASSET_ID, ALICE, INITIAL_BALANCE).execute_with(|| {
@@ -2018,6 +4089,7 @@ fn non_owner_permitted_update_asset_info() {
 			update: Owner::Address(BOB),
 			mint: Owner::None,
 			burn: Owner::None,
+			freeze: Owner::None,
 		};
 		assert_ok!(GenericAsset::update_permission(
 			Origin::signed(ALICE),
@@ -1,18 +1,72 @@
 //! Generic Asset Types
 
 use codec::{Decode, Encode, Error as CodecError, HasCompact, Input, Output};
-use frame_support::traits::{LockIdentifier, WithdrawReasons};
+use frame_support::traits::{Contains, LockIdentifier, WithdrawReasons};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+/// Identifier for a named reserve, allowing subsystems to reserve independently of each other
+pub type ReserveId = [u8; 8];
+
+/// Handler for when a new asset has been created
+pub trait OnNewAsset<AssetId> {
+	/// Called once `asset_id` has been created
+	fn on_new_asset(asset_id: AssetId);
+}
+
+impl<AssetId> OnNewAsset<AssetId> for () {
+	fn on_new_asset(_asset_id: AssetId) {}
+}
+
+/// An empty set of blocked recipients, allowing transfers to any account
+pub struct NoBlockedRecipients;
+
+impl<AccountId: Ord> Contains<AccountId> for NoBlockedRecipients {
+	fn contains(_who: &AccountId) -> bool {
+		false
+	}
+
+	fn sorted_members() -> Vec<AccountId> {
+		Vec::new()
+	}
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct BalanceLock<Balance> {
+pub struct BalanceLock<Balance, BlockNumber> {
 	pub id: LockIdentifier,
 	pub amount: Balance,
 	pub reasons: WithdrawReasons,
+	/// The block from which the lock is released, `None` if it never expires
+	pub until: Option<BlockNumber>,
+}
+
+impl<Balance, BlockNumber: PartialOrd> BalanceLock<Balance, BlockNumber> {
+	/// Whether the lock still restricts withdrawals at block `now`
+	pub fn is_active(&self, now: &BlockNumber) -> bool {
+		self.until.as_ref().map_or(true, |until| now < until)
+	}
+}
+
+/// A balance lock as stored before locks could expire
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct LegacyBalanceLock<Balance> {
+	pub id: LockIdentifier,
+	pub amount: Balance,
+	pub reasons: WithdrawReasons,
+}
+
+impl<Balance, BlockNumber> From<LegacyBalanceLock<Balance>> for BalanceLock<Balance, BlockNumber> {
+	fn from(lock: LegacyBalanceLock<Balance>) -> Self {
+		BalanceLock {
+			id: lock.id,
+			amount: lock.amount,
+			reasons: lock.reasons,
+			until: None,
+		}
+	}
 }
 
 /// Asset Metadata
@@ -22,26 +76,72 @@ pub struct AssetInfo {
 	symbol: Vec<u8>,
 	decimal_places: u8,
 	existential_deposit: u64,
+	/// Free balances below this are reclaimed as dust, it is never lower than the existential deposit
+	#[cfg_attr(feature = "std", serde(default))]
+	dust_threshold: u64,
+	/// Burned from the sender on each transfer
+	#[cfg_attr(feature = "std", serde(default))]
+	transfer_fee: u64,
+	/// Whether the asset is meant to be held without any of the native assets
+	#[cfg_attr(feature = "std", serde(default))]
+	sufficient: bool,
 }
 
 impl AssetInfo {
 	/// Create a new asset info by specifying its name/symbol and the number of decimal places
 	/// in the asset's balance. i.e. balance x 10 ^ -decimals will be the value for display
+	/// The dust threshold defaults to the existential deposit, there is no transfer fee and the asset is not
+	/// sufficient.
 	pub fn new(symbol: Vec<u8>, decimal_places: u8, existential_deposit: u64) -> Self {
 		Self {
 			symbol,
 			decimal_places,
 			existential_deposit,
+			dust_threshold: existential_deposit,
+			transfer_fee: 0,
+			sufficient: false,
 		}
 	}
 
+	pub fn symbol(&self) -> &[u8] {
+		&self.symbol
+	}
+
 	pub fn existential_deposit(&self) -> u64 {
 		self.existential_deposit
 	}
 
+	pub fn set_existential_deposit(&mut self, existential_deposit: u64) {
+		self.existential_deposit = existential_deposit;
+	}
+
+	pub fn dust_threshold(&self) -> u64 {
+		self.dust_threshold.max(self.existential_deposit)
+	}
+
+	pub fn set_dust_threshold(&mut self, dust_threshold: u64) {
+		self.dust_threshold = dust_threshold;
+	}
+
+	pub fn transfer_fee(&self) -> u64 {
+		self.transfer_fee
+	}
+
+	pub fn set_transfer_fee(&mut self, transfer_fee: u64) {
+		self.transfer_fee = transfer_fee;
+	}
+
 	pub fn decimal_places(&self) -> u8 {
 		self.decimal_places
 	}
+
+	pub fn sufficient(&self) -> bool {
+		self.sufficient
+	}
+
+	pub fn set_sufficient(&mut self, sufficient: bool) {
+		self.sufficient = sufficient;
+	}
 }
 
 impl Default for AssetInfo {
@@ -50,10 +150,63 @@ impl Default for AssetInfo {
 			symbol: vec![],
 			decimal_places: 4,
 			existential_deposit: 1,
+			dust_threshold: 1,
+			transfer_fee: 0,
+			sufficient: false,
 		}
 	}
 }
 
+/// Asset metadata as stored before the dust threshold was introduced
+#[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AssetInfoV0 {
+	symbol: Vec<u8>,
+	decimal_places: u8,
+	existential_deposit: u64,
+}
+
+impl From<AssetInfoV0> for AssetInfo {
+	fn from(info: AssetInfoV0) -> Self {
+		AssetInfo::new(info.symbol, info.decimal_places, info.existential_deposit)
+	}
+}
+
+/// Asset metadata as stored before the transfer fee was introduced
+#[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AssetInfoV3 {
+	symbol: Vec<u8>,
+	decimal_places: u8,
+	existential_deposit: u64,
+	dust_threshold: u64,
+}
+
+impl From<AssetInfoV3> for AssetInfo {
+	fn from(info: AssetInfoV3) -> Self {
+		let mut asset_info = AssetInfo::new(info.symbol, info.decimal_places, info.existential_deposit);
+		asset_info.set_dust_threshold(info.dust_threshold);
+		asset_info
+	}
+}
+
+/// Asset metadata as stored before assets could be sufficient
+#[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AssetInfoV4 {
+	symbol: Vec<u8>,
+	decimal_places: u8,
+	existential_deposit: u64,
+	dust_threshold: u64,
+	transfer_fee: u64,
+}
+
+impl From<AssetInfoV4> for AssetInfo {
+	fn from(info: AssetInfoV4) -> Self {
+		let mut asset_info = AssetInfo::new(info.symbol, info.decimal_places, info.existential_deposit);
+		asset_info.set_dust_threshold(info.dust_threshold);
+		asset_info.set_transfer_fee(info.transfer_fee);
+		asset_info
+	}
+}
+
 /// Asset creation options.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
 pub struct AssetOptions<Balance: HasCompact, AccountId> {
@@ -101,16 +254,43 @@ impl<AccountId: Clone> PermissionsV1<AccountId> {
 	}
 }
 
+/// Asset permissions, adding who may freeze the asset
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct PermissionsV2<AccountId> {
+	/// Who have permission to update asset permission
+	pub update: Owner<AccountId>,
+	/// Who have permission to mint new asset
+	pub mint: Owner<AccountId>,
+	/// Who have permission to burn asset
+	pub burn: Owner<AccountId>,
+	/// Who have permission to freeze asset
+	pub freeze: Owner<AccountId>,
+}
+
+impl<AccountId: Clone> PermissionsV2<AccountId> {
+	/// Create a new `PermissionV2` with all permission to the given `owner`
+	pub fn new(owner: AccountId) -> Self {
+		Self {
+			update: Owner::Address(owner.clone()),
+			mint: Owner::Address(owner.clone()),
+			burn: Owner::Address(owner.clone()),
+			freeze: Owner::Address(owner),
+		}
+	}
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
 #[repr(u8)]
 enum PermissionVersionNumber {
 	V1 = 0,
+	V2 = 1,
 }
 
 /// Versioned asset permission
 #[derive(Clone, PartialEq, Eq, RuntimeDebug)]
 pub enum PermissionVersions<AccountId> {
 	V1(PermissionsV1<AccountId>),
+	V2(PermissionsV2<AccountId>),
 }
 
 /// Asset permission types
@@ -121,14 +301,16 @@ pub enum PermissionType {
 	Mint,
 	/// Permission to update asset
 	Update,
+	/// Permission to freeze asset
+	Freeze,
 }
 
 /// Alias to latest asset permissions
-pub type PermissionLatest<AccountId> = PermissionsV1<AccountId>;
+pub type PermissionLatest<AccountId> = PermissionsV2<AccountId>;
 
 impl<AccountId> Default for PermissionVersions<AccountId> {
 	fn default() -> Self {
-		PermissionVersions::V1(Default::default())
+		PermissionVersions::V2(Default::default())
 	}
 }
 
@@ -139,6 +321,10 @@ impl<AccountId: Encode> Encode for PermissionVersions<AccountId> {
 				dest.write(&PermissionVersionNumber::V1.encode());
 				dest.write(&payload.encode());
 			}
+			PermissionVersions::V2(payload) => {
+				dest.write(&PermissionVersionNumber::V2.encode());
+				dest.write(&payload.encode());
+			}
 		}
 	}
 }
@@ -150,6 +336,7 @@ impl<AccountId: Decode> Decode for PermissionVersions<AccountId> {
 		let version = PermissionVersionNumber::decode(input)?;
 		Ok(match version {
 			PermissionVersionNumber::V1 => PermissionVersions::V1(Decode::decode(input)?),
+			PermissionVersionNumber::V2 => PermissionVersions::V2(Decode::decode(input)?),
 		})
 	}
 }
@@ -164,10 +351,34 @@ impl<AccountId> Default for PermissionsV1<AccountId> {
 	}
 }
 
+impl<AccountId> Default for PermissionsV2<AccountId> {
+	fn default() -> Self {
+		PermissionsV2 {
+			update: Owner::None,
+			mint: Owner::None,
+			burn: Owner::None,
+			freeze: Owner::None,
+		}
+	}
+}
+
+/// Migrates `V1` permissions, nobody may freeze the asset until it is granted by an update
+impl<AccountId> From<PermissionsV1<AccountId>> for PermissionsV2<AccountId> {
+	fn from(v1: PermissionsV1<AccountId>) -> Self {
+		PermissionsV2 {
+			update: v1.update,
+			mint: v1.mint,
+			burn: v1.burn,
+			freeze: Owner::None,
+		}
+	}
+}
+
 impl<AccountId> Into<PermissionLatest<AccountId>> for PermissionVersions<AccountId> {
 	fn into(self) -> PermissionLatest<AccountId> {
 		match self {
-			PermissionVersions::V1(v1) => v1,
+			PermissionVersions::V1(v1) => v1.into(),
+			PermissionVersions::V2(v2) => v2,
 		}
 	}
 }
@@ -175,6 +386,6 @@ impl<AccountId> Into<PermissionLatest<AccountId>> for PermissionVersions<Account
 /// Converts the latest permission to other version.
 impl<AccountId> Into<PermissionVersions<AccountId>> for PermissionLatest<AccountId> {
 	fn into(self) -> PermissionVersions<AccountId> {
-		PermissionVersions::V1(self)
+		PermissionVersions::V2(self)
 	}
 }
@@ -22,13 +22,17 @@
 // - 'token' / 'asset' / 'currency' and
 // - 'balance' / 'value' / 'amount'
 // are used interchangeably as they make more sense in certain contexts.
-use frame_support::traits::{ExistenceRequirement, Imbalance, SignedImbalance, WithdrawReasons};
+use frame_support::traits::{
+	BalanceStatus, ExistenceRequirement, Imbalance, ReservableCurrency, SignedImbalance, WithdrawReasons,
+};
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize, Saturating},
 	DispatchError, DispatchResult,
 };
 use sp_std::{fmt::Debug, result};
 
+pub mod fungibles;
+
 /// Something which provides an ID with authority from chain storage
 pub trait AssetIdAuthority {
 	/// The asset ID type e.g a `u32`
@@ -170,3 +174,84 @@ pub trait MultiCurrencyAccounting {
 	/// is less than `amount`, then the remaining amount will be returned.
 	fn unreserve(who: &Self::AccountId, currency: Option<Self::CurrencyId>, amount: Self::Balance) -> Self::Balance;
 }
+
+/// A currency where funds can be reserved under distinct identifiers, so that subsystems can reserve
+/// independently of each other. This matches the upstream FRAME `NamedReservableCurrency` trait, which the
+/// `frame-support` version Plug is built against does not ship yet.
+pub trait NamedReservableCurrency<AccountId>: ReservableCurrency<AccountId> {
+	/// An identifier for a reserve, distinguishing it from the reserves of other subsystems.
+	type ReserveIdentifier;
+
+	/// Deducts up to `value` from the reserved balance of `who` held under `id`. This function cannot fail.
+	///
+	/// If the balance reserved under `id` is less than `value`, then a non-zero second item will be returned.
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> (Self::NegativeImbalance, Self::Balance);
+
+	/// The portion of the reserved balance of `who` held under `id`.
+	fn reserved_balance_named(id: &Self::ReserveIdentifier, who: &AccountId) -> Self::Balance;
+
+	/// Moves `value` from free balance to the reserved balance held under `id`.
+	///
+	/// If the free balance is lower than `value`, then no funds will be moved and an `Err` will
+	/// be returned. This is different behavior than `unreserve_named`.
+	fn reserve_named(id: &Self::ReserveIdentifier, who: &AccountId, value: Self::Balance) -> DispatchResult;
+
+	/// Moves up to `value` from the reserved balance held under `id` to free balance. This function cannot fail.
+	///
+	/// If the balance reserved under `id` is less than `value`, then the remaining amount will be returned.
+	fn unreserve_named(id: &Self::ReserveIdentifier, who: &AccountId, value: Self::Balance) -> Self::Balance;
+
+	/// Moves up to `value` from the reserved balance of `slashed` held under `id` to `beneficiary`.
+	/// Funds are placed in either the free balance or the reserved balance of `beneficiary` depending on
+	/// `status`. Reserved funds are held under the same `id`.
+	///
+	/// If the balance reserved under `id` is less than `value`, then `Ok(non_zero)` will be returned.
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError>;
+
+	/// Reserve or unreserve so that the balance reserved under `id` is exactly `value`.
+	fn ensure_reserved_named(id: &Self::ReserveIdentifier, who: &AccountId, value: Self::Balance) -> DispatchResult {
+		let current = Self::reserved_balance_named(id, who);
+		if current > value {
+			Self::unreserve_named(id, who, current - value);
+			Ok(())
+		} else if value > current {
+			Self::reserve_named(id, who, value - current)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Unreserve all of the balance reserved under `id`, returning the amount unreserved.
+	fn unreserve_all_named(id: &Self::ReserveIdentifier, who: &AccountId) -> Self::Balance {
+		let value = Self::reserved_balance_named(id, who);
+		Self::unreserve_named(id, who, value);
+		value
+	}
+
+	/// Slash all of the balance reserved under `id`, returning the negative imbalance created.
+	fn slash_all_reserved_named(id: &Self::ReserveIdentifier, who: &AccountId) -> Self::NegativeImbalance {
+		let value = Self::reserved_balance_named(id, who);
+		Self::slash_reserved_named(id, who, value).0
+	}
+
+	/// Move all of the balance of `slashed` reserved under `id` to `beneficiary`, according to `status`.
+	fn repatriate_all_reserved_named(
+		id: &Self::ReserveIdentifier,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		status: BalanceStatus,
+	) -> DispatchResult {
+		let value = Self::reserved_balance_named(id, slashed);
+		Self::repatriate_reserved_named(id, slashed, beneficiary, value, status).map(|_| ())
+	}
+}
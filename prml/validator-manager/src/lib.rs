@@ -22,14 +22,20 @@
 //!
 //! ## Dispatchable methods
 //!
-//! There are two dispatchable methods, both of which require root previlage.
+//! All dispatchable methods require root previlage.
 //!
 //! ```ignore
 //! pub fn add(origin, validator: T::ValidatorId) { ... }
-//! pub fn remove(origin, validator: T::ValidatorId) { ... }
+//! pub fn remove(origin, validator: T::ValidatorId, force: bool) { ... }
+//! pub fn add_many(origin, validators: Vec<T::ValidatorId>) { ... }
+//! pub fn remove_many(origin, validators: Vec<T::ValidatorId>, force: bool) { ... }
+//! pub fn swap(origin, old: T::ValidatorId, new: T::ValidatorId) { ... }
 //! ```
 //!
-//! *Note* session keys of new validators must be set prior to calling `add()`.
+//! *Note* session keys of new validators must be set prior to calling `add()`, `add_many()` or `swap()`.
+//!
+//! Unless forced, `remove()` and `remove_many()` only schedule the removal, which is applied at the start of the next
+//! call to `new_session`, so a validator is not dropped while it may still be producing blocks.
 //!
 //! ## Dependency
 //!
@@ -58,12 +64,16 @@ pub trait Trait: frame_system::Config + pallet_session::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
     /// The minimum number of validators persisted in storage to ensure block production continues.
     type MinimumValidatorCount: Get<u32>;
+    /// The maximum number of validators persisted in storage to keep session rotation bounded.
+    type MaximumValidatorCount: Get<u32>;
 }
 
 decl_storage! {
     trait Store for Module<T: Config> as ValidatorManager {
         /// Current validators set.
         Validators get(fn validators) config(): Vec<T::ValidatorId>;
+        /// Validators to be removed when the next validators set is queued.
+        PendingRemovals get(fn pending_removals): Vec<T::ValidatorId>;
     }
 }
 
@@ -76,6 +86,12 @@ decl_event!(
         Added(ValidatorId),
         /// Validator removed.
         Removed(ValidatorId),
+        /// Validator scheduled to be removed when the next validators set is queued.
+        RemovalScheduled(ValidatorId),
+        /// Validator replaced by another (old, new).
+        Swapped(ValidatorId, ValidatorId),
+        /// A changed validators set is queued for the next session.
+        SetChanged(Vec<ValidatorId>),
     }
 );
 
@@ -83,12 +99,16 @@ decl_error! {
     pub enum Error for Module<T: Config> {
         /// Number of validators in Validators should be at least MinimumValidatorCount.
         MinimumValidatorCount,
+        /// Number of validators in Validators should be at most MaximumValidatorCount.
+        MaximumValidatorCount,
         /// Validator is already added.
         ValidatorAlreadyAdded,
         /// Validator to be removed is not found.
         ValidatorNotFound,
         /// Session keys are not set for a new validator.
         SessionKeysNotSet,
+        /// Validator is already scheduled to be removed.
+        RemovalAlreadyScheduled,
     }
 }
 
@@ -109,6 +129,7 @@ decl_module! {
 
             let mut validators = Validators::<T>::get();
             ensure!(!validators.contains(&validator), Error::<T>::ValidatorAlreadyAdded);
+            ensure!(validators.len() < T::MaximumValidatorCount::get() as usize, Error::<T>::MaximumValidatorCount);
 
             validators.push(validator.clone());
             Validators::<T>::put(validators);
@@ -116,22 +137,149 @@ decl_module! {
         }
 
         /// Removes a validator using sudo privileges.
-        pub fn remove(origin, validator: T::ValidatorId) {
+        /// Unless `force` is set, the removal is only scheduled and is applied when the next
+        /// validators set is queued. A forced removal takes effect immediately.
+        pub fn remove(origin, validator: T::ValidatorId, force: bool) {
             ensure_root(origin)?;
 
             let mut validators = Validators::<T>::get();
             ensure!(validators.contains(&validator), Error::<T>::ValidatorNotFound);
 
+            let mut pending_removals = Self::pending_removals();
+            ensure!(force || !pending_removals.contains(&validator), Error::<T>::RemovalAlreadyScheduled);
+            pending_removals.retain(|x| *x != validator);
+
             validators.retain(|x| *x != validator);
-            ensure!(validators.len() >= T::MinimumValidatorCount::get() as usize, Error::<T>::MinimumValidatorCount);
+            ensure!(
+                Self::remaining_count(&validators, &pending_removals) >= T::MinimumValidatorCount::get() as usize,
+                Error::<T>::MinimumValidatorCount
+            );
+
+            if force {
+                Validators::<T>::put(validators);
+                PendingRemovals::<T>::put(pending_removals);
+                Self::deposit_event(RawEvent::Removed(validator));
+            } else {
+                pending_removals.push(validator.clone());
+                PendingRemovals::<T>::put(pending_removals);
+                Self::deposit_event(RawEvent::RemovalScheduled(validator));
+            }
+        }
+
+        /// Adds many new validators using sudo privileges. Either all are added or none.
+        /// Session keys of every new validator should be set in session module before calling this.
+        pub fn add_many(origin, new_validators: Vec<T::ValidatorId>) {
+            ensure_root(origin)?;
+
+            ensure!(
+                new_validators.iter().all(|validator| Session::<T>::has_keys(validator)),
+                Error::<T>::SessionKeysNotSet
+            );
+
+            let mut validators = Validators::<T>::get();
+            for validator in new_validators.iter() {
+                ensure!(!validators.contains(validator), Error::<T>::ValidatorAlreadyAdded);
+                validators.push(validator.clone());
+            }
+            ensure!(validators.len() <= T::MaximumValidatorCount::get() as usize, Error::<T>::MaximumValidatorCount);
+
+            Validators::<T>::put(validators);
+            for validator in new_validators {
+                Self::deposit_event(RawEvent::Added(validator));
+            }
+        }
+
+        /// Replaces a validator with a new one in the same position, using sudo privileges.
+        /// The number of validators never changes, so `MinimumValidatorCount` is always upheld.
+        /// New validator's session keys should be set in session module before calling this.
+        pub fn swap(origin, old: T::ValidatorId, new: T::ValidatorId) {
+            ensure_root(origin)?;
+
+            ensure!(Session::<T>::has_keys(&new), Error::<T>::SessionKeysNotSet);
+
+            let mut validators = Validators::<T>::get();
+            ensure!(!validators.contains(&new), Error::<T>::ValidatorAlreadyAdded);
+            let index = validators.iter().position(|x| *x == old).ok_or(Error::<T>::ValidatorNotFound)?;
 
+            validators[index] = new.clone();
             Validators::<T>::put(validators);
-            Self::deposit_event(RawEvent::Removed(validator));
+            PendingRemovals::<T>::mutate(|pending_removals| pending_removals.retain(|x| *x != old));
+            Self::deposit_event(RawEvent::Swapped(old, new));
+        }
+
+        /// Removes many validators using sudo privileges. Either all are removed or none.
+        /// As with `remove`, unless `force` is set the removals are only scheduled and are applied
+        /// when the next validators set is queued. Forced removals take effect immediately.
+        pub fn remove_many(origin, old_validators: Vec<T::ValidatorId>, force: bool) {
+            ensure_root(origin)?;
+
+            let mut validators = Validators::<T>::get();
+            for validator in old_validators.iter() {
+                ensure!(validators.contains(validator), Error::<T>::ValidatorNotFound);
+                validators.retain(|x| x != validator);
+            }
+            let mut pending_removals = Self::pending_removals();
+            ensure!(
+                force || !old_validators.iter().any(|x| pending_removals.contains(x)),
+                Error::<T>::RemovalAlreadyScheduled
+            );
+            pending_removals.retain(|x| !old_validators.contains(x));
+            ensure!(
+                Self::remaining_count(&validators, &pending_removals) >= T::MinimumValidatorCount::get() as usize,
+                Error::<T>::MinimumValidatorCount
+            );
+
+            if force {
+                Validators::<T>::put(validators);
+                PendingRemovals::<T>::put(pending_removals);
+                for validator in old_validators {
+                    Self::deposit_event(RawEvent::Removed(validator));
+                }
+            } else {
+                pending_removals.extend(old_validators.iter().cloned());
+                PendingRemovals::<T>::put(pending_removals);
+                for validator in old_validators {
+                    Self::deposit_event(RawEvent::RemovalScheduled(validator));
+                }
+            }
         }
     }
 }
 
 impl<T: Config> Module<T> {
+    /// Returns whether the given id is in the validators set.
+    /// The set is bounded by `MaximumValidatorCount`, so scanning it stays cheap.
+    pub fn is_validator(who: &T::ValidatorId) -> bool {
+        Validators::<T>::get().contains(who)
+    }
+
+    /// Returns the number of validators in the validators set.
+    pub fn validator_count() -> u32 {
+        Validators::<T>::decode_len().unwrap_or_default() as u32
+    }
+
+    /// Returns the number of validators left once pending removals are applied.
+    fn remaining_count(validators: &[T::ValidatorId], pending_removals: &[T::ValidatorId]) -> usize {
+        validators.iter().filter(|x| !pending_removals.contains(x)).count()
+    }
+
+    /// Removes validators scheduled for removal from the validators set.
+    fn apply_pending_removals() {
+        let pending_removals = PendingRemovals::<T>::take();
+        if pending_removals.is_empty() {
+            return;
+        }
+
+        let mut validators = Validators::<T>::get();
+        for validator in pending_removals {
+            if let Some(index) = validators.iter().position(|x| *x == validator) {
+                validators.remove(index);
+                Self::deposit_event(RawEvent::Removed(validator));
+            }
+        }
+        Validators::<T>::put(validators);
+    }
+
     /// Returns currently queued validators.
     fn queued_validators() -> Vec<T::ValidatorId> {
         Session::<T>::queued_keys()
@@ -156,8 +304,10 @@ impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Module<T> {
             return None;
         }
 
+        Self::apply_pending_removals();
         let validators = Validators::<T>::get();
         if Self::is_updated(&validators) {
+            Self::deposit_event(RawEvent::SetChanged(validators.clone()));
             Some(validators)
         } else {
             None
@@ -65,6 +65,7 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumValidatorCount: u32 = 1;
+    pub const MaximumValidatorCount: u32 = 3;
 }
 
 impl pallet_session::Config for Test {
@@ -103,6 +104,7 @@ impl frame_system::Config for Test {
 impl Config for Test {
     type Event = TestEvent;
     type MinimumValidatorCount = MinimumValidatorCount;
+    type MaximumValidatorCount = MaximumValidatorCount;
 }
 
 pub struct TestShouldEndSession;
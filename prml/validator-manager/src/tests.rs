@@ -65,6 +65,28 @@ fn add_rejects_validators_in_list() {
     });
 }
 
+#[test]
+fn add_rejects_above_maximum_validator_count() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .build()
+        .execute_with(|| {
+            let dave = 3;
+            assert_ok!(set_keys(CHARLIE));
+            assert_ok!(set_keys(dave));
+            assert_ok!(ValidatorManager::add(Origin::ROOT, CHARLIE));
+            assert_eq!(
+                ValidatorManager::validators().len() as u32,
+                <Test as Trait>::MaximumValidatorCount::get()
+            );
+            assert_noop!(
+                ValidatorManager::add(Origin::ROOT, dave),
+                Error::<Test>::MaximumValidatorCount,
+            );
+        });
+}
+
 #[test]
 fn add_event_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -88,10 +110,10 @@ fn remove_requires_root_origin() {
             assert_ok!(set_keys(BOB));
             assert_ok!(ValidatorManager::add(Origin::ROOT, BOB));
             assert_eq!(ValidatorManager::validators(), vec![ALICE, BOB]);
-            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB));
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, true));
             assert_eq!(ValidatorManager::validators(), vec![ALICE]);
-            assert_noop!(ValidatorManager::remove(Origin::NONE, BOB), BadOrigin);
-            assert_noop!(ValidatorManager::remove(Origin::signed(1), BOB), BadOrigin);
+            assert_noop!(ValidatorManager::remove(Origin::NONE, BOB, true), BadOrigin);
+            assert_noop!(ValidatorManager::remove(Origin::signed(1), BOB, true), BadOrigin);
         });
 }
 
@@ -103,7 +125,7 @@ fn remove_returns_error_on_non_existing_validator() {
         .execute_with(|| {
             let validator = 7357;
             assert_noop!(
-                ValidatorManager::remove(Origin::ROOT, validator),
+                ValidatorManager::remove(Origin::ROOT, validator, true),
                 Error::<Test>::ValidatorNotFound,
             );
         });
@@ -116,7 +138,7 @@ fn remove_rejects_below_minium_validator_count() {
         .build()
         .execute_with(|| {
             assert_noop!(
-                ValidatorManager::remove(Origin::ROOT, ALICE),
+                ValidatorManager::remove(Origin::ROOT, ALICE, true),
                 Error::<Test>::MinimumValidatorCount,
             );
         });
@@ -130,8 +152,8 @@ fn remove_event_works() {
         .validator(CHARLIE)
         .build()
         .execute_with(|| {
-            assert_ok!(ValidatorManager::remove(Origin::ROOT, ALICE));
-            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB));
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, ALICE, true));
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, true));
 
             let events = MockSystem::events();
             assert_eq!(events[0].event, TestEvent::poa(RawEvent::Removed(ALICE)));
@@ -139,6 +161,191 @@ fn remove_event_works() {
         });
 }
 
+#[test]
+fn add_many_requires_session_keys_for_all_validators() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(set_keys(ALICE));
+        assert_noop!(
+            ValidatorManager::add_many(Origin::ROOT, vec![ALICE, BOB]),
+            Error::<Test>::SessionKeysNotSet
+        );
+        assert_noop!(ValidatorManager::add_many(Origin::signed(1), vec![ALICE]), BadOrigin);
+    });
+}
+
+#[test]
+fn add_many_rejects_validators_in_list() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .build()
+        .execute_with(|| {
+            assert_ok!(set_keys(ALICE));
+            assert_ok!(set_keys(BOB));
+            assert_noop!(
+                ValidatorManager::add_many(Origin::ROOT, vec![BOB, ALICE]),
+                Error::<Test>::ValidatorAlreadyAdded,
+            );
+            assert_noop!(
+                ValidatorManager::add_many(Origin::ROOT, vec![BOB, BOB]),
+                Error::<Test>::ValidatorAlreadyAdded,
+            );
+        });
+}
+
+#[test]
+fn add_many_works() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .build()
+        .execute_with(|| {
+            assert_ok!(set_keys(BOB));
+            assert_ok!(set_keys(CHARLIE));
+            assert_ok!(ValidatorManager::add_many(Origin::ROOT, vec![BOB, CHARLIE]));
+            assert_eq!(ValidatorManager::validators(), vec![ALICE, BOB, CHARLIE]);
+
+            let events = MockSystem::events();
+            assert_eq!(events[0].event, TestEvent::poa(RawEvent::Added(BOB)));
+            assert_eq!(events[1].event, TestEvent::poa(RawEvent::Added(CHARLIE)));
+        });
+}
+
+#[test]
+fn add_many_rejects_above_maximum_validator_count() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .build()
+        .execute_with(|| {
+            let dave = 3;
+            assert_ok!(set_keys(BOB));
+            assert_ok!(set_keys(CHARLIE));
+            assert_ok!(set_keys(dave));
+            assert_noop!(
+                ValidatorManager::add_many(Origin::ROOT, vec![BOB, CHARLIE, dave]),
+                Error::<Test>::MaximumValidatorCount,
+            );
+            assert_ok!(ValidatorManager::add_many(Origin::ROOT, vec![BOB, CHARLIE]));
+        });
+}
+
+#[test]
+fn remove_many_rejects_non_existing_validator() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ValidatorManager::remove_many(Origin::ROOT, vec![BOB, 7357], true),
+                Error::<Test>::ValidatorNotFound,
+            );
+            assert_noop!(ValidatorManager::remove_many(Origin::signed(1), vec![BOB], true), BadOrigin);
+        });
+}
+
+#[test]
+fn remove_many_rejects_below_minimum_validator_count() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .validator(CHARLIE)
+        .build()
+        .execute_with(|| {
+            // Each removal alone would be fine, but not all of them together
+            assert_noop!(
+                ValidatorManager::remove_many(Origin::ROOT, vec![ALICE, BOB, CHARLIE], false),
+                Error::<Test>::MinimumValidatorCount,
+            );
+            assert_eq!(ValidatorManager::validators(), vec![ALICE, BOB, CHARLIE]);
+        });
+}
+
+#[test]
+fn forced_remove_many_works() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .validator(CHARLIE)
+        .build()
+        .execute_with(|| {
+            assert_ok!(ValidatorManager::remove_many(Origin::ROOT, vec![ALICE, CHARLIE], true));
+            assert_eq!(ValidatorManager::validators(), vec![BOB]);
+
+            let events = MockSystem::events();
+            assert_eq!(events[0].event, TestEvent::poa(RawEvent::Removed(ALICE)));
+            assert_eq!(events[1].event, TestEvent::poa(RawEvent::Removed(CHARLIE)));
+        });
+}
+
+#[test]
+fn swap_requires_session_keys_for_new_validator() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ValidatorManager::swap(Origin::ROOT, ALICE, BOB),
+                Error::<Test>::SessionKeysNotSet
+            );
+            assert_ok!(set_keys(BOB));
+            assert_noop!(ValidatorManager::swap(Origin::signed(1), ALICE, BOB), BadOrigin);
+        });
+}
+
+#[test]
+fn swap_rejects_missing_old_or_existing_new_validator() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .build()
+        .execute_with(|| {
+            assert_ok!(set_keys(BOB));
+            assert_ok!(set_keys(CHARLIE));
+            assert_noop!(
+                ValidatorManager::swap(Origin::ROOT, ALICE, BOB),
+                Error::<Test>::ValidatorAlreadyAdded
+            );
+            assert_noop!(
+                ValidatorManager::swap(Origin::ROOT, 7357, CHARLIE),
+                Error::<Test>::ValidatorNotFound
+            );
+        });
+}
+
+#[test]
+fn swap_at_minimum_validator_count_works() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .build()
+        .execute_with(|| {
+            // Removing ALICE first would fail the minimum count check
+            assert_ok!(set_keys(BOB));
+            assert_noop!(
+                ValidatorManager::remove(Origin::ROOT, ALICE, true),
+                Error::<Test>::MinimumValidatorCount,
+            );
+            assert_ok!(ValidatorManager::swap(Origin::ROOT, ALICE, BOB));
+            assert_eq!(ValidatorManager::validators(), vec![BOB]);
+
+            let events = MockSystem::events();
+            assert_eq!(events[0].event, TestEvent::poa(RawEvent::Swapped(ALICE, BOB)));
+        });
+}
+
+#[test]
+fn swap_preserves_ordering() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .validator(CHARLIE)
+        .build()
+        .execute_with(|| {
+            let dave = 3;
+            assert_ok!(set_keys(dave));
+            assert_ok!(ValidatorManager::swap(Origin::ROOT, BOB, dave));
+            assert_eq!(ValidatorManager::validators(), vec![ALICE, dave, CHARLIE]);
+        });
+}
+
 #[test]
 fn first_session_returns_none() {
     ExtBuilder::default().build().execute_with(|| {
@@ -185,7 +392,7 @@ fn new_session_returns_none_if_list_remains_unchanged() {
             assert_eq!(session_index, 2);
             assert_ok!(set_keys(BOB));
             assert_ok!(ValidatorManager::add(Origin::ROOT, BOB));
-            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB));
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, true));
             assert_eq!(ValidatorManager::new_session(session_index), None);
         });
 }
@@ -229,8 +436,8 @@ fn new_session_returns_some_validators_if_updated() {
             ValidatorManager::queued_validators(),
             vec![ALICE, BOB, CHARLIE]
         );
-        assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB));
-        assert_ok!(ValidatorManager::remove(Origin::ROOT, CHARLIE));
+        assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, true));
+        assert_ok!(ValidatorManager::remove(Origin::ROOT, CHARLIE, true));
         assert_eq!(ValidatorManager::validators(), vec![ALICE]);
         assert_eq!(
             ValidatorManager::new_session(session_index),
@@ -247,3 +454,185 @@ fn new_session_returns_some_validators_if_updated() {
         assert_eq!(MockSession::validators(), vec![ALICE]);
     });
 }
+
+#[test]
+fn new_session_emits_set_changed_once_per_change() {
+    ExtBuilder::default().build().execute_with(|| {
+        let set_changed_events = || {
+            MockSystem::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    TestEvent::poa(RawEvent::SetChanged(validators)) => Some(validators),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_ok!(set_keys(ALICE));
+        assert_ok!(ValidatorManager::add(Origin::ROOT, ALICE));
+        let _ = MockSession::rotate_session();
+        assert_eq!(set_changed_events(), vec![vec![ALICE]]);
+
+        // No change, no event
+        let _ = MockSession::rotate_session();
+        assert_eq!(set_changed_events(), vec![vec![ALICE]]);
+
+        // Adding and removing within a session is not a change
+        assert_ok!(set_keys(BOB));
+        assert_ok!(ValidatorManager::add(Origin::ROOT, BOB));
+        assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, true));
+        let _ = MockSession::rotate_session();
+        assert_eq!(set_changed_events(), vec![vec![ALICE]]);
+
+        assert_ok!(ValidatorManager::add(Origin::ROOT, BOB));
+        let _ = MockSession::rotate_session();
+        let _ = MockSession::rotate_session();
+        assert_eq!(set_changed_events(), vec![vec![ALICE], vec![ALICE, BOB]]);
+    });
+}
+
+#[test]
+fn is_validator_and_validator_count_work() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .build()
+        .execute_with(|| {
+            assert!(ValidatorManager::is_validator(&ALICE));
+            assert!(!ValidatorManager::is_validator(&BOB));
+            assert_eq!(ValidatorManager::validator_count(), 1);
+
+            assert_ok!(set_keys(BOB));
+            assert_ok!(ValidatorManager::add(Origin::ROOT, BOB));
+            assert!(ValidatorManager::is_validator(&BOB));
+            assert_eq!(ValidatorManager::validator_count(), 2);
+
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, ALICE, true));
+            assert!(!ValidatorManager::is_validator(&ALICE));
+            assert_eq!(ValidatorManager::validator_count(), 1);
+        });
+}
+
+#[test]
+fn validator_count_is_zero_without_validators() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ValidatorManager::validator_count(), 0);
+    });
+}
+
+#[test]
+fn remove_is_applied_at_next_session() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .build()
+        .execute_with(|| {
+            let _ = MockSession::rotate_session();
+            assert_eq!(ValidatorManager::queued_validators(), vec![ALICE, BOB]);
+
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, false));
+            // BOB stays in the set until the next validators set is queued
+            assert_eq!(ValidatorManager::validators(), vec![ALICE, BOB]);
+            assert_eq!(ValidatorManager::pending_removals(), vec![BOB]);
+            assert_eq!(
+                MockSystem::events()[0].event,
+                TestEvent::poa(RawEvent::RemovalScheduled(BOB))
+            );
+
+            let _ = MockSession::rotate_session();
+            assert_eq!(ValidatorManager::validators(), vec![ALICE]);
+            assert_eq!(ValidatorManager::pending_removals(), vec![]);
+            assert_eq!(ValidatorManager::queued_validators(), vec![ALICE]);
+            assert!(MockSystem::events()
+                .iter()
+                .any(|record| record.event == TestEvent::poa(RawEvent::Removed(BOB))));
+        });
+}
+
+#[test]
+fn forced_remove_is_applied_immediately() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .build()
+        .execute_with(|| {
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, false));
+            // Forcing overrides the scheduled removal
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, true));
+            assert_eq!(ValidatorManager::validators(), vec![ALICE]);
+            assert_eq!(ValidatorManager::pending_removals(), vec![]);
+        });
+}
+
+#[test]
+fn scheduled_removals_count_towards_minimum_validator_count() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .build()
+        .execute_with(|| {
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, false));
+            assert_noop!(
+                ValidatorManager::remove(Origin::ROOT, BOB, false),
+                Error::<Test>::RemovalAlreadyScheduled,
+            );
+            assert_noop!(
+                ValidatorManager::remove(Origin::ROOT, ALICE, false),
+                Error::<Test>::MinimumValidatorCount,
+            );
+            assert_noop!(
+                ValidatorManager::remove(Origin::ROOT, ALICE, true),
+                Error::<Test>::MinimumValidatorCount,
+            );
+        });
+}
+
+#[test]
+fn remove_many_is_applied_at_next_session() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .validator(CHARLIE)
+        .build()
+        .execute_with(|| {
+            let _ = MockSession::rotate_session();
+
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, CHARLIE, false));
+            assert_noop!(
+                ValidatorManager::remove_many(Origin::ROOT, vec![BOB, CHARLIE], false),
+                Error::<Test>::RemovalAlreadyScheduled,
+            );
+
+            assert_ok!(ValidatorManager::remove_many(Origin::ROOT, vec![BOB], false));
+            // BOB and CHARLIE stay in the set until the next validators set is queued
+            assert_eq!(ValidatorManager::validators(), vec![ALICE, BOB, CHARLIE]);
+            assert_eq!(ValidatorManager::pending_removals(), vec![CHARLIE, BOB]);
+            assert_eq!(
+                MockSystem::events()[1].event,
+                TestEvent::poa(RawEvent::RemovalScheduled(BOB))
+            );
+
+            let _ = MockSession::rotate_session();
+            assert_eq!(ValidatorManager::validators(), vec![ALICE]);
+            assert_eq!(ValidatorManager::pending_removals(), vec![]);
+        });
+}
+
+#[test]
+fn swapped_out_validator_is_no_longer_scheduled_for_removal() {
+    ExtBuilder::default()
+        .validator(ALICE)
+        .validator(BOB)
+        .build()
+        .execute_with(|| {
+            assert_ok!(ValidatorManager::remove(Origin::ROOT, BOB, false));
+            assert_ok!(set_keys(CHARLIE));
+            assert_ok!(ValidatorManager::swap(Origin::ROOT, BOB, CHARLIE));
+            assert_eq!(ValidatorManager::pending_removals(), vec![]);
+
+            // BOB can be added back without being removed at the next session
+            assert_ok!(set_keys(BOB));
+            assert_ok!(ValidatorManager::add(Origin::ROOT, BOB));
+            let _ = MockSession::rotate_session();
+            assert_eq!(ValidatorManager::validators(), vec![ALICE, CHARLIE, BOB]);
+        });
+}
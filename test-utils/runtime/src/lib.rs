@@ -863,10 +863,65 @@ cfg_if! {
 				}
 			}
 
-			impl prml_generic_asset_rpc_runtime_api::AssetMetaApi<Block, u32> for Runtime {
+			impl prml_generic_asset_rpc_runtime_api::AssetMetaApi<Block, u32, u64, AccountId> for Runtime {
 				fn asset_meta() -> Vec<(u32, AssetInfo)> {
 					vec![(0, Default::default())]
 				}
+
+				fn total_issuance(asset_id: u32) -> u64 {
+					if asset_id == 0 { 1_000 } else { 0 }
+				}
+
+				fn total_dust_reclaimed(asset_id: u32) -> u64 {
+					if asset_id == 0 { 7 } else { 0 }
+				}
+
+				fn transferable_balance(asset_id: u32, _who: AccountId, keep_alive: bool) -> u64 {
+					match (asset_id, keep_alive) {
+						(0, true) => 999,
+						(0, false) => 1_000,
+						_ => 0,
+					}
+				}
+
+				fn balance_detailed(asset_id: u32, _who: AccountId) -> (u64, u64, u64) {
+					if asset_id == 0 { (1_000, 20, 300) } else { (0, 0, 0) }
+				}
+
+				fn holders(
+					_asset_id: u32,
+					start_key: Option<Vec<u8>>,
+					_limit: u32,
+				) -> (Vec<(AccountId, u64)>, Option<Vec<u8>>) {
+					(Vec::new(), start_key)
+				}
+			}
+
+			impl prml_consortium_permission_rpc_runtime_api::PermissionClaimApi<Block, AccountId> for Runtime {
+				fn permission_claim(holder: AccountId, topic: Vec<u8>) -> Option<(AccountId, Vec<u8>)> {
+					if topic == b"access".to_vec() { Some((holder, vec![1])) } else { None }
+				}
+
+				fn has_permission(_holder: AccountId, topic: Vec<u8>, value: Vec<u8>) -> bool {
+					topic == b"access".to_vec() && value == vec![1]
+				}
+
+				fn claims_page(
+					_start: Option<(AccountId, Vec<u8>)>,
+					_limit: u32,
+				) -> (Vec<((AccountId, Vec<u8>), (AccountId, Vec<u8>))>, Option<(AccountId, Vec<u8>)>) {
+					(Vec::new(), None)
+				}
+			}
+
+			impl prml_attestation_rpc_runtime_api::AttestationApi<Block, AccountId> for Runtime {
+				fn attestation_issuers(holder: AccountId) -> Vec<AccountId> {
+					vec![holder]
+				}
+
+				fn attestation_value(_holder: AccountId, _issuer: AccountId, topic: Vec<u8>) -> sp_core::U256 {
+					if topic == b"isOver18".to_vec() { 1.into() } else { 0.into() }
+				}
 			}
 		}
 	} else {